@@ -37,6 +37,16 @@ pub struct Cli {
     #[arg(long)]
     pub no_hardware_benchmarks: bool,
 
+    /// Add the bootnodes published on-chain by the active validators as reserved peers.
+    ///
+    /// Without this flag the registered bootnodes are only logged.
+    #[arg(long)]
+    pub reserve_validator_bootnodes: bool,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub meta_tx_relayer: crate::relayer::RelayerParams,
+
     #[clap(flatten)]
     pub storage_monitor: StorageMonitorParams,
 }
@@ -47,6 +57,10 @@ pub enum Subcommand {
     #[command(subcommand)]
     Key(sc_cli::KeySubcommand),
 
+    /// Validator session keys management, working offline on the local keystore.
+    #[command(subcommand)]
+    Validator(crate::validator::ValidatorCmd),
+
     /// Build a chain specification.
     BuildSpec(sc_cli::BuildSpecCmd),
 
@@ -22,6 +22,7 @@ use crate::{
     chain_specs::{ChainSpec, IdentifyVariant},
     cli::{Cli, Subcommand},
     service,
+    validator::ValidatorCmd,
 };
 
 #[cfg(feature = "allfeat-runtime")]
@@ -70,6 +71,19 @@ pub fn run() -> sc_cli::Result<()> {
 
     match &cli.subcommand {
         Some(Subcommand::Key(cmd)) => cmd.run(&cli),
+        Some(Subcommand::Validator(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            set_default_ss58_version(&runner.config().chain_spec);
+            match cmd {
+                ValidatorCmd::Verify(verify) => {
+                    dispatch_async_run!(runner, &runner.config().chain_spec, config => |components| {
+                        let keystore = components.keystore_container.local_keystore();
+                        Ok(futures::future::ready(verify.run(components.client, keystore)))
+                    })
+                }
+                _ => runner.sync_run(|config| cmd.run(config)),
+            }
+        }
         Some(Subcommand::BuildSpec(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             set_default_ss58_version(&runner.config().chain_spec);
@@ -157,7 +171,9 @@ pub fn run() -> sc_cli::Result<()> {
         None => {
             let runner = cli.create_runner(&cli.run)?;
             let no_hardware_benchmarks = cli.no_hardware_benchmarks;
+            let reserve_validator_bootnodes = cli.reserve_validator_bootnodes;
             let storage_monitor = cli.storage_monitor.clone();
+            let meta_tx_relayer = cli.meta_tx_relayer.clone();
 
             runner.run_node_until_exit(move |config| async move {
                 let hwbench = (!no_hardware_benchmarks)
@@ -196,8 +212,12 @@ pub fn run() -> sc_cli::Result<()> {
 
                 let task_manager: sc_service::TaskManager =
                     dispatch_on_runtime!(chain_spec => |RuntimeApi| {
-                        service::new_full_from_network_cfg::<RuntimeApi>(config)
-                            .map_err(|e| sc_cli::Error::from(*e))
+                        service::new_full_from_network_cfg::<RuntimeApi>(
+                            config,
+                            reserve_validator_bootnodes,
+                            meta_tx_relayer,
+                        )
+                        .map_err(|e| sc_cli::Error::from(*e))
                     })?;
 
                 if let Some(path) = database_source.path() {
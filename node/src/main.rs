@@ -34,8 +34,10 @@ static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
 mod chain_specs;
 mod cli;
+mod relayer;
 mod rpc;
 mod service;
+mod validator;
 
 // runtime must be declared after service (uses service types)
 // but before command (command uses runtime macros)
@@ -0,0 +1,404 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Meta-transaction relayer: wraps the meta-transactions received through `allfeat_relayMetaTx`
+//! into `MetaTx::dispatch` transactions signed, and paid, by the relayer key of the keystore.
+//!
+//! Meta-transactions are checked against the runtime before being relayed, so that the relayer
+//! does not pay for relays failing at dispatch. Relays are restricted to the configured calls and
+//! rate limited per signer.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use allfeat_primitives::{
+    AccountId, Block, Hash, Nonce, Signature,
+    meta_tx::{MetaTxRelay, MetaTxRelayApi},
+};
+use futures::{
+    StreamExt,
+    channel::{mpsc, oneshot},
+};
+use parity_scale_codec::Encode;
+use sc_transaction_pool_api::{InPoolTransaction, TransactionPool, TransactionSource};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{crypto::KeyTypeId, sr25519};
+use sp_keystore::KeystorePtr;
+use sp_runtime::{OpaqueExtrinsic, generic::Era, transaction_validity::TransactionValidityError};
+use substrate_frame_rpc_system::AccountNonceApi;
+
+/// Key type of the relayer key, the sr25519 key signing the relaying transactions.
+pub const RELAYER_KEY_TYPE: KeyTypeId = KeyTypeId(*b"rlay");
+
+const LOG_TARGET: &str = "meta-tx-relayer";
+/// Number of blocks a relaying transaction stays valid.
+const RELAY_MORTALITY: u64 = 64;
+/// Period over which the relays of a signer are counted.
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+/// Number of meta-transactions waiting to be relayed before new ones are refused.
+const RELAY_QUEUE_SIZE: usize = 256;
+
+/// Meta-transaction relayer configuration.
+#[derive(Debug, Clone, clap::Args)]
+pub struct RelayerParams {
+    /// Relay the meta-transactions submitted through the `allfeat_relayMetaTx` RPC.
+    ///
+    /// The relaying transactions are signed with, and their fees paid by, the sr25519 key of type
+    /// `rlay` of the keystore.
+    #[arg(long)]
+    pub meta_tx_relayer: bool,
+
+    /// Only relay this call, given as `Pallet` or `Pallet::call`. Can be repeated.
+    ///
+    /// All the calls are relayed when no call is given.
+    #[arg(long, value_name = "CALL", requires = "meta_tx_relayer")]
+    pub meta_tx_relayer_allow: Vec<CallFilter>,
+
+    /// Maximum number of meta-transactions relayed per signer and minute.
+    #[arg(long, value_name = "COUNT", default_value_t = 10)]
+    pub meta_tx_relayer_rate_limit: u32,
+}
+
+/// A call allowed by the relayer, all the calls of a pallet if `call` is `None`.
+#[derive(Debug, Clone)]
+pub struct CallFilter {
+    pallet: String,
+    call: Option<String>,
+}
+
+impl CallFilter {
+    fn matches<AccountId>(&self, relay: &MetaTxRelay<AccountId>) -> bool {
+        self.pallet.as_bytes() == relay.pallet
+            && self
+                .call
+                .as_ref()
+                .is_none_or(|call| call.as_bytes() == relay.call)
+    }
+}
+
+impl FromStr for CallFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pallet, call) = match s.split_once("::") {
+            Some((pallet, call)) => (pallet, Some(call)),
+            None => (s, None),
+        };
+        if pallet.is_empty() || call.is_some_and(str::is_empty) {
+            return Err(format!(
+                "Invalid call `{s}`, expected `Pallet` or `Pallet::call`"
+            ));
+        }
+        Ok(Self {
+            pallet: pallet.into(),
+            call: call.map(Into::into),
+        })
+    }
+}
+
+/// Reasons a meta-transaction is not relayed.
+#[derive(Debug)]
+pub enum RelayError {
+    /// The meta-transaction is invalid on the current chain state.
+    Invalid(TransactionValidityError),
+    /// The relayed call is not allowed by the relayer.
+    CallNotAllowed,
+    /// The signer of the meta-transaction relayed too many meta-transactions recently.
+    RateLimited,
+    /// The relaying transaction could not be built or submitted.
+    Failed(String),
+}
+
+impl std::fmt::Display for RelayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RelayError::Invalid(e) => write!(f, "Invalid meta-transaction: {e:?}"),
+            RelayError::CallNotAllowed => write!(f, "The call is not relayed by this node"),
+            RelayError::RateLimited => {
+                write!(f, "Too many meta-transactions relayed for the signer")
+            }
+            RelayError::Failed(e) => write!(f, "Relay failed: {e}"),
+        }
+    }
+}
+
+struct RelayRequest {
+    meta_tx: Vec<u8>,
+    reply: oneshot::Sender<Result<Hash, RelayError>>,
+}
+
+/// Submits meta-transactions to the relayer task.
+#[derive(Clone)]
+pub struct RelayerHandle(mpsc::Sender<RelayRequest>);
+
+impl RelayerHandle {
+    /// Relay the encoded `meta_tx`, returning the hash of the relaying transaction.
+    pub async fn relay(&self, meta_tx: Vec<u8>) -> Result<Hash, RelayError> {
+        let (reply, response) = oneshot::channel();
+        self.0
+            .clone()
+            .try_send(RelayRequest { meta_tx, reply })
+            .map_err(|e| {
+                RelayError::Failed(if e.is_full() {
+                    "too many pending relays".into()
+                } else {
+                    "the relayer stopped".into()
+                })
+            })?;
+        response
+            .await
+            .map_err(|_| RelayError::Failed("the relayer stopped".into()))?
+    }
+}
+
+/// Limits the number of relays of each signer over [`RATE_LIMIT_WINDOW`].
+struct RateLimiter {
+    limit: u32,
+    /// Times of the recent relays of each signer.
+    relayed: HashMap<AccountId, VecDeque<Instant>>,
+}
+
+impl RateLimiter {
+    fn new(limit: u32) -> Self {
+        Self {
+            limit,
+            relayed: HashMap::new(),
+        }
+    }
+
+    /// Whether `signer` can relay at `now`. Forgets the relays which left the window.
+    fn allows(&mut self, signer: &AccountId, now: Instant) -> bool {
+        self.relayed.retain(|_, times| {
+            while times
+                .front()
+                .is_some_and(|at| now.duration_since(*at) >= RATE_LIMIT_WINDOW)
+            {
+                times.pop_front();
+            }
+            !times.is_empty()
+        });
+        self.relayed.get(signer).map_or(0, VecDeque::len) < self.limit as usize
+    }
+
+    /// Count a relay of `signer` at `now`.
+    fn record(&mut self, signer: AccountId, now: Instant) {
+        self.relayed.entry(signer).or_default().push_back(now);
+    }
+}
+
+/// Relayer task, relaying the meta-transactions one at a time to keep track of its nonce.
+pub struct MetaTxRelayer<C, P> {
+    client: Arc<C>,
+    pool: Arc<P>,
+    keystore: KeystorePtr,
+    public: sr25519::Public,
+    allowed: Vec<CallFilter>,
+    rate_limiter: RateLimiter,
+    requests: mpsc::Receiver<RelayRequest>,
+}
+
+impl<C, P> MetaTxRelayer<C, P>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: MetaTxRelayApi<Block, AccountId, Nonce, Signature>
+        + AccountNonceApi<Block, AccountId, Nonce>,
+    P: TransactionPool<Block = Block, Hash = Hash> + 'static,
+{
+    /// Create the relayer and the handle to submit meta-transactions to it.
+    ///
+    /// Fails if the keystore has no relayer key.
+    pub fn new(
+        client: Arc<C>,
+        pool: Arc<P>,
+        keystore: KeystorePtr,
+        params: &RelayerParams,
+    ) -> Result<(Self, RelayerHandle), String> {
+        let public = keystore
+            .sr25519_public_keys(RELAYER_KEY_TYPE)
+            .into_iter()
+            .next()
+            .ok_or("No relayer key (sr25519, key type `rlay`) in the keystore")?;
+        log::info!(
+            target: LOG_TARGET,
+            "Relaying meta-transactions with {}",
+            AccountId::from(public)
+        );
+
+        let (sender, requests) = mpsc::channel(RELAY_QUEUE_SIZE);
+        let relayer = Self {
+            client,
+            pool,
+            keystore,
+            public,
+            allowed: params.meta_tx_relayer_allow.clone(),
+            rate_limiter: RateLimiter::new(params.meta_tx_relayer_rate_limit),
+            requests,
+        };
+        Ok((relayer, RelayerHandle(sender)))
+    }
+
+    /// Relay the submitted meta-transactions until all the handles are dropped.
+    pub async fn run(mut self) {
+        while let Some(RelayRequest { meta_tx, reply }) = self.requests.next().await {
+            let result = self.relay(meta_tx).await;
+            match &result {
+                Ok(hash) => {
+                    log::debug!(target: LOG_TARGET, "Relayed meta-transaction in {hash:?}")
+                }
+                Err(e) => log::debug!(target: LOG_TARGET, "Meta-transaction not relayed: {e}"),
+            }
+            let _ = reply.send(result);
+        }
+    }
+
+    async fn relay(&mut self, meta_tx: Vec<u8>) -> Result<Hash, RelayError> {
+        let info = self.client.info();
+        let at = info.best_hash;
+        let nonce = self.next_nonce(at)?;
+        let era = Era::mortal(RELAY_MORTALITY, info.best_number.into());
+
+        let relay = self
+            .client
+            .runtime_api()
+            .prepare_relay(at, meta_tx, nonce, era, at)
+            .map_err(|e| RelayError::Failed(e.to_string()))?
+            .map_err(RelayError::Invalid)?;
+
+        if !self.allowed.is_empty() && !self.allowed.iter().any(|call| call.matches(&relay)) {
+            return Err(RelayError::CallNotAllowed);
+        }
+
+        let now = Instant::now();
+        if !self.rate_limiter.allows(&relay.signer, now) {
+            return Err(RelayError::RateLimited);
+        }
+
+        let signer = relay.signer.clone();
+        let xt = self.sign(at, relay)?;
+        let hash = self
+            .pool
+            .submit_one(at, TransactionSource::Local, xt)
+            .await
+            .map_err(|e| RelayError::Failed(e.to_string()))?;
+        self.rate_limiter.record(signer, now);
+        Ok(hash)
+    }
+
+    /// Nonce of the relayer account, accounting for its transactions ready in the pool.
+    fn next_nonce(&self, at: Hash) -> Result<Nonce, RelayError> {
+        let relayer = AccountId::from(self.public);
+        let mut nonce = self
+            .client
+            .runtime_api()
+            .account_nonce(at, relayer.clone())
+            .map_err(|e| RelayError::Failed(e.to_string()))?;
+
+        // Ready transactions are ordered by nonce, one pass is enough.
+        let mut tag = (&relayer, nonce).encode();
+        for tx in self.pool.ready() {
+            if tx.provides().first() == Some(&tag) {
+                nonce += 1;
+                tag = (&relayer, nonce).encode();
+            }
+        }
+        Ok(nonce)
+    }
+
+    /// Sign the payload of `relay` with the relayer key and build the relaying transaction.
+    fn sign(&self, at: Hash, relay: MetaTxRelay<AccountId>) -> Result<OpaqueExtrinsic, RelayError> {
+        let signature = self
+            .keystore
+            .sr25519_sign(RELAYER_KEY_TYPE, &self.public, &relay.payload)
+            .map_err(|e| RelayError::Failed(e.to_string()))?
+            .ok_or_else(|| RelayError::Failed("the relayer key is not in the keystore".into()))?;
+
+        self.client
+            .runtime_api()
+            .relay_extrinsic(at, relay, self.public.into(), Signature::Sr25519(signature))
+            .map_err(|e| RelayError::Failed(e.to_string()))?
+            .map_err(RelayError::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn relay(pallet: &str, call: &str) -> MetaTxRelay<AccountId> {
+        MetaTxRelay {
+            signer: AccountId::from([1; 32]),
+            pallet: pallet.as_bytes().to_vec(),
+            call: call.as_bytes().to_vec(),
+            dispatch: Vec::new(),
+            extension: Vec::new(),
+            payload: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn call_filters_are_parsed() {
+        let pallet = CallFilter::from_str("Ats").unwrap();
+        assert_eq!((pallet.pallet.as_str(), pallet.call), ("Ats", None));
+        let call = CallFilter::from_str("Ats::register").unwrap();
+        assert_eq!(
+            (call.pallet.as_str(), call.call.as_deref()),
+            ("Ats", Some("register"))
+        );
+
+        for invalid in ["", "::register", "Ats::"] {
+            assert!(CallFilter::from_str(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn call_filters_match_pallets_and_calls() {
+        let pallet = CallFilter::from_str("Ats").unwrap();
+        let call = CallFilter::from_str("Ats::register").unwrap();
+
+        assert!(pallet.matches(&relay("Ats", "update")));
+        assert!(call.matches(&relay("Ats", "register")));
+        assert!(!call.matches(&relay("Ats", "update")));
+        assert!(!pallet.matches(&relay("MusicalWorks", "register")));
+    }
+
+    #[test]
+    fn relays_are_limited_over_the_window() {
+        let alice = AccountId::from([1; 32]);
+        let bob = AccountId::from([2; 32]);
+        let start = Instant::now();
+        let mut limiter = RateLimiter::new(2);
+
+        limiter.record(alice.clone(), start);
+        limiter.record(alice.clone(), start + Duration::from_secs(30));
+        assert!(!limiter.allows(&alice, start + Duration::from_secs(59)));
+        assert!(limiter.allows(&bob, start + Duration::from_secs(59)));
+
+        // The first relay left the window.
+        assert!(limiter.allows(&alice, start + RATE_LIMIT_WINDOW));
+        limiter.record(alice.clone(), start + RATE_LIMIT_WINDOW);
+        assert!(!limiter.allows(&alice, start + RATE_LIMIT_WINDOW));
+
+        assert!(limiter.allows(&alice, start + RATE_LIMIT_WINDOW * 2));
+        assert!(!limiter.relayed.contains_key(&alice));
+    }
+}
@@ -21,6 +21,11 @@ use std::sync::Arc;
 // Allfeat
 use allfeat_primitives::*;
 use jsonrpsee::RpcModule;
+use sp_keystore::KeystorePtr;
+
+mod meta_tx_relay;
+mod validator_status;
+pub use validator_status::{LastAuthored, track_authored_blocks};
 
 /// Extra dependencies for GRANDPA
 pub struct GrandpaDeps<BE> {
@@ -45,6 +50,12 @@ pub struct FullDeps<C, P, BE> {
     pub pool: Arc<P>,
     /// GRANDPA specific dependencies.
     pub grandpa: GrandpaDeps<BE>,
+    /// The node keystore, inspected by `allfeat_validatorStatus`.
+    pub keystore: KeystorePtr,
+    /// Last block authored by this node.
+    pub last_authored: LastAuthored,
+    /// Meta-transaction relayer, `allfeat_relayMetaTx` is only exposed when it runs.
+    pub relayer: Option<crate::relayer::RelayerHandle>,
 }
 
 /// Instantiate all RPC extensions.
@@ -68,13 +79,17 @@ where
         + sp_blockchain::HeaderMetadata<Block, Error = sp_blockchain::Error>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
         + sp_block_builder::BlockBuilder<Block>
+        + sp_consensus_aura::AuraApi<Block, sp_consensus_aura::sr25519::AuthorityId>
+        + sp_consensus_grandpa::GrandpaApi<Block>
         + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     P: 'static + Sync + Send + sc_transaction_pool_api::TransactionPool<Block = Block>,
 {
     // polkadot-sdk
+    use meta_tx_relay::{MetaTxRelayApiServer, MetaTxRelayRpc};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
+    use validator_status::{ValidatorStatusApiServer, ValidatorStatusRpc};
 
     let mut module = RpcModule::new(());
 
@@ -82,6 +97,9 @@ where
         client,
         pool,
         grandpa,
+        keystore,
+        last_authored,
+        relayer,
     } = deps;
     let GrandpaDeps {
         shared_voter_state,
@@ -93,6 +111,10 @@ where
 
     module.merge(System::new(client.clone(), pool.clone()).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(ValidatorStatusRpc::new(client.clone(), keystore, last_authored).into_rpc())?;
+    if let Some(relayer) = relayer {
+        module.merge(MetaTxRelayRpc::new(relayer).into_rpc())?;
+    }
     module.merge(
         Grandpa::new(
             subscription_executor,
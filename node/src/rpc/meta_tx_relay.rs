@@ -0,0 +1,77 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! `allfeat_relayMetaTx` RPC: submits meta-transactions to the relayer of this node.
+
+use allfeat_primitives::Hash;
+use jsonrpsee::{
+    core::{RpcResult, async_trait},
+    proc_macros::rpc,
+    types::{ErrorObject, ErrorObjectOwned},
+};
+use sp_core::Bytes;
+
+use crate::relayer::{RelayError, RelayerHandle};
+
+/// The meta-transaction is invalid on the current chain state.
+const INVALID_META_TX: i32 = 8000;
+/// The relayed call is not allowed by the relayer.
+const CALL_NOT_ALLOWED: i32 = 8001;
+/// The signer relayed too many meta-transactions recently.
+const RATE_LIMITED: i32 = 8002;
+/// The relaying transaction could not be built or submitted.
+const RELAY_FAILED: i32 = 8003;
+
+#[rpc(server)]
+pub trait MetaTxRelayApi {
+    /// Relay the SCALE encoded meta-transaction, returning the hash of the relaying transaction.
+    #[method(name = "allfeat_relayMetaTx")]
+    async fn relay_meta_tx(&self, meta_tx: Bytes) -> RpcResult<Hash>;
+}
+
+/// Implementation of [`MetaTxRelayApiServer`].
+pub struct MetaTxRelayRpc {
+    relayer: RelayerHandle,
+}
+
+impl MetaTxRelayRpc {
+    /// Create a new instance of the meta-transaction relay RPC.
+    pub fn new(relayer: RelayerHandle) -> Self {
+        Self { relayer }
+    }
+}
+
+fn relay_error(e: RelayError) -> ErrorObjectOwned {
+    let code = match e {
+        RelayError::Invalid(_) => INVALID_META_TX,
+        RelayError::CallNotAllowed => CALL_NOT_ALLOWED,
+        RelayError::RateLimited => RATE_LIMITED,
+        RelayError::Failed(_) => RELAY_FAILED,
+    };
+    ErrorObject::owned(code, e.to_string(), None::<()>)
+}
+
+#[async_trait]
+impl MetaTxRelayApiServer for MetaTxRelayRpc {
+    async fn relay_meta_tx(&self, meta_tx: Bytes) -> RpcResult<Hash> {
+        self.relayer
+            .relay(meta_tx.to_vec())
+            .await
+            .map_err(relay_error)
+    }
+}
@@ -0,0 +1,335 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! `allfeat_validatorStatus` RPC: cross-checks the local keystore with the on-chain
+//! authority sets.
+
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+use allfeat_primitives::{AccountId, Block, BlockNumber, Hash};
+use futures::StreamExt;
+use jsonrpsee::{
+    Extensions,
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{ErrorObject, ErrorObjectOwned},
+};
+use parity_scale_codec::{Decode, Encode};
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_consensus::BlockOrigin;
+use sp_consensus_aura::{
+    AuraApi,
+    digests::CompatibleDigestItem,
+    sr25519::{AuthorityId as AuraId, AuthoritySignature as AuraSignature},
+};
+use sp_consensus_grandpa::{AuthorityId as GrandpaId, GrandpaApi};
+use sp_core::{
+    crypto::{ByteArray, KeyTypeId, key_types},
+    hashing::{twox_64, twox_128},
+    storage::StorageKey,
+};
+use sp_keystore::KeystorePtr;
+use sp_runtime::traits::Header;
+
+/// Number of blocks scanned back from the best block for one authored by the local Aura keys,
+/// when none was authored since the node started.
+///
+/// Matches the default state pruning, the authority set of older blocks may not be available.
+const LAST_AUTHORED_SCAN_DEPTH: u32 = 256;
+
+/// Number of the last block authored by this node since it started.
+#[derive(Clone, Default)]
+pub struct LastAuthored(Arc<Mutex<Option<BlockNumber>>>);
+
+impl LastAuthored {
+    fn get(&self) -> Option<BlockNumber> {
+        *self.0.lock().expect("lock is never poisoned; qed")
+    }
+
+    fn note(&self, number: BlockNumber) {
+        *self.0.lock().expect("lock is never poisoned; qed") = Some(number);
+    }
+}
+
+/// Records the blocks authored by this node as they are imported.
+pub async fn track_authored_blocks<C>(client: Arc<C>, last_authored: LastAuthored)
+where
+    C: BlockchainEvents<Block>,
+{
+    let mut imports = client.import_notification_stream();
+    while let Some(notification) = imports.next().await {
+        if notification.origin == BlockOrigin::Own {
+            last_authored.note(*notification.header.number());
+        }
+    }
+}
+
+/// On-chain status of a key of the local keystore.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyStatus {
+    /// Hex encoded public key.
+    pub public: String,
+    /// Account the key is registered for with `session.setKeys`.
+    pub owner: Option<AccountId>,
+    /// Whether the key is part of the current authority set.
+    pub active: bool,
+    /// Whether the key is queued for the next session.
+    pub queued: bool,
+}
+
+/// Validator health report of this node.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorStatus {
+    /// Best block the report was computed at.
+    pub best_block: BlockNumber,
+    /// Aura keys of the keystore.
+    pub aura: Vec<KeyStatus>,
+    /// GRANDPA keys of the keystore.
+    pub grandpa: Vec<KeyStatus>,
+    /// Last block authored by this node.
+    ///
+    /// Tracked from the imported blocks since the node started. After a restart, it is recovered
+    /// from the Aura digests of the last `LAST_AUTHORED_SCAN_DEPTH` (256) blocks, so `None` means
+    /// no block was authored by the local keys since the start nor within that window.
+    pub last_authored_block: Option<BlockNumber>,
+    /// Inconsistencies between the keystore and the chain state.
+    pub warnings: Vec<String>,
+}
+
+#[rpc(server)]
+pub trait ValidatorStatusApi {
+    /// Report whether the keystore keys of this node are registered, active and queued.
+    #[method(name = "allfeat_validatorStatus", with_extensions)]
+    fn validator_status(&self) -> RpcResult<ValidatorStatus>;
+}
+
+/// Implementation of [`ValidatorStatusApiServer`].
+pub struct ValidatorStatusRpc<C, BE> {
+    client: Arc<C>,
+    keystore: KeystorePtr,
+    last_authored: LastAuthored,
+    _backend: PhantomData<BE>,
+}
+
+impl<C, BE> ValidatorStatusRpc<C, BE> {
+    /// Create a new instance of the validator status RPC.
+    pub fn new(client: Arc<C>, keystore: KeystorePtr, last_authored: LastAuthored) -> Self {
+        Self {
+            client,
+            keystore,
+            last_authored,
+            _backend: PhantomData,
+        }
+    }
+}
+
+fn internal_error(e: impl std::fmt::Display) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        jsonrpsee::types::error::INTERNAL_ERROR_CODE,
+        e.to_string(),
+        None::<()>,
+    )
+}
+
+/// Storage key of `Session::<item>`.
+fn session_storage_key(item: &[u8]) -> Vec<u8> {
+    let mut key = twox_128(b"Session").to_vec();
+    key.extend(twox_128(item));
+    key
+}
+
+impl<C, BE> ValidatorStatusRpc<C, BE>
+where
+    C: StorageProvider<Block, BE> + HeaderBackend<Block>,
+    BE: Backend<Block>,
+{
+    fn read<T: Decode>(&self, at: Hash, key: Vec<u8>) -> RpcResult<Option<T>> {
+        self.client
+            .storage(at, &StorageKey(key))
+            .map_err(internal_error)?
+            .map(|data| T::decode(&mut &data.0[..]).map_err(internal_error))
+            .transpose()
+    }
+
+    /// Account the key is registered for, from `Session::KeyOwner`.
+    fn key_owner(
+        &self,
+        at: Hash,
+        key_type: KeyTypeId,
+        public: &[u8],
+    ) -> RpcResult<Option<AccountId>> {
+        let mut key = session_storage_key(b"KeyOwner");
+        let encoded = (key_type, public.to_vec()).encode();
+        key.extend(twox_64(&encoded));
+        key.extend(encoded);
+        self.read(at, key)
+    }
+}
+
+impl<C, BE> ValidatorStatusRpc<C, BE>
+where
+    C: HeaderBackend<Block> + ProvideRuntimeApi<Block>,
+    C::Api: AuraApi<Block, AuraId>,
+{
+    /// Most recent block of the last `LAST_AUTHORED_SCAN_DEPTH` whose Aura slot belongs to one
+    /// of `local` keys.
+    fn scan_last_authored(&self, best: Hash, local: &[AuraId]) -> RpcResult<Option<BlockNumber>> {
+        if local.is_empty() {
+            return Ok(None);
+        }
+
+        let mut hash = best;
+        for _ in 0..LAST_AUTHORED_SCAN_DEPTH {
+            let Some(header) = self.client.header(hash).map_err(internal_error)? else {
+                break;
+            };
+            if *header.number() == 0 {
+                break;
+            }
+            let parent = *header.parent_hash();
+            let slot = header
+                .digest()
+                .logs()
+                .iter()
+                .find_map(|log| CompatibleDigestItem::<AuraSignature>::as_aura_pre_digest(log));
+            if let Some(slot) = slot {
+                // The state of the parent may already be pruned.
+                let Ok(authorities) = self.client.runtime_api().authorities(parent) else {
+                    break;
+                };
+                if !authorities.is_empty()
+                    && local.contains(&authorities[(*slot % authorities.len() as u64) as usize])
+                {
+                    return Ok(Some(*header.number()));
+                }
+            }
+            hash = parent;
+        }
+        Ok(None)
+    }
+}
+
+impl<C, BE> ValidatorStatusApiServer for ValidatorStatusRpc<C, BE>
+where
+    C: StorageProvider<Block, BE> + HeaderBackend<Block> + ProvideRuntimeApi<Block>,
+    C: Send + Sync + 'static,
+    C::Api: AuraApi<Block, AuraId> + GrandpaApi<Block>,
+    BE: Backend<Block> + Send + Sync + 'static,
+{
+    fn validator_status(&self, ext: &Extensions) -> RpcResult<ValidatorStatus> {
+        sc_rpc_api::check_if_safe(ext)?;
+
+        let info = self.client.info();
+        let at = info.best_hash;
+        let api = self.client.runtime_api();
+
+        let aura_authorities = api.authorities(at).map_err(internal_error)?;
+        let grandpa_authorities = api.grandpa_authorities(at).map_err(internal_error)?;
+        let queued: Vec<(AccountId, (GrandpaId, AuraId))> = self
+            .read(at, session_storage_key(b"QueuedKeys"))?
+            .unwrap_or_default();
+
+        let mut warnings = Vec::new();
+
+        let mut aura = Vec::new();
+        for public in self.keystore.sr25519_public_keys(key_types::AURA) {
+            let id = AuraId::from(public);
+            aura.push(KeyStatus {
+                public: array_bytes::bytes2hex("0x", id.as_slice()),
+                owner: self.key_owner(at, key_types::AURA, id.as_slice())?,
+                active: aura_authorities.contains(&id),
+                queued: queued.iter().any(|(_, (_, queued))| queued == &id),
+            });
+        }
+
+        let mut grandpa = Vec::new();
+        for public in self.keystore.ed25519_public_keys(key_types::GRANDPA) {
+            let id = GrandpaId::from(public);
+            grandpa.push(KeyStatus {
+                public: array_bytes::bytes2hex("0x", id.as_slice()),
+                owner: self.key_owner(at, key_types::GRANDPA, id.as_slice())?,
+                active: grandpa_authorities.iter().any(|(auth, _)| auth == &id),
+                queued: queued.iter().any(|(_, (queued, _))| queued == &id),
+            });
+        }
+
+        if aura.is_empty() {
+            warnings.push("No Aura key in the keystore".into());
+        }
+        if grandpa.is_empty() {
+            warnings.push("No GRANDPA key in the keystore".into());
+        }
+        for (name, keys) in [("Aura", &aura), ("GRANDPA", &grandpa)] {
+            for key in keys {
+                match &key.owner {
+                    None => warnings.push(format!(
+                        "{name} key {} is not registered on chain",
+                        key.public
+                    )),
+                    Some(owner) if !key.active && !key.queued => warnings.push(format!(
+                        "{name} key {} is registered for {owner} but neither active nor queued",
+                        key.public
+                    )),
+                    _ => {}
+                }
+            }
+        }
+
+        let aura_owners = aura.iter().filter_map(|k| k.owner.as_ref());
+        let grandpa_owners = grandpa
+            .iter()
+            .filter_map(|k| k.owner.as_ref())
+            .collect::<Vec<_>>();
+        for owner in aura_owners {
+            if !grandpa.is_empty() && !grandpa_owners.contains(&owner) {
+                warnings.push(format!(
+                    "The Aura key of {owner} has no matching GRANDPA key in the keystore"
+                ));
+            }
+        }
+
+        let last_authored_block = match self.last_authored.get() {
+            Some(number) => Some(number),
+            None => {
+                let local = self.keystore.sr25519_public_keys(key_types::AURA);
+                let local = local.into_iter().map(AuraId::from).collect::<Vec<_>>();
+                let found = self.scan_last_authored(at, &local)?;
+                if let Some(number) = found {
+                    self.last_authored.note(number);
+                }
+                found
+            }
+        };
+
+        Ok(ValidatorStatus {
+            best_block: info.best_number,
+            aura,
+            grandpa,
+            last_authored_block,
+            warnings,
+        })
+    }
+}
@@ -25,20 +25,24 @@ pub use melodie_runtime::RuntimeApi as MelodieRuntimeApi;
 
 // std
 use sc_consensus_aura::{ImportQueueParams, StartAuraParams};
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 // crates.io
-use futures::FutureExt;
+use futures::{FutureExt, StreamExt};
 // allfeat
+use crate::relayer::{MetaTxRelayer, RelayerHandle, RelayerParams};
 use allfeat_primitives::*;
+use pallet_validators::runtime_api::ValidatorsApi;
 // polkadot-sdk
-use sc_client_api::{BlockBackend, backend::Backend};
+use sc_client_api::{BlockBackend, BlockchainEvents, backend::Backend};
 use sc_consensus_slots::SlotProportion;
+use sc_network::{NetworkPeers, config::MultiaddrWithPeerId, service::traits::NetworkService};
 use sc_rpc_spec_v2::SubscriptionTaskExecutor;
 use sc_service::{Configuration, TaskManager, WarpSyncConfig, error::Error as ServiceError};
 use sc_telemetry::TelemetryWorker;
 use sc_transaction_pool_api::OffchainTransactionPoolFactory;
-use sp_api::ConstructRuntimeApi;
+use sp_api::{ConstructRuntimeApi, ProvideRuntimeApi};
 use sp_consensus_aura::sr25519::{AuthorityId as AuraId, AuthorityPair as AuraPair};
+use sp_keystore::KeystorePtr;
 
 /// The minimum period of blocks on which justifications will be
 /// imported and generated.
@@ -49,6 +53,8 @@ const TELEMETRY_BUFFER_SIZE: usize = 16;
 const BLOCK_PROPOSAL_SLOT_PORTION: f32 = 2.0 / 3.0;
 /// Duration between GRANDPA gossip rounds in milliseconds.
 const GRANDPA_GOSSIP_DURATION_MS: u64 = 333;
+/// Minimum number of finalized blocks between two reads of the on-chain bootnode registry.
+const BOOTNODES_REFRESH_PERIOD: BlockNumber = 600;
 
 type HostFunctions = sp_io::SubstrateHostFunctions;
 
@@ -92,6 +98,8 @@ pub trait RuntimeApiCollection:
     + sp_session::SessionKeys<Block>
     + sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
     + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+    + pallet_validators::runtime_api::ValidatorsApi<Block, AccountId>
+    + allfeat_primitives::meta_tx::MetaTxRelayApi<Block, AccountId, Nonce, Signature>
 {
 }
 impl<Api> RuntimeApiCollection for Api where
@@ -105,6 +113,8 @@ impl<Api> RuntimeApiCollection for Api where
         + sp_session::SessionKeys<Block>
         + sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
         + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+        + pallet_validators::runtime_api::ValidatorsApi<Block, AccountId>
+        + allfeat_primitives::meta_tx::MetaTxRelayApi<Block, AccountId, Nonce, Signature>
 {
 }
 
@@ -233,6 +243,9 @@ fn build_rpc_extensions<RuntimeApi>(
     >,
     backend: Arc<FullBackend>,
     grandpa_link: &GrandpaLinkHalf<RuntimeApi>,
+    keystore: KeystorePtr,
+    last_authored: crate::rpc::LastAuthored,
+    relayer: Option<RelayerHandle>,
 ) -> Box<dyn Fn(SubscriptionTaskExecutor) -> Result<jsonrpsee::RpcModule<()>, ServiceError>>
 where
     RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi>>,
@@ -258,13 +271,114 @@ where
                 subscription_executor: subscription_executor.clone(),
                 finality_provider: finality_proof_provider.clone(),
             },
+            keystore: keystore.clone(),
+            last_authored: last_authored.clone(),
+            relayer: relayer.clone(),
         };
         crate::rpc::create_full(deps).map_err(sc_service::Error::Application)
     })
 }
 
+/// Follows finality and reads the bootnodes published by the active validators.
+///
+/// New addresses are logged and, if `reserve` is set, added to the reserved peers. Addresses no
+/// longer published, because the profile was cleared or the validator left the active set, are
+/// removed from the reserved peers.
+async fn watch_validator_bootnodes<RuntimeApi>(
+    client: Arc<FullClient<RuntimeApi>>,
+    network: Arc<dyn NetworkService>,
+    reserve: bool,
+) where
+    RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi>>,
+    RuntimeApi: Send + Sync + 'static,
+    RuntimeApi::RuntimeApi: RuntimeApiCollection,
+{
+    let mut finality_stream = client.finality_notification_stream();
+    let mut last_refresh: Option<BlockNumber> = None;
+    // Addresses published at the last refresh, `None` for the invalid ones.
+    let mut known: HashMap<String, Option<MultiaddrWithPeerId>> = HashMap::new();
+
+    while let Some(notification) = finality_stream.next().await {
+        let number = notification.header.number;
+        if last_refresh.is_some_and(|at| number.saturating_sub(at) < BOOTNODES_REFRESH_PERIOD) {
+            continue;
+        }
+        last_refresh = Some(number);
+
+        let bootnodes = match client.runtime_api().active_bootnodes(notification.hash) {
+            Ok(bootnodes) => bootnodes,
+            Err(e) => {
+                log::debug!("Unable to read the validators bootnode registry: {e}");
+                continue;
+            }
+        };
+
+        let mut current = HashMap::new();
+        for (validator, addrs) in bootnodes {
+            for raw in addrs {
+                let Ok(addr) = String::from_utf8(raw) else {
+                    continue;
+                };
+                if current.contains_key(&addr) {
+                    continue;
+                }
+                if let Some(peer) = known.remove(&addr) {
+                    current.insert(addr, peer);
+                    continue;
+                }
+                let peer = match addr.parse::<MultiaddrWithPeerId>() {
+                    Ok(peer) => peer,
+                    Err(e) => {
+                        log::warn!(
+                            "Ignoring invalid bootnode {addr} of validator {validator}: {e}"
+                        );
+                        current.insert(addr, None);
+                        continue;
+                    }
+                };
+
+                if !reserve {
+                    log::info!("Validator {validator} advertises bootnode {addr}");
+                } else if let Err(e) = network.add_reserved_peer(peer.clone()) {
+                    // Not recorded, the reservation is retried at the next refresh.
+                    log::warn!("Failed to reserve bootnode {addr} of validator {validator}: {e}");
+                    continue;
+                } else {
+                    log::info!("Reserved bootnode {addr} of validator {validator}");
+                }
+                current.insert(addr, Some(peer));
+            }
+        }
+
+        for (addr, peer) in known.drain() {
+            let Some(peer) = peer else {
+                continue;
+            };
+            // The peer may still be reachable through another published address.
+            if current
+                .values()
+                .flatten()
+                .any(|other| other.peer_id == peer.peer_id)
+            {
+                continue;
+            }
+            if reserve {
+                network.remove_reserved_peer(peer.peer_id);
+                log::info!("Removed bootnode {addr}, no longer published by an active validator");
+            } else {
+                log::info!("Bootnode {addr} is no longer published by an active validator");
+            }
+        }
+        known = current;
+    }
+}
+
 /// Builds a new service for a full client.
-fn new_full<RuntimeApi, N>(config: Configuration) -> Result<TaskManager, Box<ServiceError>>
+fn new_full<RuntimeApi, N>(
+    config: Configuration,
+    reserve_validator_bootnodes: bool,
+    relayer_params: RelayerParams,
+) -> Result<TaskManager, Box<ServiceError>>
 where
     N: sc_network::NetworkBackend<Block, <Block as sp_runtime::traits::Block>::Hash>,
     RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi>>,
@@ -353,17 +467,55 @@ where
         );
     }
 
+    task_manager.spawn_handle().spawn(
+        "validator-bootnodes",
+        None,
+        watch_validator_bootnodes(
+            client.clone(),
+            network.clone(),
+            reserve_validator_bootnodes,
+        ),
+    );
+
     let role = config.role;
     let force_authoring = config.force_authoring;
     let name = config.network.node_name.clone();
     let enable_grandpa = !config.disable_grandpa;
     let prometheus_registry = config.prometheus_registry().cloned();
 
+    let last_authored = crate::rpc::LastAuthored::default();
+    if role.is_authority() {
+        task_manager.spawn_handle().spawn(
+            "last-authored-tracker",
+            None,
+            crate::rpc::track_authored_blocks(client.clone(), last_authored.clone()),
+        );
+    }
+
+    let relayer = if relayer_params.meta_tx_relayer {
+        let (relayer, handle) = MetaTxRelayer::new(
+            client.clone(),
+            transaction_pool.clone(),
+            keystore_container.keystore(),
+            &relayer_params,
+        )
+        .map_err(|e| Box::new(ServiceError::Other(e)))?;
+        task_manager
+            .spawn_handle()
+            .spawn("meta-tx-relayer", None, relayer.run());
+        Some(handle)
+    } else {
+        None
+    };
+
     let rpc_extensions_builder = build_rpc_extensions(
         client.clone(),
         transaction_pool.clone(),
         backend.clone(),
         &extra_parts.consensus_parts.grandpa_link,
+        keystore_container.keystore(),
+        last_authored,
+        relayer,
     );
 
     sc_service::spawn_tasks(sc_service::SpawnTasksParams {
@@ -468,6 +620,8 @@ where
 
 pub fn new_full_from_network_cfg<RuntimeApi>(
     config: Configuration,
+    reserve_validator_bootnodes: bool,
+    relayer_params: RelayerParams,
 ) -> Result<TaskManager, Box<ServiceError>>
 where
     RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi>>,
@@ -478,9 +632,13 @@ where
         sc_network::config::NetworkBackendType::Libp2p => new_full::<
             RuntimeApi,
             sc_network::NetworkWorker<Block, <Block as sp_runtime::traits::Block>::Hash>,
-        >(config),
+        >(config, reserve_validator_bootnodes, relayer_params),
         sc_network::config::NetworkBackendType::Litep2p => {
-            new_full::<RuntimeApi, sc_network::Litep2pNetworkBackend>(config)
+            new_full::<RuntimeApi, sc_network::Litep2pNetworkBackend>(
+                config,
+                reserve_validator_bootnodes,
+                relayer_params,
+            )
         }
     }
 }
@@ -0,0 +1,576 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! `allfeat validator` subcommand: offline management of the validator session keys.
+//!
+//! All commands work on the keystore of the selected chain (`--base-path`, `--chain`,
+//! `--keystore-path`), the node does not need to run.
+
+use std::{fs, path::PathBuf, sync::Arc};
+
+use allfeat_primitives::{AccountId, Block};
+use chacha20poly1305::{
+    ChaCha20Poly1305, Key, Nonce,
+    aead::{Aead, KeyInit},
+};
+use parity_scale_codec::{Decode, Encode};
+use rand::{RngCore, rngs::OsRng};
+use sc_cli::{CliConfiguration, KeystoreParams, SharedParams, utils::pair_from_suri};
+use sc_client_api::{Backend, StorageProvider};
+use sc_keystore::LocalKeystore;
+use sc_service::config::{Configuration, KeystoreConfig};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+    Pair,
+    crypto::{ByteArray, ExposeSecret, KeyTypeId, SecretString, key_types},
+    ed25519,
+    hashing::{twox_64, twox_128},
+    sr25519,
+    storage::StorageKey,
+};
+use sp_keystore::Keystore;
+
+/// Version of the backup file format.
+const BACKUP_VERSION: u32 = 1;
+/// scrypt cost parameters used to derive the backup encryption key.
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// Key types making up the session keys, in `SessionKeys` order.
+const SESSION_KEY_TYPES: [KeyTypeId; 2] = [key_types::GRANDPA, key_types::AURA];
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ValidatorCmd {
+    /// Generate new Aura (sr25519) and GRANDPA (ed25519) keys into the keystore.
+    Generate(GenerateCmd),
+
+    /// Print the SCALE encoded session keys to submit with `session.setKeys`.
+    SessionKeys(SessionKeysCmd),
+
+    /// Check the keys registered on-chain for an account against the local keystore.
+    Verify(VerifyCmd),
+
+    /// Export the validator keys into an encrypted backup file.
+    Export(ExportCmd),
+
+    /// Import validator keys from an encrypted backup file.
+    Import(ImportCmd),
+}
+
+/// Keystore selection shared by all the validator commands.
+#[derive(Debug, Clone, clap::Args)]
+pub struct ValidatorKeystoreParams {
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub keystore_params: KeystoreParams,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct GenerateCmd {
+    /// Derive the keys from this secret (`<suri>//aura` and `<suri>//grandpa`) instead of
+    /// generating random ones.
+    #[arg(long)]
+    pub suri: Option<String>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub params: ValidatorKeystoreParams,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct SessionKeysCmd {
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub params: ValidatorKeystoreParams,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct VerifyCmd {
+    /// Validator account (SS58 or hex) the session keys were set for.
+    #[arg(long)]
+    pub account: AccountId,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub params: ValidatorKeystoreParams,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportCmd {
+    /// File the encrypted backup is written to.
+    #[arg(long)]
+    pub output: PathBuf,
+
+    /// File containing the backup password, prompted for if omitted.
+    #[arg(long)]
+    pub backup_password_filename: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub params: ValidatorKeystoreParams,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ImportCmd {
+    /// Encrypted backup file produced by `allfeat validator export`.
+    #[arg(long)]
+    pub input: PathBuf,
+
+    /// File containing the backup password, prompted for if omitted.
+    #[arg(long)]
+    pub backup_password_filename: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub params: ValidatorKeystoreParams,
+}
+
+/// A keystore entry as stored in a backup.
+#[derive(Serialize, Deserialize)]
+struct BackupKey {
+    key_type: String,
+    public: String,
+    suri: String,
+}
+
+/// On-disk format of an encrypted backup.
+#[derive(Serialize, Deserialize)]
+struct BackupFile {
+    version: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl ValidatorCmd {
+    fn params(&self) -> &ValidatorKeystoreParams {
+        match self {
+            ValidatorCmd::Generate(cmd) => &cmd.params,
+            ValidatorCmd::SessionKeys(cmd) => &cmd.params,
+            ValidatorCmd::Verify(cmd) => &cmd.params,
+            ValidatorCmd::Export(cmd) => &cmd.params,
+            ValidatorCmd::Import(cmd) => &cmd.params,
+        }
+    }
+
+    /// Run the commands that only need the keystore.
+    ///
+    /// `Verify` reads the chain database and is run through [`VerifyCmd::run`].
+    pub fn run(&self, config: Configuration) -> sc_cli::Result<()> {
+        let keystore = open_keystore(&config.keystore)?;
+        match self {
+            ValidatorCmd::Generate(cmd) => cmd.run(&config.keystore, &keystore),
+            ValidatorCmd::SessionKeys(_) => {
+                let (grandpa, aura) = local_session_keys(&keystore)?;
+                print_session_keys(&grandpa, &aura);
+                Ok(())
+            }
+            ValidatorCmd::Export(cmd) => cmd.run(&config.keystore),
+            ValidatorCmd::Import(cmd) => cmd.run(&keystore),
+            ValidatorCmd::Verify(_) => Err("`verify` needs the chain database".into()),
+        }
+    }
+}
+
+impl CliConfiguration for ValidatorCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.params().shared_params
+    }
+
+    fn keystore_params(&self) -> Option<&KeystoreParams> {
+        Some(&self.params().keystore_params)
+    }
+}
+
+impl GenerateCmd {
+    fn run(&self, config: &KeystoreConfig, keystore: &LocalKeystore) -> sc_cli::Result<()> {
+        let (grandpa, aura) = match &self.suri {
+            Some(suri) => {
+                let password = keystore_password(config);
+                let grandpa_suri = format!("{suri}//grandpa");
+                let aura_suri = format!("{suri}//aura");
+                let grandpa =
+                    pair_from_suri::<ed25519::Pair>(&grandpa_suri, password.clone())?.public();
+                let aura = pair_from_suri::<sr25519::Pair>(&aura_suri, password)?.public();
+
+                keystore
+                    .insert(key_types::GRANDPA, &grandpa_suri, grandpa.as_ref())
+                    .map_err(|_| sc_cli::Error::KeystoreOperation)?;
+                keystore
+                    .insert(key_types::AURA, &aura_suri, aura.as_ref())
+                    .map_err(|_| sc_cli::Error::KeystoreOperation)?;
+                (grandpa, aura)
+            }
+            None => (
+                keystore
+                    .ed25519_generate_new(key_types::GRANDPA, None)
+                    .map_err(|_| sc_cli::Error::KeystoreOperation)?,
+                keystore
+                    .sr25519_generate_new(key_types::AURA, None)
+                    .map_err(|_| sc_cli::Error::KeystoreOperation)?,
+            ),
+        };
+
+        print_session_keys(&grandpa, &aura);
+        println!("Back the keys up with `allfeat validator export` before submitting them.");
+        Ok(())
+    }
+}
+
+impl VerifyCmd {
+    /// Compare the `Session::NextKeys` of the account at the best block with the local keystore.
+    pub fn run<C, BE>(&self, client: Arc<C>, keystore: Arc<LocalKeystore>) -> sc_cli::Result<()>
+    where
+        C: StorageProvider<Block, BE> + HeaderBackend<Block>,
+        BE: Backend<Block>,
+    {
+        let best = client.info().best_hash;
+        let encoded = client
+            .storage(best, &next_keys_key(&self.account))?
+            .ok_or_else(|| format!("No session keys registered on-chain for {}", self.account))?;
+        let (grandpa, aura) =
+            <(ed25519::Public, sr25519::Public)>::decode(&mut &encoded.0[..]).map_err(|e| {
+                sc_cli::Error::Input(format!("Unexpected on-chain session keys: {e}"))
+            })?;
+
+        let grandpa_found = keystore.has_keys(&[(grandpa.to_raw_vec(), key_types::GRANDPA)]);
+        let aura_found = keystore.has_keys(&[(aura.to_raw_vec(), key_types::AURA)]);
+        let status = |found: bool| if found { "found" } else { "MISSING" };
+
+        println!("On-chain session keys of {}:", self.account);
+        println!(
+            "  GRANDPA: {} ({} in keystore)",
+            array_bytes::bytes2hex("0x", grandpa),
+            status(grandpa_found)
+        );
+        println!(
+            "  Aura:    {} ({} in keystore)",
+            array_bytes::bytes2hex("0x", aura),
+            status(aura_found)
+        );
+
+        if grandpa_found && aura_found {
+            Ok(())
+        } else {
+            Err("The local keystore does not hold the on-chain session keys".into())
+        }
+    }
+}
+
+impl ExportCmd {
+    fn run(&self, config: &KeystoreConfig) -> sc_cli::Result<()> {
+        let KeystoreConfig::Path { path, .. } = config else {
+            return Err("Cannot export an in-memory keystore".into());
+        };
+
+        let mut keys = Vec::new();
+        for entry in fs::read_dir(path)? {
+            let entry = entry?;
+            let Some((key_type, public)) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| array_bytes::hex2bytes(name).ok())
+                .filter(|raw| raw.len() > 4)
+                .map(|raw| {
+                    let key_type = <[u8; 4]>::try_from(&raw[..4]).ok().map(KeyTypeId);
+                    (key_type, raw[4..].to_vec())
+                })
+            else {
+                continue;
+            };
+            let Some(key_type) = key_type.filter(|kt| SESSION_KEY_TYPES.contains(kt)) else {
+                continue;
+            };
+
+            let suri: String = serde_json::from_slice(&fs::read(entry.path())?)
+                .map_err(|e| sc_cli::Error::Input(format!("Invalid keystore entry: {e}")))?;
+            keys.push(BackupKey {
+                key_type: String::from_utf8_lossy(&key_type.0).into(),
+                public: array_bytes::bytes2hex("0x", &public),
+                suri,
+            });
+        }
+
+        if keys.is_empty() {
+            return Err("No validator keys found in the keystore".into());
+        }
+
+        let password = backup_password(self.backup_password_filename.as_ref(), true)?;
+        let plaintext = serde_json::to_vec(&keys).expect("backup keys serialize; qed");
+        let backup = encrypt(&plaintext, &password)?;
+        fs::write(
+            &self.output,
+            serde_json::to_vec_pretty(&backup).expect("backup file serializes; qed"),
+        )?;
+
+        println!("Exported {} keys to {}", keys.len(), self.output.display());
+        Ok(())
+    }
+}
+
+impl ImportCmd {
+    fn run(&self, keystore: &LocalKeystore) -> sc_cli::Result<()> {
+        let backup: BackupFile = serde_json::from_slice(&fs::read(&self.input)?)
+            .map_err(|e| sc_cli::Error::Input(format!("Invalid backup file: {e}")))?;
+        let password = backup_password(self.backup_password_filename.as_ref(), false)?;
+        let plaintext = decrypt(&backup, &password)?;
+        let keys: Vec<BackupKey> = serde_json::from_slice(&plaintext)
+            .map_err(|e| sc_cli::Error::Input(format!("Invalid backup content: {e}")))?;
+
+        for key in &keys {
+            let key_type = KeyTypeId::try_from(key.key_type.as_str())
+                .map_err(|_| sc_cli::Error::Input(format!("Invalid key type {}", key.key_type)))?;
+            let public = array_bytes::hex2bytes(&key.public)
+                .map_err(|_| sc_cli::Error::Input(format!("Invalid public key {}", key.public)))?;
+            keystore
+                .insert(key_type, &key.suri, &public)
+                .map_err(|_| sc_cli::Error::KeystoreOperation)?;
+            println!("Imported {} key {}", key.key_type, key.public);
+        }
+
+        Ok(())
+    }
+}
+
+/// Open the keystore configured for the selected chain.
+pub fn open_keystore(config: &KeystoreConfig) -> sc_cli::Result<LocalKeystore> {
+    Ok(match config {
+        KeystoreConfig::Path { path, password } => LocalKeystore::open(path, password.clone())?,
+        KeystoreConfig::InMemory => LocalKeystore::in_memory(),
+    })
+}
+
+fn keystore_password(config: &KeystoreConfig) -> Option<SecretString> {
+    match config {
+        KeystoreConfig::Path { password, .. } => password.clone(),
+        KeystoreConfig::InMemory => None,
+    }
+}
+
+/// The single GRANDPA and Aura keys of the keystore.
+fn local_session_keys(
+    keystore: &LocalKeystore,
+) -> sc_cli::Result<(ed25519::Public, sr25519::Public)> {
+    let grandpa = keystore.ed25519_public_keys(key_types::GRANDPA);
+    let aura = keystore.sr25519_public_keys(key_types::AURA);
+    match (grandpa.as_slice(), aura.as_slice()) {
+        ([grandpa], [aura]) => Ok((*grandpa, *aura)),
+        _ => Err(format!(
+            "Expected exactly one GRANDPA and one Aura key, found {} and {}. Use \
+            `allfeat validator verify` to identify the keys registered on-chain.",
+            grandpa.len(),
+            aura.len()
+        )
+        .into()),
+    }
+}
+
+fn print_session_keys(grandpa: &ed25519::Public, aura: &sr25519::Public) {
+    println!("GRANDPA (ed25519): {}", array_bytes::bytes2hex("0x", grandpa));
+    println!("Aura (sr25519):    {}", array_bytes::bytes2hex("0x", aura));
+    println!(
+        "Session keys:      {}",
+        array_bytes::bytes2hex("0x", (grandpa, aura).encode())
+    );
+}
+
+/// Storage key of `Session::NextKeys` for `account`.
+fn next_keys_key(account: &AccountId) -> StorageKey {
+    let mut key = twox_128(b"Session").to_vec();
+    key.extend(twox_128(b"NextKeys"));
+    let encoded = account.encode();
+    key.extend(twox_64(&encoded));
+    key.extend(encoded);
+    StorageKey(key)
+}
+
+fn backup_password(file: Option<&PathBuf>, confirm: bool) -> sc_cli::Result<SecretString> {
+    if let Some(file) = file {
+        let password = fs::read_to_string(file)?;
+        return Ok(SecretString::new(password.trim_end().into()));
+    }
+
+    let password = rpassword::prompt_password("Backup password: ")?;
+    if confirm && rpassword::prompt_password("Repeat backup password: ")? != password {
+        return Err("Passwords do not match".into());
+    }
+    Ok(SecretString::new(password.into()))
+}
+
+fn derive_key(password: &SecretString, salt: &[u8]) -> [u8; 32] {
+    let params = scrypt::Params::new(SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P, 32)
+        .expect("scrypt parameters are valid; qed");
+    let mut key = [0u8; 32];
+    scrypt::scrypt(
+        password.expose_secret().as_bytes(),
+        salt,
+        &params,
+        &mut key,
+    )
+    .expect("output length matches the parameters; qed");
+    key
+}
+
+fn encrypt(plaintext: &[u8], password: &SecretString) -> sc_cli::Result<BackupFile> {
+    let mut salt = [0u8; 16];
+    let mut nonce = [0u8; 12];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+
+    let key = derive_key(password, &salt);
+    let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&key))
+        .encrypt(Nonce::from_slice(&nonce), plaintext)
+        .map_err(|_| sc_cli::Error::Input("Backup encryption failed".into()))?;
+
+    Ok(BackupFile {
+        version: BACKUP_VERSION,
+        salt: array_bytes::bytes2hex("0x", salt),
+        nonce: array_bytes::bytes2hex("0x", nonce),
+        ciphertext: array_bytes::bytes2hex("0x", ciphertext),
+    })
+}
+
+fn decrypt(backup: &BackupFile, password: &SecretString) -> sc_cli::Result<Vec<u8>> {
+    if backup.version != BACKUP_VERSION {
+        return Err(format!("Unsupported backup version {}", backup.version).into());
+    }
+    let field = |value: &str| {
+        array_bytes::hex2bytes(value)
+            .map_err(|_| sc_cli::Error::Input("Invalid backup encoding".into()))
+    };
+    let salt = field(&backup.salt)?;
+    let nonce = field(&backup.nonce)?;
+    if nonce.len() != 12 {
+        return Err(sc_cli::Error::Input("Invalid backup nonce".into()));
+    }
+
+    let key = derive_key(password, &salt);
+    ChaCha20Poly1305::new(Key::from_slice(&key))
+        .decrypt(Nonce::from_slice(&nonce), field(&backup.ciphertext)?.as_slice())
+        .map_err(|_| sc_cli::Error::Input("Wrong backup password or corrupted backup".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A scratch directory removed when dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir()
+                .join(format!("allfeat-validator-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn export_import_round_trip() {
+        let dir = TempDir::new("round-trip");
+        let backup = dir.0.join("backup.json");
+        let password = dir.0.join("password");
+        fs::write(&password, "correct horse battery staple\n").unwrap();
+
+        let config = KeystoreConfig::Path {
+            path: dir.0.join("keystore"),
+            password: None,
+        };
+        let keystore = open_keystore(&config).unwrap();
+        GenerateCmd::parse_from(["generate", "--suri", "//Alice"])
+            .run(&config, &keystore)
+            .unwrap();
+        let keys = local_session_keys(&keystore).unwrap();
+
+        ExportCmd::parse_from([
+            "export",
+            "--output",
+            backup.to_str().unwrap(),
+            "--backup-password-filename",
+            password.to_str().unwrap(),
+        ])
+        .run(&config)
+        .unwrap();
+        let file = fs::read_to_string(&backup).unwrap();
+        assert!(!file.contains("//Alice"));
+
+        let restored = LocalKeystore::in_memory();
+        ImportCmd::parse_from([
+            "import",
+            "--input",
+            backup.to_str().unwrap(),
+            "--backup-password-filename",
+            password.to_str().unwrap(),
+        ])
+        .run(&restored)
+        .unwrap();
+
+        assert_eq!(local_session_keys(&restored).unwrap(), keys);
+        let message = b"allfeat";
+        let signature = restored
+            .sr25519_sign(key_types::AURA, &keys.1, message)
+            .unwrap()
+            .unwrap();
+        assert!(sr25519::Pair::verify(&signature, message, &keys.1));
+    }
+
+    #[test]
+    fn wrong_passphrase_is_rejected() {
+        let password = SecretString::new("correct horse battery staple".into());
+        let backup = encrypt(b"validator keys", &password).unwrap();
+
+        assert_eq!(decrypt(&backup, &password).unwrap(), b"validator keys");
+        assert!(decrypt(&backup, &SecretString::new("wrong password".into())).is_err());
+    }
+
+    #[test]
+    fn tampered_backup_is_rejected() {
+        let password = SecretString::new("correct horse battery staple".into());
+        let mut backup = encrypt(b"validator keys", &password).unwrap();
+        let mut ciphertext = array_bytes::hex2bytes(&backup.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        backup.ciphertext = array_bytes::bytes2hex("0x", ciphertext);
+
+        assert!(decrypt(&backup, &password).is_err());
+    }
+
+    #[cfg(feature = "allfeat-runtime")]
+    #[test]
+    fn next_keys_key_matches_session_storage() {
+        let account = AccountId::from([7u8; 32]);
+        assert_eq!(
+            next_keys_key(&account).0,
+            pallet_session::NextKeys::<allfeat_runtime::Runtime>::hashed_key_for(&account)
+        );
+    }
+}
@@ -0,0 +1,146 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Fee Distribution
+//!
+//! Splits transaction fees and tips between a burn, the treasury and the block author.
+//!
+//! ## Features
+//! - The burn and treasury shares are read from the runtime, e.g. from dynamic parameters. When
+//!   they add up to more than 100%, the burn share is applied first and the treasury gets what is
+//!   left.
+//! - The block author receives the rest, or the treasury when there is no author or the author
+//!   cannot receive it.
+//! - Burned fees, including any credit the treasury cannot take, are added to [`TotalFeesBurned`]
+//!   and reported with [`Event::FeesBurned`].
+//!
+//! ## Integration
+//! Use this pallet as the `OnUnbalanced` handler of
+//! `pallet_transaction_payment::FungibleAdapter`. The block author is taken from
+//! `pallet-authorship`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+use frame_support::traits::{
+    Imbalance, OnUnbalanced,
+    fungible::{Balanced, Credit, Inspect},
+};
+use sp_runtime::{
+    Perbill,
+    traits::{Saturating, Zero},
+};
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type CreditOf<T> = Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The currency fees are paid in.
+        type Currency: Balanced<Self::AccountId>;
+
+        /// Share of the fees and tips burned.
+        type BurnShare: Get<Perbill>;
+
+        /// Share of the fees and tips sent to the treasury.
+        type TreasuryShare: Get<Perbill>;
+
+        /// Account receiving the treasury share.
+        type TreasuryAccount: Get<Self::AccountId>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Total amount of fees and tips burned.
+    #[pallet::storage]
+    pub type TotalFeesBurned<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Part of the fees and tips of a transaction was burned.
+        FeesBurned { amount: BalanceOf<T> },
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// The burn and treasury shares to apply, the treasury share being capped to what the burn
+    /// share leaves.
+    pub fn shares() -> (Perbill, Perbill) {
+        let burn = T::BurnShare::get();
+        let treasury = T::TreasuryShare::get().min(Perbill::one().saturating_sub(burn));
+        (burn, treasury)
+    }
+
+    /// Burn, pay the treasury and pay `author` their share of `amount`.
+    pub fn distribute(amount: CreditOf<T>, author: Option<T::AccountId>) {
+        let (burn_share, treasury_share) = Self::shares();
+        let total = amount.peek();
+
+        let (burn_part, rest) = amount.split(burn_share * total);
+        let (mut treasury_part, author_part) = rest.split(treasury_share * total);
+        let mut burned = burn_part.peek();
+        drop(burn_part);
+
+        // Without an author, or if the author cannot receive it, the share goes to the treasury
+        match author {
+            Some(author) => {
+                if let Err(author_part) = T::Currency::resolve(&author, author_part) {
+                    author_part.merge_into(&mut treasury_part);
+                }
+            }
+            None => author_part.merge_into(&mut treasury_part),
+        }
+
+        if let Err(unresolved) = T::Currency::resolve(&T::TreasuryAccount::get(), treasury_part) {
+            burned = burned.saturating_add(unresolved.peek());
+        }
+
+        if !burned.is_zero() {
+            TotalFeesBurned::<T>::mutate(|total| *total = total.saturating_add(burned));
+            Self::deposit_event(Event::FeesBurned { amount: burned });
+        }
+    }
+}
+
+impl<T: Config + pallet_authorship::Config> OnUnbalanced<CreditOf<T>> for Pallet<T> {
+    fn on_unbalanceds(mut fees_then_tips: impl Iterator<Item = CreditOf<T>>) {
+        if let Some(mut amount) = fees_then_tips.next() {
+            if let Some(tips) = fees_then_tips.next() {
+                tips.merge_into(&mut amount);
+            }
+
+            Self::distribute(amount, pallet_authorship::Pallet::<T>::author());
+        }
+    }
+}
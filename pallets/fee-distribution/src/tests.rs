@@ -0,0 +1,94 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Event, TotalFeesBurned, mock::*};
+use frame_support::traits::fungible::{Balanced, Inspect};
+use sp_runtime::Perbill;
+
+const FEE: u64 = 1_000;
+
+#[test]
+fn fees_are_split_between_burn_treasury_and_author() {
+    new_test_ext().execute_with(|| {
+        let issuance = Balances::total_issuance();
+
+        FeeDistribution::distribute(Balances::issue(FEE), Some(AUTHOR));
+
+        assert_eq!(Balances::total_issuance(), issuance + FEE - 100);
+        assert_eq!(Balances::balance(&TREASURY), 10 + 200);
+        assert_eq!(Balances::balance(&AUTHOR), 10 + 700);
+        assert_eq!(TotalFeesBurned::<Test>::get(), 100);
+        System::assert_last_event(Event::FeesBurned { amount: 100 }.into());
+    });
+}
+
+#[test]
+fn author_share_goes_to_treasury_without_author() {
+    new_test_ext().execute_with(|| {
+        FeeDistribution::distribute(Balances::issue(FEE), None);
+
+        assert_eq!(Balances::balance(&TREASURY), 10 + 900);
+        assert_eq!(TotalFeesBurned::<Test>::get(), 100);
+    });
+}
+
+#[test]
+fn burned_total_accumulates() {
+    new_test_ext().execute_with(|| {
+        FeeDistribution::distribute(Balances::issue(FEE), None);
+        FeeDistribution::distribute(Balances::issue(FEE), None);
+
+        assert_eq!(TotalFeesBurned::<Test>::get(), 200);
+    });
+}
+
+#[test]
+fn nothing_burned_emits_no_event() {
+    new_test_ext().execute_with(|| {
+        BurnShare::set(Perbill::zero());
+
+        FeeDistribution::distribute(Balances::issue(FEE), Some(AUTHOR));
+
+        assert_eq!(TotalFeesBurned::<Test>::get(), 0);
+        assert!(
+            !System::events()
+                .iter()
+                .any(|r| matches!(r.event, RuntimeEvent::FeeDistribution(_)))
+        );
+    });
+}
+
+#[test]
+fn treasury_share_is_capped_by_the_burn_share() {
+    new_test_ext().execute_with(|| {
+        BurnShare::set(Perbill::from_percent(70));
+        TreasuryShare::set(Perbill::from_percent(50));
+
+        assert_eq!(
+            FeeDistribution::shares(),
+            (Perbill::from_percent(70), Perbill::from_percent(30))
+        );
+
+        let issuance = Balances::total_issuance();
+        FeeDistribution::distribute(Balances::issue(FEE), Some(AUTHOR));
+
+        assert_eq!(Balances::total_issuance(), issuance + FEE - 700);
+        assert_eq!(Balances::balance(&TREASURY), 10 + 300);
+        assert_eq!(Balances::balance(&AUTHOR), 10);
+    });
+}
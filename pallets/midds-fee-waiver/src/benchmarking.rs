@@ -0,0 +1,64 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_quota() {
+        let provider: T::AccountId = account("provider", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, provider.clone(), 100);
+
+        assert_eq!(Pallet::<T>::remaining_quota(&provider), Some(100));
+    }
+
+    #[benchmark]
+    fn revoke() {
+        let provider: T::AccountId = account("provider", 0, SEED);
+        Pallet::<T>::set_quota(RawOrigin::Root.into(), provider.clone(), 100).unwrap();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, provider.clone());
+
+        assert!(Providers::<T>::get(&provider).is_none());
+    }
+
+    #[benchmark]
+    fn consume_quota() {
+        let provider: T::AccountId = account("provider", 0, SEED);
+        Pallet::<T>::set_quota(RawOrigin::Root.into(), provider.clone(), 100).unwrap();
+
+        #[block]
+        {
+            Pallet::<T>::consume_quota(&provider);
+        }
+
+        assert_eq!(Pallet::<T>::remaining_quota(&provider), Some(99));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
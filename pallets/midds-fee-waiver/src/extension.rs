@@ -0,0 +1,189 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Config, Pallet, WeightInfo};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use frame_support::{dispatch::DispatchInfo, pallet_prelude::*, traits::Contains};
+use sp_runtime::{
+    SaturatedConversion,
+    traits::{
+        AsSystemOriginSigner, DispatchInfoOf, DispatchOriginOf, Dispatchable, PostDispatchInfoOf,
+        TransactionExtension, TransactionExtensionMetadata, ValidateResult,
+    },
+    transaction_validity::{TransactionSource, ValidTransaction},
+};
+
+/// Transaction extension skipping the wrapped fee payment extension `S` when the signer is an
+/// accredited provider with quota left and the call is one of [`Config::FeelessCalls`].
+///
+/// The quota is checked during validation and used when the transaction is applied. Waived
+/// transactions are only valid until the quota period ends and are told apart in the pool by the
+/// nonce tag of `CheckNonce`, so several of them can be pending at once. Those applied once the
+/// quota is exhausted are rejected and can be resubmitted with the fee paid.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct WaiveFeeWithinQuota<T, S>(pub S, PhantomData<T>);
+
+impl<T, S> WaiveFeeWithinQuota<T, S> {
+    pub fn new(inner: S) -> Self {
+        Self(inner, PhantomData)
+    }
+}
+
+impl<T, S> From<S> for WaiveFeeWithinQuota<T, S> {
+    fn from(inner: S) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<T, S: core::fmt::Debug> core::fmt::Debug for WaiveFeeWithinQuota<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "WaiveFeeWithinQuota<{:?}>", self.0)
+    }
+}
+
+/// Outcome of the validation, either the wrapped extension data or the provider whose quota pays.
+pub enum Intermediate<V, AccountId> {
+    Charge(V),
+    Waive(AccountId),
+}
+
+impl<T, S> TransactionExtension<T::RuntimeCall> for WaiveFeeWithinQuota<T, S>
+where
+    T: Config + Send + Sync,
+    S: TransactionExtension<T::RuntimeCall>,
+    T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+    DispatchOriginOf<T::RuntimeCall>: AsSystemOriginSigner<T::AccountId> + Clone,
+{
+    // Seen from outside, this is the wrapped extension.
+    const IDENTIFIER: &'static str = S::IDENTIFIER;
+    type Implicit = S::Implicit;
+    type Val = Intermediate<S::Val, T::AccountId>;
+    type Pre = Intermediate<S::Pre, T::AccountId>;
+
+    fn metadata() -> Vec<TransactionExtensionMetadata> {
+        S::metadata()
+    }
+
+    fn implicit(&self) -> Result<Self::Implicit, TransactionValidityError> {
+        self.0.implicit()
+    }
+
+    fn weight(&self, call: &T::RuntimeCall) -> Weight {
+        let weight = self.0.weight(call);
+        if T::FeelessCalls::contains(call) {
+            weight.saturating_add(T::WeightInfo::consume_quota())
+        } else {
+            weight
+        }
+    }
+
+    fn validate(
+        &self,
+        origin: DispatchOriginOf<T::RuntimeCall>,
+        call: &T::RuntimeCall,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+        self_implicit: Self::Implicit,
+        inherited_implication: &impl Encode,
+        source: TransactionSource,
+    ) -> ValidateResult<Self::Val, T::RuntimeCall> {
+        if let Some(who) = origin.as_system_origin_signer()
+            && Pallet::<T>::is_waived(who, call)
+        {
+            let valid = ValidTransaction {
+                longevity: Pallet::<T>::blocks_left_in_period().saturated_into(),
+                ..Default::default()
+            };
+            let who = who.clone();
+            return Ok((valid, Intermediate::Waive(who), origin));
+        }
+
+        let (valid, val, origin) = self.0.validate(
+            origin,
+            call,
+            info,
+            len,
+            self_implicit,
+            inherited_implication,
+            source,
+        )?;
+        Ok((valid, Intermediate::Charge(val), origin))
+    }
+
+    fn prepare(
+        self,
+        val: Self::Val,
+        origin: &DispatchOriginOf<T::RuntimeCall>,
+        call: &T::RuntimeCall,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        match val {
+            Intermediate::Charge(val) => self
+                .0
+                .prepare(val, origin, call, info, len)
+                .map(Intermediate::Charge),
+            Intermediate::Waive(who) => {
+                Pallet::<T>::consume_quota(&who).ok_or(InvalidTransaction::Payment)?;
+                Ok(Intermediate::Waive(who))
+            }
+        }
+    }
+
+    fn post_dispatch_details(
+        pre: Self::Pre,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+        result: &DispatchResult,
+    ) -> Result<Weight, TransactionValidityError> {
+        match pre {
+            Intermediate::Charge(pre) => {
+                S::post_dispatch_details(pre, info, post_info, len, result)
+            }
+            Intermediate::Waive(_) => Ok(Weight::zero()),
+        }
+    }
+
+    fn bare_validate(
+        call: &T::RuntimeCall,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+    ) -> TransactionValidity {
+        S::bare_validate(call, info, len)
+    }
+
+    fn bare_validate_and_prepare(
+        call: &T::RuntimeCall,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+    ) -> Result<(), TransactionValidityError> {
+        S::bare_validate_and_prepare(call, info, len)
+    }
+
+    fn bare_post_dispatch(
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        post_info: &mut PostDispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+        result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        S::bare_post_dispatch(info, post_info, len, result)
+    }
+}
@@ -0,0 +1,241 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet MIDDS Fee Waiver
+//!
+//! Lets accredited MIDDS providers register and unregister MIDDS without paying transaction fees,
+//! within a quota renewed every period.
+//!
+//! ## Features
+//! - Governance accredits providers and sets their number of feeless transactions per period.
+//! - Usage is tracked on chain and reset when a new period starts.
+//! - The [`WaiveFeeWithinQuota`] transaction extension wraps the fee payment extension and skips
+//!   it for the configured calls while the signer has quota left. Once the quota is exhausted,
+//!   fees are charged normally.
+//!
+//! ## Integration
+//! Wrap `pallet_transaction_payment::ChargeTransactionPayment` with [`WaiveFeeWithinQuota`] in the
+//! runtime transaction extensions. The wrapper keeps the identifier and encoding of the wrapped
+//! extension, so wallets are not affected. Use [`Pallet::is_waived`] to report the waiver in the
+//! fee query runtime APIs.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod extension;
+pub use extension::*;
+
+pub mod weights;
+pub use weights::*;
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+
+/// Feeless transactions granted to a provider, and their usage in the current period.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct ProviderQuota<BlockNumber> {
+    /// Number of feeless transactions allowed per period.
+    pub quota: u32,
+    /// Number of feeless transactions already used in `period`.
+    pub used: u32,
+    /// Index of the period `used` refers to.
+    pub period: BlockNumber,
+}
+
+impl<BlockNumber: PartialEq> ProviderQuota<BlockNumber> {
+    /// Feeless transactions left during `period`.
+    pub fn remaining(&self, period: BlockNumber) -> u32 {
+        if self.period == period {
+            self.quota.saturating_sub(self.used)
+        } else {
+            self.quota
+        }
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::traits::Contains;
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Bounded, Zero};
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Origin allowed to accredit providers and set their quota.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Calls whose fee can be waived, typically MIDDS registrations.
+        type FeelessCalls: Contains<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Length of a quota period, in blocks.
+        #[pallet::constant]
+        type QuotaPeriod: Get<BlockNumberFor<Self>>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Accredited providers and their feeless transaction quota.
+    #[pallet::storage]
+    pub type Providers<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ProviderQuota<BlockNumberFor<T>>>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A provider was accredited or its quota updated.
+        QuotaSet { provider: T::AccountId, quota: u32 },
+        /// A provider lost its accreditation.
+        ProviderRevoked { provider: T::AccountId },
+        /// The fee of a transaction was waived.
+        FeeWaived {
+            provider: T::AccountId,
+            remaining: u32,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The account is not an accredited provider.
+        NotAccredited,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Accredit a provider, or update its quota.
+        ///
+        /// Transactions already used in the current period still count against the new quota.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_quota())]
+        pub fn set_quota(
+            origin: OriginFor<T>,
+            provider: T::AccountId,
+            quota: u32,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let period = Self::current_period();
+            Providers::<T>::mutate(&provider, |entry| {
+                entry
+                    .get_or_insert(ProviderQuota {
+                        quota,
+                        used: 0,
+                        period,
+                    })
+                    .quota = quota;
+            });
+
+            Self::deposit_event(Event::QuotaSet { provider, quota });
+            Ok(())
+        }
+
+        /// Revoke the accreditation of a provider.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::revoke())]
+        pub fn revoke(origin: OriginFor<T>, provider: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Providers::<T>::take(&provider).ok_or(Error::<T>::NotAccredited)?;
+
+            Self::deposit_event(Event::ProviderRevoked { provider });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Index of the current quota period.
+        pub fn current_period() -> BlockNumberFor<T> {
+            let length = T::QuotaPeriod::get();
+            if length.is_zero() {
+                return Zero::zero();
+            }
+            frame_system::Pallet::<T>::block_number() / length
+        }
+
+        /// Feeless transactions left to `who` in the current period, `None` if not accredited.
+        pub fn remaining_quota(who: &T::AccountId) -> Option<u32> {
+            Providers::<T>::get(who).map(|entry| entry.remaining(Self::current_period()))
+        }
+
+        /// Blocks left before the current quota period ends.
+        pub fn blocks_left_in_period() -> BlockNumberFor<T> {
+            let length = T::QuotaPeriod::get();
+            if length.is_zero() {
+                return Bounded::max_value();
+            }
+            length - frame_system::Pallet::<T>::block_number() % length
+        }
+
+        /// Whether the fee of `call` signed by `who` would be waived.
+        pub fn is_waived(
+            who: &T::AccountId,
+            call: &<T as frame_system::Config>::RuntimeCall,
+        ) -> bool {
+            T::FeelessCalls::contains(call)
+                && Self::remaining_quota(who).is_some_and(|remaining| remaining > 0)
+        }
+
+        /// Use one feeless transaction of `who`, returning the quota left.
+        pub(crate) fn consume_quota(who: &T::AccountId) -> Option<u32> {
+            let period = Self::current_period();
+            let remaining = Providers::<T>::mutate(who, |entry| {
+                let entry = entry.as_mut()?;
+                let remaining = entry.remaining(period).checked_sub(1)?;
+                if entry.period != period {
+                    entry.period = period;
+                    entry.used = 0;
+                }
+                entry.used.saturating_inc();
+                Some(remaining)
+            })?;
+
+            Self::deposit_event(Event::FeeWaived {
+                provider: who.clone(),
+                remaining,
+            });
+            Some(remaining)
+        }
+    }
+}
@@ -0,0 +1,138 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use core::cell::RefCell;
+use frame_support::{derive_impl, pallet_prelude::*, parameter_types, traits::Contains};
+use frame_system::EnsureRoot;
+use sp_runtime::{
+    BuildStorage,
+    traits::{DispatchInfoOf, DispatchOriginOf, TransactionExtension, ValidateResult},
+    transaction_validity::TransactionSource,
+};
+
+use crate as pallet_midds_fee_waiver;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type MiddsFeeWaiver = pallet_midds_fee_waiver;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+/// Stands for the MIDDS registration calls: only `remark` is feeless.
+pub struct RemarkOnly;
+impl Contains<RuntimeCall> for RemarkOnly {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::System(frame_system::Call::remark { .. }))
+    }
+}
+
+parameter_types! {
+    pub const QuotaPeriod: u64 = 10;
+}
+
+impl pallet_midds_fee_waiver::Config for Test {
+    type AdminOrigin = EnsureRoot<u64>;
+    type FeelessCalls = RemarkOnly;
+    type QuotaPeriod = QuotaPeriod;
+    type WeightInfo = ();
+}
+
+thread_local! {
+    /// Number of transactions the inner extension charged.
+    pub static CHARGED: RefCell<u32> = const { RefCell::new(0) };
+}
+
+pub fn charged() -> u32 {
+    CHARGED.with(|c| *c.borrow())
+}
+
+/// Stands for `ChargeTransactionPayment`, counts the transactions it charges.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, Debug, TypeInfo)]
+pub struct MockCharge;
+
+impl TransactionExtension<RuntimeCall> for MockCharge {
+    const IDENTIFIER: &'static str = "MockCharge";
+    type Implicit = ();
+    type Val = ();
+    type Pre = ();
+
+    fn weight(&self, _call: &RuntimeCall) -> Weight {
+        Weight::zero()
+    }
+
+    fn validate(
+        &self,
+        origin: DispatchOriginOf<RuntimeCall>,
+        _call: &RuntimeCall,
+        _info: &DispatchInfoOf<RuntimeCall>,
+        _len: usize,
+        _self_implicit: (),
+        _inherited_implication: &impl Encode,
+        _source: TransactionSource,
+    ) -> ValidateResult<(), RuntimeCall> {
+        Ok((ValidTransaction::default(), (), origin))
+    }
+
+    fn prepare(
+        self,
+        _val: (),
+        _origin: &DispatchOriginOf<RuntimeCall>,
+        _call: &RuntimeCall,
+        _info: &DispatchInfoOf<RuntimeCall>,
+        _len: usize,
+    ) -> Result<(), TransactionValidityError> {
+        CHARGED.with(|c| *c.borrow_mut() += 1);
+        Ok(())
+    }
+}
+
+pub type Extension = pallet_midds_fee_waiver::WaiveFeeWithinQuota<Test, MockCharge>;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
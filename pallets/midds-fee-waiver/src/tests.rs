@@ -0,0 +1,220 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Error, Event, Providers, WeightInfo, mock::*};
+use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo, weights::Weight};
+use sp_runtime::{
+    DispatchError,
+    traits::TransactionExtension,
+    transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+};
+
+const PROVIDER: u64 = 1;
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![1] })
+}
+
+fn remark_with_event() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![1] })
+}
+
+/// Validate and prepare `call` signed by `who` as the transaction pool and block builder would.
+fn apply(who: u64, call: RuntimeCall) -> Result<(), InvalidTransaction> {
+    let info = call.get_dispatch_info();
+    let ext = Extension::new(MockCharge);
+    ext.validate_and_prepare(RuntimeOrigin::signed(who), &call, &info, 0, 0)
+        .map(|_| ())
+        .map_err(|e| match e {
+            TransactionValidityError::Invalid(e) => e,
+            e => panic!("unexpected error: {e:?}"),
+        })
+}
+
+#[test]
+fn admin_sets_and_revokes_quota() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            MiddsFeeWaiver::set_quota(RuntimeOrigin::signed(PROVIDER), PROVIDER, 3),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(MiddsFeeWaiver::set_quota(
+            RuntimeOrigin::root(),
+            PROVIDER,
+            3
+        ));
+        assert_eq!(MiddsFeeWaiver::remaining_quota(&PROVIDER), Some(3));
+        System::assert_last_event(
+            Event::QuotaSet {
+                provider: PROVIDER,
+                quota: 3,
+            }
+            .into(),
+        );
+
+        assert_ok!(MiddsFeeWaiver::revoke(RuntimeOrigin::root(), PROVIDER));
+        assert_eq!(MiddsFeeWaiver::remaining_quota(&PROVIDER), None);
+        assert_noop!(
+            MiddsFeeWaiver::revoke(RuntimeOrigin::root(), PROVIDER),
+            Error::<Test>::NotAccredited
+        );
+    });
+}
+
+#[test]
+fn fee_is_waived_within_quota() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(MiddsFeeWaiver::set_quota(
+            RuntimeOrigin::root(),
+            PROVIDER,
+            2
+        ));
+
+        assert_ok!(apply(PROVIDER, remark()));
+        assert_ok!(apply(PROVIDER, remark()));
+        assert_eq!(charged(), 0);
+        assert_eq!(MiddsFeeWaiver::remaining_quota(&PROVIDER), Some(0));
+        System::assert_last_event(
+            Event::FeeWaived {
+                provider: PROVIDER,
+                remaining: 0,
+            }
+            .into(),
+        );
+
+        // Quota exhausted, the fee is charged again.
+        assert_ok!(apply(PROVIDER, remark()));
+        assert_eq!(charged(), 1);
+    });
+}
+
+#[test]
+fn fee_is_charged_for_other_calls_and_accounts() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(MiddsFeeWaiver::set_quota(
+            RuntimeOrigin::root(),
+            PROVIDER,
+            2
+        ));
+
+        assert_ok!(apply(PROVIDER, remark_with_event()));
+        assert_ok!(apply(2, remark()));
+
+        assert_eq!(charged(), 2);
+        assert_eq!(MiddsFeeWaiver::remaining_quota(&PROVIDER), Some(2));
+    });
+}
+
+#[test]
+fn quota_is_renewed_every_period() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(MiddsFeeWaiver::set_quota(
+            RuntimeOrigin::root(),
+            PROVIDER,
+            1
+        ));
+        assert_ok!(apply(PROVIDER, remark()));
+        assert_eq!(MiddsFeeWaiver::remaining_quota(&PROVIDER), Some(0));
+
+        System::set_block_number(10);
+        assert_eq!(MiddsFeeWaiver::remaining_quota(&PROVIDER), Some(1));
+        assert_ok!(apply(PROVIDER, remark()));
+        assert_eq!(charged(), 0);
+
+        let entry = Providers::<Test>::get(PROVIDER).unwrap();
+        assert_eq!((entry.used, entry.period), (1, 1));
+    });
+}
+
+#[test]
+fn quota_exhausted_after_validation_rejects_transaction() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(MiddsFeeWaiver::set_quota(
+            RuntimeOrigin::root(),
+            PROVIDER,
+            1
+        ));
+        let call = remark();
+        let info = call.get_dispatch_info();
+        let ext = Extension::new(MockCharge);
+        let (_, val, origin) = ext
+            .validate_only(
+                RuntimeOrigin::signed(PROVIDER),
+                &call,
+                &info,
+                0,
+                TransactionSource::External,
+                0,
+            )
+            .unwrap();
+
+        // Another transaction uses the last feeless slot first.
+        assert_ok!(apply(PROVIDER, remark()));
+
+        assert_eq!(
+            ext.prepare(val, &origin, &call, &info, 0).err(),
+            Some(InvalidTransaction::Payment.into())
+        );
+        assert_eq!(charged(), 0);
+    });
+}
+
+#[test]
+fn waived_transactions_are_valid_until_the_period_ends() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(MiddsFeeWaiver::set_quota(
+            RuntimeOrigin::root(),
+            PROVIDER,
+            2
+        ));
+        let validate = || {
+            let call = remark();
+            let info = call.get_dispatch_info();
+            Extension::new(MockCharge)
+                .validate_only(
+                    RuntimeOrigin::signed(PROVIDER),
+                    &call,
+                    &info,
+                    0,
+                    TransactionSource::External,
+                    0,
+                )
+                .unwrap()
+                .0
+        };
+
+        // No tag of their own: pending transactions are told apart by their nonce.
+        let valid = validate();
+        assert!(valid.provides.is_empty());
+        assert_eq!(valid.longevity, 9);
+
+        System::set_block_number(15);
+        assert_eq!(validate().longevity, 5);
+    });
+}
+
+#[test]
+fn quota_weight_is_only_added_to_feeless_calls() {
+    new_test_ext().execute_with(|| {
+        let ext = Extension::new(MockCharge);
+
+        assert_eq!(ext.weight(&remark()), <() as WeightInfo>::consume_quota());
+        assert_eq!(ext.weight(&remark_with_event()), Weight::zero());
+    });
+}
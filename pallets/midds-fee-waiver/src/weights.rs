@@ -0,0 +1,51 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_midds_fee_waiver.
+pub trait WeightInfo {
+    fn set_quota() -> Weight;
+    fn revoke() -> Weight;
+    fn consume_quota() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `MiddsFeeWaiver::Providers` (r:1 w:1)
+    /// Proof: `MiddsFeeWaiver::Providers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn set_quota() -> Weight {
+        Weight::from_parts(9_540_000, 3525)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `MiddsFeeWaiver::Providers` (r:1 w:1)
+    /// Proof: `MiddsFeeWaiver::Providers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn revoke() -> Weight {
+        Weight::from_parts(10_480_000, 3525)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `MiddsFeeWaiver::Providers` (r:1 w:1)
+    /// Proof: `MiddsFeeWaiver::Providers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn consume_quota() -> Weight {
+        Weight::from_parts(10_310_000, 3525)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,163 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_support::{
+    dispatch::{DispatchInfo, GetDispatchInfo, PostDispatchInfo},
+    traits::fungible::Mutate,
+};
+use frame_system::RawOrigin;
+use sp_runtime::{
+    traits::{AsSystemOriginSigner, Bounded, TransactionExtension},
+    transaction_validity::TransactionSource,
+};
+
+const SEED: u32 = 0;
+
+/// A sponsor able to pay the deposits of a sponsorship with `MaxBeneficiaries` beneficiaries.
+fn sponsor<T: Config>() -> T::AccountId {
+    let sponsor: T::AccountId = account("sponsor", 0, SEED);
+    let deposits = T::BeneficiaryDeposit::get()
+        .saturating_mul(T::MaxBeneficiaries::get().into())
+        .saturating_add(T::SponsorshipDeposit::get());
+    T::Currency::set_balance(
+        &sponsor,
+        deposits.saturating_add(T::Currency::minimum_balance()),
+    );
+    sponsor
+}
+
+fn sponsorship<T: Config>(calls: T::CallFilter) -> T::AccountId {
+    let sponsor = sponsor::<T>();
+    Pallet::<T>::set_sponsorship(
+        RawOrigin::Signed(sponsor.clone()).into(),
+        calls,
+        T::Balance::max_value(),
+    )
+    .unwrap();
+    sponsor
+}
+
+#[benchmarks(
+    where
+        T: Send + Sync,
+        <T as frame_system::Config>::RuntimeCall:
+            Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+        <T as frame_system::Config>::RuntimeOrigin: AsSystemOriginSigner<T::AccountId> + Clone,
+)]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_sponsorship() {
+        let sponsor = sponsor::<T>();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(sponsor.clone()),
+            Default::default(),
+            T::Balance::max_value(),
+        );
+
+        assert_eq!(
+            Pallet::<T>::remaining_budget(&sponsor),
+            Some(T::Balance::max_value())
+        );
+    }
+
+    #[benchmark]
+    fn add_beneficiary() {
+        let sponsor = sponsorship::<T>(Default::default());
+        let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(sponsor.clone()), beneficiary.clone());
+
+        assert!(Pallet::<T>::is_beneficiary(&sponsor, &beneficiary));
+    }
+
+    #[benchmark]
+    fn remove_beneficiary() {
+        let sponsor = sponsorship::<T>(Default::default());
+        let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+        Pallet::<T>::add_beneficiary(
+            RawOrigin::Signed(sponsor.clone()).into(),
+            beneficiary.clone(),
+        )
+        .unwrap();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(sponsor.clone()), beneficiary.clone());
+
+        assert!(!Pallet::<T>::is_beneficiary(&sponsor, &beneficiary));
+    }
+
+    #[benchmark]
+    fn remove_sponsorship(n: Linear<0, { T::MaxBeneficiaries::get() }>) {
+        let sponsor = sponsorship::<T>(Default::default());
+        for i in 0..n {
+            let beneficiary: T::AccountId = account("beneficiary", i, SEED);
+            Pallet::<T>::add_beneficiary(RawOrigin::Signed(sponsor.clone()).into(), beneficiary)
+                .unwrap();
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(sponsor.clone()));
+
+        assert!(Pallet::<T>::remaining_budget(&sponsor).is_none());
+    }
+
+    /// A sponsored relay going through validation, the budget withdrawal and the settlement.
+    #[benchmark]
+    fn check_sponsorship() {
+        let (beneficiary, call, calls) = T::BenchmarkHelper::relayed_call();
+        let sponsor = sponsorship::<T>(calls);
+        Pallet::<T>::add_beneficiary(
+            RawOrigin::Signed(sponsor.clone()).into(),
+            beneficiary.clone(),
+        )
+        .unwrap();
+        let origin: T::RuntimeOrigin = RawOrigin::Signed(sponsor.clone()).into();
+        let info = call.get_dispatch_info();
+        let post_info = PostDispatchInfo::default();
+        let len = call.encoded_size();
+
+        #[block]
+        {
+            let extension = CheckSponsorship::<T>::new();
+            let (_, relay, origin) = extension
+                .validate_only(origin, &call, &info, len, TransactionSource::External, 0)
+                .unwrap();
+            let pre = extension
+                .prepare(relay, &origin, &call, &info, len)
+                .unwrap();
+            assert!(pre.is_some());
+            CheckSponsorship::<T>::post_dispatch_details(pre, &info, &post_info, len, &Ok(()))
+                .unwrap();
+        }
+
+        let fee = T::Fees::compute_actual_fee(len as u32, &info, &post_info);
+        assert_eq!(
+            Sponsorships::<T>::get(&sponsor).map(|entry| entry.spent),
+            Some(fee)
+        );
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,140 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ComputeFee, Config, InspectRelayedCall, Pallet, Sponsorships, WeightInfo};
+use core::marker::PhantomData;
+use frame_support::{dispatch::DispatchInfo, pallet_prelude::*};
+use sp_runtime::{
+    traits::{
+        AsSystemOriginSigner, DispatchInfoOf, DispatchOriginOf, Dispatchable, PostDispatchInfoOf,
+        TransactionExtension, ValidateResult,
+    },
+    transaction_validity::TransactionSource,
+};
+
+/// Transaction extension enforcing the policy and budget of sponsors relaying calls.
+///
+/// When the signer has a sponsorship and the call relays a signed call, the relayed call must
+/// come from one of its beneficiaries, pass its call filter and fit in its budget. Otherwise the
+/// transaction is invalid and no fee is charged. The fee is taken from the budget when the
+/// transaction is applied and the unused part is given back after dispatch.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckSponsorship<T>(PhantomData<T>);
+
+impl<T> CheckSponsorship<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for CheckSponsorship<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> core::fmt::Debug for CheckSponsorship<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "CheckSponsorship")
+    }
+}
+
+/// A relay paid by `sponsor` for `beneficiary`, with the fee taken from the budget.
+pub struct SponsoredRelay<AccountId, Balance> {
+    pub sponsor: AccountId,
+    pub beneficiary: AccountId,
+    pub fee: Balance,
+}
+
+impl<T> TransactionExtension<T::RuntimeCall> for CheckSponsorship<T>
+where
+    T: Config + Send + Sync,
+    T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+    DispatchOriginOf<T::RuntimeCall>: AsSystemOriginSigner<T::AccountId> + Clone,
+{
+    const IDENTIFIER: &'static str = "CheckSponsorship";
+    type Implicit = ();
+    type Val = Option<SponsoredRelay<T::AccountId, T::Balance>>;
+    type Pre = Option<SponsoredRelay<T::AccountId, T::Balance>>;
+
+    fn weight(&self, _call: &T::RuntimeCall) -> Weight {
+        T::WeightInfo::check_sponsorship()
+    }
+
+    fn validate(
+        &self,
+        origin: DispatchOriginOf<T::RuntimeCall>,
+        call: &T::RuntimeCall,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+        _self_implicit: (),
+        _inherited_implication: &impl Encode,
+        _source: TransactionSource,
+    ) -> ValidateResult<Self::Val, T::RuntimeCall> {
+        let Some(sponsor) = origin.as_system_origin_signer() else {
+            return Ok((Default::default(), None, origin));
+        };
+        if !Sponsorships::<T>::contains_key(sponsor) {
+            return Ok((Default::default(), None, origin));
+        }
+        let Some((beneficiary, relayed)) = T::RelayedCall::inspect(call) else {
+            return Ok((Default::default(), None, origin));
+        };
+
+        let fee = T::Fees::compute_fee(len as u32, info);
+        Pallet::<T>::check(sponsor, &beneficiary, &relayed, fee)
+            .map_err(InvalidTransaction::from)?;
+
+        let relay = SponsoredRelay {
+            sponsor: sponsor.clone(),
+            beneficiary,
+            fee,
+        };
+        Ok((Default::default(), Some(relay), origin))
+    }
+
+    fn prepare(
+        self,
+        val: Self::Val,
+        _origin: &DispatchOriginOf<T::RuntimeCall>,
+        _call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        if let Some(relay) = &val {
+            Pallet::<T>::withdraw_budget(&relay.sponsor, relay.fee)
+                .map_err(InvalidTransaction::from)?;
+        }
+        Ok(val)
+    }
+
+    fn post_dispatch_details(
+        pre: Self::Pre,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+        _result: &DispatchResult,
+    ) -> Result<Weight, TransactionValidityError> {
+        if let Some(relay) = pre {
+            let actual = T::Fees::compute_actual_fee(len as u32, info, post_info);
+            Pallet::<T>::settle(&relay.sponsor, relay.beneficiary, relay.fee, actual);
+        }
+        Ok(Weight::zero())
+    }
+}
@@ -0,0 +1,477 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Sponsorship
+//!
+//! Lets an account sponsor the fees of other accounts through meta-transactions, within a policy
+//! and a budget recorded on chain.
+//!
+//! ## Features
+//! - A sponsor declares which calls it sponsors and the fee budget it is willing to spend.
+//! - The sponsor manages the list of beneficiaries whose meta-transactions it relays.
+//! - A storage deposit is held from the sponsor for its sponsorship and for each beneficiary,
+//!   and released when they are removed.
+//! - The [`CheckSponsorship`] transaction extension rejects, before any fee is charged, the
+//!   relays of a sponsor which are outside its policy or above its remaining budget. The fee of
+//!   accepted relays is charged against the budget.
+//!
+//! ## Integration
+//! Add [`CheckSponsorship`] to the runtime transaction extensions before the fee payment
+//! extension. The runtime tells the pallet how to read relayed calls through
+//! [`InspectRelayedCall`] and how to compute fees through [`ComputeFee`]. Relays by accounts
+//! without a sponsorship are not affected.
+//!
+//! ## Budgets
+//! Budgets are caps a sponsor puts on itself, nothing is held for them. The fees of relays are
+//! paid by the fee payment extension from the free balance of the sponsor, which signs the relay
+//! transactions: a sponsor without the funds to pay a fee sees its relays rejected even with
+//! budget left.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod extension;
+pub use extension::*;
+
+pub mod runtime_api;
+
+pub mod weights;
+pub use weights::*;
+
+use frame_support::pallet_prelude::*;
+use sp_runtime::traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf};
+
+#[cfg(feature = "runtime-benchmarks")]
+/// Provides the benchmarks of [`CheckSponsorship`] with a relayed call.
+pub trait BenchmarkHelper<CallFilter, Call, AccountId> {
+    /// A call relaying a call of the returned account, as read by [`Config::RelayedCall`], and a
+    /// call filter letting the relayed call through.
+    fn relayed_call() -> (AccountId, Call, CallFilter);
+}
+
+/// Extracts the signer and the call wrapped in a relayed call, such as `MetaTx::dispatch`.
+pub trait InspectRelayedCall<Call, AccountId> {
+    /// The account which signed the relayed call and the call itself, `None` if `call` does not
+    /// relay a signed call.
+    fn inspect(call: &Call) -> Option<(AccountId, Call)>;
+}
+
+/// Computes the fee of a transaction, usually through `pallet_transaction_payment`.
+pub trait ComputeFee<Call: Dispatchable, Balance> {
+    /// Fee of a transaction of `len` bytes before dispatch, without tip.
+    fn compute_fee(len: u32, info: &DispatchInfoOf<Call>) -> Balance;
+
+    /// Fee of a transaction of `len` bytes after dispatch, without tip.
+    fn compute_actual_fee(
+        len: u32,
+        info: &DispatchInfoOf<Call>,
+        post_info: &PostDispatchInfoOf<Call>,
+    ) -> Balance;
+}
+
+/// Policy and budget of a sponsor.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct Sponsorship<CallFilter, Balance> {
+    /// Calls the sponsor relays.
+    pub calls: CallFilter,
+    /// Fees the sponsor still accepts to pay. A cap, the fees are paid from its free balance.
+    pub budget: Balance,
+    /// Fees already paid for the beneficiaries.
+    pub spent: Balance,
+    /// Number of beneficiaries.
+    pub beneficiaries: u32,
+    /// Deposit held for the sponsorship, beneficiaries excluded.
+    pub deposit: Balance,
+}
+
+pub type SponsorshipOf<T> = Sponsorship<<T as Config>::CallFilter, <T as Config>::Balance>;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::traits::{
+        InstanceFilter,
+        fungible::MutateHold,
+        tokens::{Balance, Precision},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{CheckedSub, Saturating, Zero};
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Balance type of the budgets and deposits, the one fees are paid in.
+        type Balance: Balance;
+
+        #[cfg(not(feature = "runtime-benchmarks"))]
+        /// The currency the storage deposits are held in.
+        type Currency: MutateHold<Self::AccountId, Balance = Self::Balance, Reason = Self::RuntimeHoldReason>;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        /// The currency the storage deposits are held in, benchmarks fund the sponsors with it.
+        type Currency: frame_support::traits::fungible::Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Balance = Self::Balance, Reason = Self::RuntimeHoldReason>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Deposit held from a sponsor for its sponsorship.
+        #[pallet::constant]
+        type SponsorshipDeposit: Get<Self::Balance>;
+
+        /// Deposit held from a sponsor for each of its beneficiaries.
+        #[pallet::constant]
+        type BeneficiaryDeposit: Get<Self::Balance>;
+
+        /// Computes the fees charged against the budgets.
+        type Fees: ComputeFee<<Self as frame_system::Config>::RuntimeCall, Self::Balance>;
+
+        /// Kinds of calls a sponsor can choose to relay. The default is used by benchmarks.
+        type CallFilter: Parameter
+            + Member
+            + Default
+            + MaxEncodedLen
+            + InstanceFilter<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Reads the signer and the call of relayed calls.
+        type RelayedCall: InspectRelayedCall<<Self as frame_system::Config>::RuntimeCall, Self::AccountId>;
+
+        /// Max number of beneficiaries of a sponsor.
+        #[pallet::constant]
+        type MaxBeneficiaries: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<
+                Self::CallFilter,
+                <Self as frame_system::Config>::RuntimeCall,
+                Self::AccountId,
+            >;
+    }
+
+    /// A reason for the pallet sponsorship placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// The storage deposit of a sponsorship and of its beneficiaries.
+        SponsorshipDeposit,
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Policy and budget of each sponsor.
+    #[pallet::storage]
+    pub type Sponsorships<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SponsorshipOf<T>>;
+
+    /// Beneficiaries of each sponsor, with the deposit held for them.
+    #[pallet::storage]
+    pub type Beneficiaries<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        T::Balance,
+        OptionQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A sponsor set its policy and budget.
+        SponsorshipSet {
+            sponsor: T::AccountId,
+            calls: T::CallFilter,
+            budget: T::Balance,
+        },
+        /// A sponsor stopped sponsoring.
+        SponsorshipRemoved { sponsor: T::AccountId },
+        BeneficiaryAdded {
+            sponsor: T::AccountId,
+            beneficiary: T::AccountId,
+        },
+        BeneficiaryRemoved {
+            sponsor: T::AccountId,
+            beneficiary: T::AccountId,
+        },
+        /// A relayed call was paid by its sponsor.
+        Sponsored {
+            sponsor: T::AccountId,
+            beneficiary: T::AccountId,
+            fee: T::Balance,
+            remaining: T::Balance,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The account does not sponsor anyone.
+        NoSponsorship,
+        /// The account is already a beneficiary of the sponsor.
+        AlreadyBeneficiary,
+        /// The account is not a beneficiary of the sponsor.
+        NotBeneficiary,
+        /// The sponsor reached the max number of beneficiaries.
+        TooManyBeneficiaries,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the calls the origin sponsors and its remaining budget.
+        ///
+        /// Creates the sponsorship if needed, holding `SponsorshipDeposit` from the origin.
+        /// Beneficiaries and spent fees are kept on updates. The budget only caps the fees paid
+        /// for the beneficiaries, nothing is held for it.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_sponsorship())]
+        pub fn set_sponsorship(
+            origin: OriginFor<T>,
+            calls: T::CallFilter,
+            budget: T::Balance,
+        ) -> DispatchResult {
+            let sponsor = ensure_signed(origin)?;
+
+            Sponsorships::<T>::try_mutate(&sponsor, |entry| -> DispatchResult {
+                match entry {
+                    Some(entry) => {
+                        entry.calls = calls.clone();
+                        entry.budget = budget;
+                    }
+                    None => {
+                        let deposit = T::SponsorshipDeposit::get();
+                        T::Currency::hold(
+                            &HoldReason::SponsorshipDeposit.into(),
+                            &sponsor,
+                            deposit,
+                        )?;
+                        *entry = Some(Sponsorship {
+                            calls: calls.clone(),
+                            budget,
+                            spent: Zero::zero(),
+                            beneficiaries: 0,
+                            deposit,
+                        });
+                    }
+                }
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::SponsorshipSet {
+                sponsor,
+                calls,
+                budget,
+            });
+            Ok(())
+        }
+
+        /// Sponsor the relayed calls of `beneficiary`, holding `BeneficiaryDeposit` from the
+        /// origin.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::add_beneficiary())]
+        pub fn add_beneficiary(origin: OriginFor<T>, beneficiary: T::AccountId) -> DispatchResult {
+            let sponsor = ensure_signed(origin)?;
+
+            ensure!(
+                !Beneficiaries::<T>::contains_key(&sponsor, &beneficiary),
+                Error::<T>::AlreadyBeneficiary
+            );
+            Sponsorships::<T>::try_mutate(&sponsor, |entry| -> DispatchResult {
+                let entry = entry.as_mut().ok_or(Error::<T>::NoSponsorship)?;
+                ensure!(
+                    entry.beneficiaries < T::MaxBeneficiaries::get(),
+                    Error::<T>::TooManyBeneficiaries
+                );
+                entry.beneficiaries.saturating_inc();
+                Ok(())
+            })?;
+            let deposit = T::BeneficiaryDeposit::get();
+            T::Currency::hold(&HoldReason::SponsorshipDeposit.into(), &sponsor, deposit)?;
+            Beneficiaries::<T>::insert(&sponsor, &beneficiary, deposit);
+
+            Self::deposit_event(Event::BeneficiaryAdded {
+                sponsor,
+                beneficiary,
+            });
+            Ok(())
+        }
+
+        /// Stop sponsoring the relayed calls of `beneficiary` and release its deposit.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::remove_beneficiary())]
+        pub fn remove_beneficiary(
+            origin: OriginFor<T>,
+            beneficiary: T::AccountId,
+        ) -> DispatchResult {
+            let sponsor = ensure_signed(origin)?;
+
+            let deposit = Beneficiaries::<T>::take(&sponsor, &beneficiary)
+                .ok_or(Error::<T>::NotBeneficiary)?;
+            Sponsorships::<T>::mutate(&sponsor, |entry| {
+                if let Some(entry) = entry {
+                    entry.beneficiaries.saturating_dec();
+                }
+            });
+            T::Currency::release(
+                &HoldReason::SponsorshipDeposit.into(),
+                &sponsor,
+                deposit,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::BeneficiaryRemoved {
+                sponsor,
+                beneficiary,
+            });
+            Ok(())
+        }
+
+        /// Stop sponsoring, remove all the beneficiaries and release the deposits.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::remove_sponsorship(T::MaxBeneficiaries::get()))]
+        pub fn remove_sponsorship(origin: OriginFor<T>) -> DispatchResult {
+            let sponsor = ensure_signed(origin)?;
+
+            let entry = Sponsorships::<T>::take(&sponsor).ok_or(Error::<T>::NoSponsorship)?;
+            let deposit = Beneficiaries::<T>::drain_prefix(&sponsor)
+                .fold(entry.deposit, |total, (_, deposit)| {
+                    total.saturating_add(deposit)
+                });
+            T::Currency::release(
+                &HoldReason::SponsorshipDeposit.into(),
+                &sponsor,
+                deposit,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::SponsorshipRemoved { sponsor });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Fees `sponsor` still accepts to pay, `None` if it does not sponsor anyone.
+        pub fn remaining_budget(sponsor: &T::AccountId) -> Option<T::Balance> {
+            Sponsorships::<T>::get(sponsor).map(|entry| entry.budget)
+        }
+
+        /// Whether `sponsor` relays the calls of `beneficiary`.
+        pub fn is_beneficiary(sponsor: &T::AccountId, beneficiary: &T::AccountId) -> bool {
+            Beneficiaries::<T>::contains_key(sponsor, beneficiary)
+        }
+
+        /// Check that `sponsor` can relay `call` signed by `beneficiary` for `fee`.
+        pub fn check(
+            sponsor: &T::AccountId,
+            beneficiary: &T::AccountId,
+            call: &<T as frame_system::Config>::RuntimeCall,
+            fee: T::Balance,
+        ) -> Result<(), SponsorshipError> {
+            let entry = Sponsorships::<T>::get(sponsor).ok_or(SponsorshipError::NoSponsorship)?;
+            if !Self::is_beneficiary(sponsor, beneficiary) {
+                return Err(SponsorshipError::NotBeneficiary);
+            }
+            if !entry.calls.filter(call) {
+                return Err(SponsorshipError::CallNotSponsored);
+            }
+            if entry.budget < fee {
+                return Err(SponsorshipError::BudgetExhausted);
+            }
+            Ok(())
+        }
+
+        /// Take `fee` from the budget of `sponsor`.
+        pub(crate) fn withdraw_budget(
+            sponsor: &T::AccountId,
+            fee: T::Balance,
+        ) -> Result<(), SponsorshipError> {
+            Sponsorships::<T>::try_mutate(sponsor, |entry| {
+                let entry = entry.as_mut().ok_or(SponsorshipError::NoSponsorship)?;
+                entry.budget = entry
+                    .budget
+                    .checked_sub(&fee)
+                    .ok_or(SponsorshipError::BudgetExhausted)?;
+                Ok(())
+            })
+        }
+
+        /// Settle a relay: give back the unused part of `withdrawn` and record the `actual` fee.
+        pub(crate) fn settle(
+            sponsor: &T::AccountId,
+            beneficiary: T::AccountId,
+            withdrawn: T::Balance,
+            actual: T::Balance,
+        ) {
+            let actual = actual.min(withdrawn);
+            let remaining = Sponsorships::<T>::mutate(sponsor, |entry| {
+                let entry = entry.as_mut()?;
+                entry
+                    .budget
+                    .saturating_accrue(withdrawn.saturating_sub(actual));
+                entry.spent.saturating_accrue(actual);
+                Some(entry.budget)
+            });
+
+            if let Some(remaining) = remaining {
+                Self::deposit_event(Event::Sponsored {
+                    sponsor: sponsor.clone(),
+                    beneficiary,
+                    fee: actual,
+                    remaining,
+                });
+            }
+        }
+    }
+}
+
+/// Reasons a relay is rejected, reported as `InvalidTransaction::Custom`.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[repr(u8)]
+pub enum SponsorshipError {
+    NoSponsorship = 1,
+    NotBeneficiary = 2,
+    CallNotSponsored = 3,
+    BudgetExhausted = 4,
+}
+
+impl From<SponsorshipError> for InvalidTransaction {
+    fn from(error: SponsorshipError) -> Self {
+        InvalidTransaction::Custom(error as u8)
+    }
+}
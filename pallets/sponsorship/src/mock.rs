@@ -0,0 +1,174 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{
+    derive_impl,
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    pallet_prelude::*,
+    parameter_types,
+    traits::InstanceFilter,
+};
+use sp_runtime::BuildStorage;
+
+use crate as pallet_sponsorship;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeTask,
+        RuntimeHoldReason
+    )]
+
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances;
+
+    #[runtime::pallet_index(2)]
+    pub type Sponsorship = pallet_sponsorship;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = frame_system::Pallet<Test>;
+}
+
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum SponsoredCalls {
+    Any,
+    #[default]
+    Remarks,
+}
+
+impl InstanceFilter<RuntimeCall> for SponsoredCalls {
+    fn filter(&self, call: &RuntimeCall) -> bool {
+        match self {
+            SponsoredCalls::Any => true,
+            SponsoredCalls::Remarks => {
+                matches!(call, RuntimeCall::System(frame_system::Call::remark { .. }))
+            }
+        }
+    }
+}
+
+/// Stands for `MetaTx::dispatch`: `remark_with_event` relays the call of the account in its first
+/// byte, a `remark` if the second byte is `0` and a `remark_with_event` otherwise.
+pub struct RelayedRemark;
+impl crate::InspectRelayedCall<RuntimeCall, u64> for RelayedRemark {
+    fn inspect(call: &RuntimeCall) -> Option<(u64, RuntimeCall)> {
+        let RuntimeCall::System(frame_system::Call::remark_with_event { remark }) = call else {
+            return None;
+        };
+        let relayed = match remark.get(1)? {
+            0 => frame_system::Call::remark { remark: vec![] },
+            _ => frame_system::Call::remark_with_event { remark: vec![] },
+        };
+        Some((u64::from(*remark.first()?), RuntimeCall::System(relayed)))
+    }
+}
+
+/// Fees are 10 per byte, the actual fee stands for a weight refund and is 6 per byte.
+pub struct LengthFee;
+impl crate::ComputeFee<RuntimeCall, u64> for LengthFee {
+    fn compute_fee(len: u32, _info: &DispatchInfo) -> u64 {
+        10 * u64::from(len)
+    }
+
+    fn compute_actual_fee(len: u32, _info: &DispatchInfo, _post_info: &PostDispatchInfo) -> u64 {
+        6 * u64::from(len)
+    }
+}
+
+/// Relays a `remark` of account 7.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<SponsoredCalls, RuntimeCall, u64> for BenchmarkHelper {
+    fn relayed_call() -> (u64, RuntimeCall, SponsoredCalls) {
+        let call = frame_system::Call::remark_with_event { remark: vec![7, 0] };
+        (7, RuntimeCall::System(call), SponsoredCalls::Remarks)
+    }
+}
+
+parameter_types! {
+    pub const MaxBeneficiaries: u32 = 2;
+    pub const SponsorshipDeposit: u64 = 100;
+    pub const BeneficiaryDeposit: u64 = 10;
+}
+
+impl pallet_sponsorship::Config for Test {
+    type Balance = u64;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type SponsorshipDeposit = SponsorshipDeposit;
+    type BeneficiaryDeposit = BeneficiaryDeposit;
+    type Fees = LengthFee;
+    type CallFilter = SponsoredCalls;
+    type RelayedCall = RelayedRemark;
+    type MaxBeneficiaries = MaxBeneficiaries;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = BenchmarkHelper;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 1000), (2, 1000), (3, 1000)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,219 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CheckSponsorship, Error, Event, SponsorshipError, Sponsorships, mock::*};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::fungible::InspectHold,
+};
+use sp_runtime::{
+    traits::TransactionExtension,
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+const SPONSOR: u64 = 1;
+const BENEFICIARY: u64 = 2;
+const LEN: usize = 10;
+
+/// A relay of a call signed by `beneficiary`, a `remark` if `remark` is true.
+fn relay(beneficiary: u8, remark: bool) -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark_with_event {
+        remark: vec![beneficiary, if remark { 0 } else { 1 }],
+    })
+}
+
+/// Submit `call` signed by `who` through the extension, as the block builder would.
+fn submit(who: u64, call: RuntimeCall) -> Result<(), TransactionValidityError> {
+    let info = call.get_dispatch_info();
+    let (pre, _) = CheckSponsorship::<Test>::new().validate_and_prepare(
+        RuntimeOrigin::signed(who),
+        &call,
+        &info,
+        LEN,
+        0,
+    )?;
+    CheckSponsorship::<Test>::post_dispatch(
+        pre,
+        &info,
+        &mut PostDispatchInfo::default(),
+        LEN,
+        &Ok(()),
+    )
+}
+
+fn sponsor(budget: u64) {
+    assert_ok!(Sponsorship::set_sponsorship(
+        RuntimeOrigin::signed(SPONSOR),
+        SponsoredCalls::Remarks,
+        budget
+    ));
+    assert_ok!(Sponsorship::add_beneficiary(
+        RuntimeOrigin::signed(SPONSOR),
+        BENEFICIARY
+    ));
+}
+
+fn rejected(error: SponsorshipError) -> Result<(), TransactionValidityError> {
+    Err(InvalidTransaction::from(error).into())
+}
+
+#[test]
+fn sponsor_manages_beneficiaries() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Sponsorship::add_beneficiary(RuntimeOrigin::signed(SPONSOR), BENEFICIARY),
+            Error::<Test>::NoSponsorship
+        );
+
+        sponsor(1_000);
+        assert!(Sponsorship::is_beneficiary(&SPONSOR, &BENEFICIARY));
+        assert_noop!(
+            Sponsorship::add_beneficiary(RuntimeOrigin::signed(SPONSOR), BENEFICIARY),
+            Error::<Test>::AlreadyBeneficiary
+        );
+        assert_ok!(Sponsorship::add_beneficiary(
+            RuntimeOrigin::signed(SPONSOR),
+            3
+        ));
+        assert_noop!(
+            Sponsorship::add_beneficiary(RuntimeOrigin::signed(SPONSOR), 4),
+            Error::<Test>::TooManyBeneficiaries
+        );
+
+        assert_ok!(Sponsorship::remove_beneficiary(
+            RuntimeOrigin::signed(SPONSOR),
+            BENEFICIARY
+        ));
+        assert!(!Sponsorship::is_beneficiary(&SPONSOR, &BENEFICIARY));
+        assert_eq!(Sponsorships::<Test>::get(SPONSOR).unwrap().beneficiaries, 1);
+
+        assert_ok!(Sponsorship::remove_sponsorship(RuntimeOrigin::signed(
+            SPONSOR
+        )));
+        assert!(!Sponsorship::is_beneficiary(&SPONSOR, &3));
+        assert_eq!(Sponsorship::remaining_budget(&SPONSOR), None);
+    });
+}
+
+#[test]
+fn deposits_are_held_and_released() {
+    new_test_ext().execute_with(|| {
+        let held = || Balances::total_balance_on_hold(&SPONSOR);
+
+        assert!(
+            Sponsorship::set_sponsorship(RuntimeOrigin::signed(4), SponsoredCalls::Remarks, 0)
+                .is_err()
+        );
+
+        sponsor(1_000);
+        assert_eq!(held(), 110);
+        // Updates do not take another deposit.
+        assert_ok!(Sponsorship::set_sponsorship(
+            RuntimeOrigin::signed(SPONSOR),
+            SponsoredCalls::Any,
+            2_000
+        ));
+        assert_ok!(Sponsorship::add_beneficiary(
+            RuntimeOrigin::signed(SPONSOR),
+            3
+        ));
+        assert_eq!(held(), 120);
+
+        assert_ok!(Sponsorship::remove_beneficiary(
+            RuntimeOrigin::signed(SPONSOR),
+            3
+        ));
+        assert_eq!(held(), 110);
+
+        assert_ok!(Sponsorship::remove_sponsorship(RuntimeOrigin::signed(
+            SPONSOR
+        )));
+        assert_eq!(held(), 0);
+        assert_eq!(Balances::free_balance(SPONSOR), 1_000);
+    });
+}
+
+#[test]
+fn relay_is_charged_against_budget() {
+    new_test_ext().execute_with(|| {
+        sponsor(1_000);
+
+        assert_ok!(submit(SPONSOR, relay(BENEFICIARY as u8, true)));
+
+        // 100 withdrawn before dispatch, 60 actually paid.
+        assert_eq!(Sponsorship::remaining_budget(&SPONSOR), Some(940));
+        assert_eq!(Sponsorships::<Test>::get(SPONSOR).unwrap().spent, 60);
+        System::assert_last_event(
+            Event::Sponsored {
+                sponsor: SPONSOR,
+                beneficiary: BENEFICIARY,
+                fee: 60,
+                remaining: 940,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn relay_outside_policy_is_rejected() {
+    new_test_ext().execute_with(|| {
+        sponsor(1_000);
+
+        assert_eq!(
+            submit(SPONSOR, relay(3, true)),
+            rejected(SponsorshipError::NotBeneficiary)
+        );
+        assert_eq!(
+            submit(SPONSOR, relay(BENEFICIARY as u8, false)),
+            rejected(SponsorshipError::CallNotSponsored)
+        );
+        assert_eq!(Sponsorship::remaining_budget(&SPONSOR), Some(1_000));
+    });
+}
+
+#[test]
+fn relay_above_budget_is_rejected() {
+    new_test_ext().execute_with(|| {
+        sponsor(150);
+
+        assert_ok!(submit(SPONSOR, relay(BENEFICIARY as u8, true)));
+        assert_eq!(Sponsorship::remaining_budget(&SPONSOR), Some(90));
+        assert_eq!(
+            submit(SPONSOR, relay(BENEFICIARY as u8, true)),
+            rejected(SponsorshipError::BudgetExhausted)
+        );
+    });
+}
+
+#[test]
+fn other_transactions_are_not_affected() {
+    new_test_ext().execute_with(|| {
+        sponsor(0);
+
+        // Not a sponsor.
+        assert_ok!(submit(3, relay(BENEFICIARY as u8, false)));
+        // Not a relay.
+        assert_ok!(submit(
+            SPONSOR,
+            RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+        ));
+        assert_eq!(Sponsorships::<Test>::get(SPONSOR).unwrap().spent, 0);
+    });
+}
@@ -0,0 +1,96 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_sponsorship.
+pub trait WeightInfo {
+    fn set_sponsorship() -> Weight;
+    fn add_beneficiary() -> Weight;
+    fn remove_beneficiary() -> Weight;
+    fn remove_sponsorship(n: u32) -> Weight;
+    fn check_sponsorship() -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `Sponsorship::Sponsorships` (r:1 w:1)
+    /// Proof: `Sponsorship::Sponsorships` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn set_sponsorship() -> Weight {
+        Weight::from_parts(48_000_000, 4764)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Sponsorship::Beneficiaries` (r:1 w:1)
+    /// Proof: `Sponsorship::Beneficiaries` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+    /// Storage: `Sponsorship::Sponsorships` (r:1 w:1)
+    /// Proof: `Sponsorship::Sponsorships` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn add_beneficiary() -> Weight {
+        Weight::from_parts(52_000_000, 4764)
+            .saturating_add(ParityDbWeight::get().reads(4_u64))
+            .saturating_add(ParityDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Sponsorship::Beneficiaries` (r:1 w:1)
+    /// Proof: `Sponsorship::Beneficiaries` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+    /// Storage: `Sponsorship::Sponsorships` (r:1 w:1)
+    /// Proof: `Sponsorship::Sponsorships` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn remove_beneficiary() -> Weight {
+        Weight::from_parts(50_000_000, 4764)
+            .saturating_add(ParityDbWeight::get().reads(4_u64))
+            .saturating_add(ParityDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Sponsorship::Sponsorships` (r:1 w:1)
+    /// Proof: `Sponsorship::Sponsorships` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Sponsorship::Beneficiaries` (r:100 w:100)
+    /// Proof: `Sponsorship::Beneficiaries` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 100]`.
+    fn remove_sponsorship(n: u32) -> Weight {
+        Weight::from_parts(47_000_000, 4764)
+            .saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(n.into()))
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+            .saturating_add(ParityDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2587).saturating_mul(n.into()))
+    }
+    /// Storage: `Sponsorship::Sponsorships` (r:1 w:1)
+    /// Proof: `Sponsorship::Sponsorships` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+    /// Storage: `Sponsorship::Beneficiaries` (r:1 w:0)
+    /// Proof: `Sponsorship::Beneficiaries` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+    fn check_sponsorship() -> Weight {
+        Weight::from_parts(14_390_000, 3577)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+}
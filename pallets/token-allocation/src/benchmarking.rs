@@ -36,6 +36,9 @@ fn setup_envelope<T: Config>(
         cliff,
         vesting_duration,
         unique_beneficiary: unique_beneficiary.clone(),
+        revocable: true,
+        transfer_approval: false,
+        curve: None,
     };
 
     Envelopes::<T>::insert(id, cfg.clone());
@@ -49,7 +52,6 @@ fn setup_envelope<T: Config>(
     if NextPayoutAt::<T>::get().is_zero() {
         NextPayoutAt::<T>::put(T::EpochDuration::get());
         EpochIndex::<T>::put(0u64);
-        PayoutCursor::<T>::kill();
     }
 }
 
@@ -61,12 +63,20 @@ fn force_allocation<T: Config>(
     emit_events: bool,
 ) {
     let cfg = Envelopes::<T>::get(id).expect("env set");
-    TokenAllocPallet::<T>::do_add_allocation(id, who, total, start, &cfg, emit_events)
-        .expect("allocation in benchmark cannot fail");
+    TokenAllocPallet::<T>::do_add_allocation(
+        id,
+        who,
+        total,
+        start,
+        Default::default(),
+        &cfg,
+        emit_events,
+    )
+    .expect("allocation in benchmark cannot fail");
 }
 
 /// Helper to make an allocation that will *definitely* pay something at `now`
-/// and be fully vested after that payout (worst-case route in payout_allocation):
+/// and be fully vested after that payout (worst-case route in claim):
 ///
 /// We do that by:
 /// - upfront_rate = 0 (so everything is vested_total)
@@ -75,7 +85,7 @@ fn force_allocation<T: Config>(
 /// - start = Some(0)
 ///
 /// Then at `now = 1`, `claimable_amount` == full vested_total.
-/// So payout_allocation() will:
+/// So claim() will:
 /// - call release(...)
 /// - see allocation fully vested
 /// - remove it from storage
@@ -101,6 +111,25 @@ fn setup_fully_vestable_allocation<T: Config>(
     who
 }
 
+/// A valid tranches curve with the maximum number of tranches.
+fn max_tranches<T: Config>() -> VestingCurve<BlockNumberFor<T>>
+where
+    BlockNumberFor<T>: From<u32>,
+{
+    let count = MaxTranches::get();
+    let tranches = (0..count)
+        .map(|i| {
+            let percent = if i == 0 {
+                100 - (count - 1) as u8 * (100 / count as u8)
+            } else {
+                100 / count as u8
+            };
+            (((i + 1) * 10).into(), Percent::from_percent(percent))
+        })
+        .collect::<sp_runtime::Vec<_>>();
+    VestingCurve::Tranches(tranches.try_into().expect("count is MaxTranches; qed"))
+}
+
 #[benchmarks(
     where
         BalanceOf<T>: From<u64> + Into<u128>,
@@ -155,7 +184,6 @@ mod benches {
         let future_epoch: BlockNumberFor<T> = 10u32.into();
         NextPayoutAt::<T>::put(future_epoch);
         EpochIndex::<T>::put(0u64);
-        PayoutCursor::<T>::kill();
 
         #[block]
         {
@@ -163,119 +191,535 @@ mod benches {
         }
     }
 
-    /// Benchmark for on_initialize() when we are IN an epoch (now >= NextPayoutAt),
-    /// and we hit MaxPayoutsPerBlock so we EXIT EARLY with a cursor (partial scan / pagination).
+    /// Benchmark for on_initialize() when an epoch elapses (now >= NextPayoutAt).
     ///
-    /// We want worst-case per-block cost:
-    /// - Have (MaxPayoutsPerBlock + 1) allocations that will all fully vest at `now`,
-    ///   so each payout_allocation() triggers the heavy path:
-    ///     - release()
-    ///     - remove allocation
-    ///     - dec_providers()
-    ///     - emit VestedReleased(...)
+    /// Vesting is released lazily on claim, so the hook only has to:
+    /// - bump EpochIndex
+    /// - set NextPayoutAt = now + EpochDuration
+    /// - emit EpochPayout
+    #[benchmark]
+    fn on_initialize_epoch() {
+        let now: BlockNumberFor<T> = 1u32.into();
+        NextPayoutAt::<T>::put(now);
+        EpochIndex::<T>::put(7u64);
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(now);
+        }
+
+        assert_eq!(EpochIndex::<T>::get(), 8u64);
+    }
+
+    /// Benchmark for `claim` extrinsic.
     ///
-    /// - After processing `limit = MaxPayoutsPerBlock`, the hook must:
-    ///     - leave some allocations unprocessed
-    ///     - set PayoutCursor(Some(next_id))
-    ///     - emit EpochPayout { cursor: Some(..) }
-    ///     - NOT bump EpochIndex
-    ///     - NOT bump NextPayoutAt
+    /// Worst case: the allocation is fully vested, so the claim releases the whole hold,
+    /// removes the allocation and decrements the beneficiary providers.
     #[benchmark]
-    fn on_initialize_partial() {
+    fn claim() {
         let ed = T::Currency::minimum_balance();
 
-        // 1. Prepare an envelope with:
-        //    upfront_rate = 0
-        //    cliff = 0
-        //    vesting_duration = 1
-        // So at now = 1, 100% is claimable.
         setup_envelope::<T>(
-            EnvelopeId::Airdrop,
-            ed.saturating_mul(10_000_000u64.into()),
+            EnvelopeId::Public2,
+            ed.saturating_mul(10_000u64.into()),
             Percent::from_percent(0),
             0u32.into(),
-            1u32.into(), // super short vesting
+            1u32.into(), // vest fully by block 1
             None,
         );
 
-        // Initialize epoch bookkeeping so that now >= NextPayoutAt triggers payout logic.
-        // We'll run on_initialize at block `now = 1`.
-        let now: BlockNumberFor<T> = 1u32.into();
-        NextPayoutAt::<T>::put(now); // force payout path
-        EpochIndex::<T>::put(0u64);
-        PayoutCursor::<T>::kill();
-        NextAllocationId::<T>::put(0);
-
-        // 2. Create N = MaxPayoutsPerBlock + 1 allocations that will fully vest at `now`.
-        let limit = T::MaxPayoutsPerBlock::get();
-        let total_allocs: u32 = limit.saturating_add(1);
-        let per_alloc_amount: BalanceOf<T> = ed.saturating_mul(1_000u64.into());
-
-        for i in 0..total_allocs {
-            let _benef = setup_fully_vestable_allocation::<T>(
-                i, // unique-ish seed for account
-                EnvelopeId::Airdrop,
-                per_alloc_amount, // same size for all
-            );
-        }
+        let id = NextAllocationId::<T>::get();
+        let who = setup_fully_vestable_allocation::<T>(
+            0,
+            EnvelopeId::Public2,
+            ed.saturating_mul(5_000u64.into()),
+        );
+        frame_system::Pallet::<T>::set_block_number(1u32.into());
 
-        // Sanity: we should have exactly total_allocs allocations in storage,
-        // with ids [0..total_allocs).
-        assert_eq!(NextAllocationId::<T>::get(), total_allocs);
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who), id);
 
-        #[block]
-        {
-            Pallet::<T>::on_initialize(now);
-        }
+        assert!(Allocations::<T>::get(id).is_none());
     }
 
-    /// Benchmark for on_initialize() when we are IN an epoch (now >= NextPayoutAt),
-    /// and we FINISH the epoch in this block (no pagination needed).
+    /// Benchmark for `revoke_allocation` extrinsic.
     ///
-    /// We want to exercise:
-    /// - process the remaining allocations (strictly fewer than MaxPayoutsPerBlock),
-    ///   each using the heavy payout_allocation path (full vest, remove allocation, etc.)
-    /// - clear PayoutCursor
-    /// - bump EpochIndex
-    /// - set NextPayoutAt = now + EpochDuration
-    /// - emit EpochPayout { cursor: None }
+    /// Worst case: the allocation is half vested, so the vested part is released to the
+    /// beneficiary and the rest is moved back to the envelope account.
     #[benchmark]
-    fn on_initialize_epoch_finished(x: Linear<1, { T::MaxPayoutsPerBlock::get() - 1 }>) {
+    fn revoke_allocation() {
         let ed = T::Currency::minimum_balance();
 
-        // Same "fully vest at now=1" envelope config.
         setup_envelope::<T>(
-            EnvelopeId::Public2,
-            ed.saturating_mul(10_000_000u64.into()),
+            EnvelopeId::Teams,
+            ed.saturating_mul(10_000u64.into()),
             Percent::from_percent(0),
             0u32.into(),
-            1u32.into(), // vest fully by block 1
+            100u32.into(),
             None,
         );
 
-        let now: BlockNumberFor<T> = 1u32.into();
-        NextPayoutAt::<T>::put(now);
-        EpochIndex::<T>::put(7u64);
-        PayoutCursor::<T>::kill();
-        NextAllocationId::<T>::put(0);
-
-        // create exactly `payouts` allocations fully vestable at `now`
-        let per_alloc_amount: BalanceOf<T> = ed.saturating_mul(5_000u64.into());
-        for i in 0..x {
-            let _ = setup_fully_vestable_allocation::<T>(
-                20_000 + i,
-                EnvelopeId::Public2,
-                per_alloc_amount,
-            );
-        }
+        let id = NextAllocationId::<T>::get();
+        let who: T::AccountId = account("recipient", 0, 0u32);
+        force_allocation::<T>(
+            EnvelopeId::Teams,
+            &who,
+            ed.saturating_mul(5_000u64.into()),
+            Some(Zero::zero()),
+            false,
+        );
+        frame_system::Pallet::<T>::set_block_number(50u32.into());
 
-        // Sanity: after this loop, we should have NextAllocationId == payouts.
-        assert_eq!(NextAllocationId::<T>::get(), x);
+        #[extrinsic_call]
+        _(RawOrigin::Root, id);
 
-        #[block]
-        {
-            Pallet::<T>::on_initialize(now);
+        assert!(Allocations::<T>::get(id).is_none());
+    }
+
+    /// Benchmark for `transfer_allocation` extrinsic.
+    #[benchmark]
+    fn transfer_allocation() {
+        let ed = T::Currency::minimum_balance();
+
+        setup_envelope::<T>(
+            EnvelopeId::Private1,
+            ed.saturating_mul(10_000u64.into()),
+            Percent::from_percent(0),
+            0u32.into(),
+            100u32.into(),
+            None,
+        );
+
+        let id = NextAllocationId::<T>::get();
+        let who: T::AccountId = account("recipient", 0, 0u32);
+        let to: T::AccountId = account("recipient", 0, 1u32);
+        force_allocation::<T>(
+            EnvelopeId::Private1,
+            &who,
+            ed.saturating_mul(5_000u64.into()),
+            Some(Zero::zero()),
+            false,
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who), id, to.clone());
+
+        assert_eq!(PendingTransfers::<T>::get(id).map(|p| p.to), Some(to));
+    }
+
+    /// Benchmark for `approve_transfer` extrinsic.
+    #[benchmark]
+    fn approve_transfer() {
+        let ed = T::Currency::minimum_balance();
+
+        setup_envelope::<T>(
+            EnvelopeId::Private2,
+            ed.saturating_mul(10_000u64.into()),
+            Percent::from_percent(0),
+            0u32.into(),
+            100u32.into(),
+            None,
+        );
+
+        let id = NextAllocationId::<T>::get();
+        let who: T::AccountId = account("recipient", 0, 0u32);
+        let to: T::AccountId = account("recipient", 0, 1u32);
+        force_allocation::<T>(
+            EnvelopeId::Private2,
+            &who,
+            ed.saturating_mul(5_000u64.into()),
+            Some(Zero::zero()),
+            false,
+        );
+        PendingTransfers::<T>::insert(
+            id,
+            PendingTransfer {
+                to,
+                approved: false,
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, id);
+
+        assert_eq!(
+            PendingTransfers::<T>::get(id).map(|p| p.approved),
+            Some(true)
+        );
+    }
+
+    /// Benchmark for `accept_transfer` extrinsic.
+    #[benchmark]
+    fn accept_transfer() {
+        let ed = T::Currency::minimum_balance();
+
+        setup_envelope::<T>(
+            EnvelopeId::Private2,
+            ed.saturating_mul(10_000u64.into()),
+            Percent::from_percent(0),
+            0u32.into(),
+            100u32.into(),
+            None,
+        );
+
+        let id = NextAllocationId::<T>::get();
+        let who: T::AccountId = account("recipient", 0, 0u32);
+        let to: T::AccountId = account("recipient", 0, 1u32);
+        force_allocation::<T>(
+            EnvelopeId::Private2,
+            &who,
+            ed.saturating_mul(5_000u64.into()),
+            Some(Zero::zero()),
+            false,
+        );
+        PendingTransfers::<T>::insert(
+            id,
+            PendingTransfer {
+                to: to.clone(),
+                approved: true,
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(to.clone()), id);
+
+        assert_eq!(Allocations::<T>::get(id).map(|a| a.beneficiary), Some(to));
+    }
+
+    /// Benchmark for `add_allocation_with_overrides` extrinsic.
+    ///
+    /// Worst case: the allocation overrides the curve with the maximum number of tranches.
+    #[benchmark]
+    fn add_allocation_with_overrides() -> Result<(), BenchmarkError> {
+        let who: T::AccountId = account("recipient", 0, 0u32);
+        let ed = T::Currency::minimum_balance();
+
+        setup_envelope::<T>(
+            EnvelopeId::Private1,
+            ed.saturating_mul(100.into()),
+            Percent::from_percent(20),
+            10u32.into(),
+            100u32.into(),
+            None,
+        );
+
+        let overrides = VestingOverride {
+            cliff: Some(20u32.into()),
+            curve: Some(max_tranches::<T>()),
+        };
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            EnvelopeId::Private1,
+            who.clone(),
+            ed.saturating_mul(50.into()),
+            None,
+            overrides,
+        );
+
+        Ok(())
+    }
+
+    /// Benchmark for `set_envelope_curve` extrinsic.
+    #[benchmark]
+    fn set_envelope_curve() {
+        setup_envelope::<T>(
+            EnvelopeId::Private1,
+            Zero::zero(),
+            Percent::from_percent(0),
+            10u32.into(),
+            100u32.into(),
+            None,
+        );
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            EnvelopeId::Private1,
+            Some(max_tranches::<T>()),
+        );
+
+        assert!(Envelopes::<T>::get(EnvelopeId::Private1).is_some_and(|c| c.curve.is_some()));
+    }
+
+    /// Benchmark for `force_settle` extrinsic.
+    ///
+    /// Worst case: the envelope of the allocation is gone, so everything still held is
+    /// released, the allocation is removed and its failed release cleared.
+    #[benchmark]
+    fn force_settle() {
+        let ed = T::Currency::minimum_balance();
+        let amount = ed.saturating_mul(5_000u64.into());
+
+        setup_envelope::<T>(
+            EnvelopeId::Teams,
+            ed.saturating_mul(10_000u64.into()),
+            Percent::from_percent(0),
+            0u32.into(),
+            100u32.into(),
+            None,
+        );
+
+        let id = NextAllocationId::<T>::get();
+        let who: T::AccountId = account("recipient", 0, 0u32);
+        force_allocation::<T>(EnvelopeId::Teams, &who, amount, Some(Zero::zero()), false);
+        Envelopes::<T>::remove(EnvelopeId::Teams);
+        FailedReleases::<T>::put(
+            BoundedVec::try_from(
+                (0..T::MaxFailedReleases::get())
+                    .map(|i| FailedRelease {
+                        id: id.saturating_add(i),
+                        amount,
+                        retries: 0,
+                    })
+                    .collect::<sp_runtime::Vec<_>>(),
+            )
+            .expect("length is MaxFailedReleases; qed"),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, id);
+
+        assert!(Allocations::<T>::get(id).is_none());
+    }
+
+    /// Benchmark for `set_airdrop_root` extrinsic.
+    #[benchmark]
+    fn set_airdrop_root() {
+        let ed = T::Currency::minimum_balance();
+
+        setup_envelope::<T>(
+            EnvelopeId::Airdrop,
+            ed.saturating_mul(10_000u64.into()),
+            Percent::from_percent(100),
+            0u32.into(),
+            0u32.into(),
+            None,
+        );
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            EnvelopeId::Airdrop,
+            H256::repeat_byte(1),
+            ed.saturating_mul(5_000u64.into()),
+            100u32.into(),
+        );
+
+        assert!(Airdrops::<T>::contains_key(EnvelopeId::Airdrop));
+    }
+
+    /// Benchmark for `claim_airdrop` extrinsic, submitted unsigned with a proof of `p` hashes.
+    #[benchmark]
+    fn claim_airdrop(p: Linear<0, { MaxProofLength::get() }>) {
+        let ed = T::Currency::minimum_balance();
+        let who: T::AccountId = account("recipient", 0, 0u32);
+        let amount = ed.saturating_mul(100u64.into());
+
+        setup_envelope::<T>(
+            EnvelopeId::Airdrop,
+            ed.saturating_mul(10_000u64.into()),
+            Percent::from_percent(100),
+            0u32.into(),
+            0u32.into(),
+            None,
+        );
+
+        let proof: BoundedVec<H256, MaxProofLength> = (0..p)
+            .map(|i| H256::repeat_byte(i as u8))
+            .collect::<sp_runtime::Vec<_>>()
+            .try_into()
+            .expect("p is at most MaxProofLength; qed");
+        let root = proof
+            .iter()
+            .fold(airdrop_leaf(0, &who, amount), |node, sibling| {
+                airdrop_node(node, *sibling)
+            });
+        Airdrops::<T>::insert(
+            EnvelopeId::Airdrop,
+            Airdrop {
+                root,
+                total: ed.saturating_mul(5_000u64.into()),
+                claimed: Zero::zero(),
+                deadline: 100u32.into(),
+            },
+        );
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::None,
+            EnvelopeId::Airdrop,
+            0,
+            who.clone(),
+            amount,
+            proof,
+        );
+
+        assert_eq!(T::Currency::balance(&who), amount);
+    }
+
+    /// Benchmark for `sweep_airdrop` extrinsic.
+    #[benchmark]
+    fn sweep_airdrop() {
+        let ed = T::Currency::minimum_balance();
+        let caller: T::AccountId = whitelisted_caller();
+
+        setup_envelope::<T>(
+            EnvelopeId::Airdrop,
+            ed.saturating_mul(10_000u64.into()),
+            Percent::from_percent(100),
+            0u32.into(),
+            0u32.into(),
+            None,
+        );
+        Airdrops::<T>::insert(
+            EnvelopeId::Airdrop,
+            Airdrop {
+                root: H256::repeat_byte(1),
+                total: ed.saturating_mul(5_000u64.into()),
+                claimed: ed.saturating_mul(1_000u64.into()),
+                deadline: 1u32.into(),
+            },
+        );
+        frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), EnvelopeId::Airdrop);
+
+        assert!(!Airdrops::<T>::contains_key(EnvelopeId::Airdrop));
+        assert!(SweptAirdropRoots::<T>::contains_key(H256::repeat_byte(1)));
+    }
+
+    /// Benchmark for `clear_airdrop_bitmap` extrinsic.
+    ///
+    /// Worst case: the `n` words left are all removed and the root is released.
+    #[benchmark]
+    fn clear_airdrop_bitmap(n: Linear<0, 1_000>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let root = H256::repeat_byte(1);
+
+        SweptAirdropRoots::<T>::insert(root, ());
+        for word in 0..n {
+            AirdropClaimBitmap::<T>::insert(root, word, u128::MAX);
         }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), root, n.saturating_add(1));
+
+        assert!(!SweptAirdropRoots::<T>::contains_key(root));
+        assert!(
+            AirdropClaimBitmap::<T>::iter_key_prefix(root)
+                .next()
+                .is_none()
+        );
+    }
+
+    /// Benchmark for `create_envelope` extrinsic.
+    ///
+    /// Worst case: the envelope is funded out of another envelope, whose cap is lowered.
+    #[benchmark]
+    fn create_envelope() {
+        let ed = T::Currency::minimum_balance();
+        let total_cap = ed.saturating_mul(5_000u64.into());
+
+        setup_envelope::<T>(
+            EnvelopeId::Reserve,
+            ed.saturating_mul(10_000u64.into()),
+            Percent::from_percent(0),
+            0u32.into(),
+            100u32.into(),
+            None,
+        );
+        let config = EnvelopeConfig {
+            total_cap,
+            upfront_rate: Percent::from_percent(0),
+            cliff: 10u32.into(),
+            vesting_duration: 100u32.into(),
+            unique_beneficiary: None,
+            revocable: true,
+            transfer_approval: true,
+            curve: None,
+        };
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            EnvelopeId::Custom(0),
+            config,
+            EnvelopeFunds::Envelope(EnvelopeId::Reserve),
+        );
+
+        assert_eq!(
+            T::Currency::balance(&EnvelopeId::Custom(0).account::<T>()),
+            total_cap
+        );
+    }
+
+    /// Benchmark for `set_envelope_cap` extrinsic.
+    #[benchmark]
+    fn set_envelope_cap() {
+        let ed = T::Currency::minimum_balance();
+
+        setup_envelope::<T>(
+            EnvelopeId::Private1,
+            ed.saturating_mul(10_000u64.into()),
+            Percent::from_percent(0),
+            0u32.into(),
+            100u32.into(),
+            None,
+        );
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            EnvelopeId::Private1,
+            ed.saturating_mul(5_000u64.into()),
+        );
+
+        assert!(
+            Envelopes::<T>::get(EnvelopeId::Private1)
+                .is_some_and(|c| c.total_cap == ed.saturating_mul(5_000u64.into()))
+        );
+    }
+
+    /// Benchmark for `close_envelope` extrinsic.
+    ///
+    /// Worst case: the envelope distributed funds so it is kept, and its undistributed balance
+    /// goes back to another envelope.
+    #[benchmark]
+    fn close_envelope() {
+        let ed = T::Currency::minimum_balance();
+
+        setup_envelope::<T>(
+            EnvelopeId::Reserve,
+            ed.saturating_mul(10_000u64.into()),
+            Percent::from_percent(0),
+            0u32.into(),
+            100u32.into(),
+            None,
+        );
+        setup_envelope::<T>(
+            EnvelopeId::Private1,
+            ed.saturating_mul(10_000u64.into()),
+            Percent::from_percent(0),
+            0u32.into(),
+            100u32.into(),
+            None,
+        );
+        EnvelopeDistributed::<T>::insert(EnvelopeId::Private1, ed.saturating_mul(1_000u64.into()));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            EnvelopeId::Private1,
+            EnvelopeFunds::Envelope(EnvelopeId::Reserve),
+        );
+
+        assert!(
+            Envelopes::<T>::get(EnvelopeId::Reserve)
+                .is_some_and(|c| c.total_cap == ed.saturating_mul(19_000u64.into()))
+        );
     }
 
     impl_benchmark_test_suite!(
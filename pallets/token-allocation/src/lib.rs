@@ -18,6 +18,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+use alloc::vec::Vec;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -28,6 +31,8 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+pub mod runtime_api;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -35,21 +40,26 @@ use frame_support::pallet_prelude::*;
 use frame_support::traits::fungible::Inspect;
 use frame_support::{
     PalletId,
+    storage::with_storage_layer,
     traits::{
         fungible::{Mutate, MutateHold},
-        tokens::{Fortitude, Precision, Preservation},
+        tokens::{Fortitude, Precision, Preservation, Restriction},
     },
 };
 use frame_system::pallet_prelude::OriginFor;
 use frame_system::pallet_prelude::*;
 use serde::{Deserialize, Serialize};
-use sp_core::U256;
+use sp_core::{H256, U256};
 use sp_runtime::Percent;
-use sp_runtime::traits::{AccountIdConversion, SaturatedConversion, Saturating, Zero};
+use sp_runtime::traits::{
+    AccountIdConversion, AtLeast32BitUnsigned, CheckedSub, SaturatedConversion, Saturating, Zero,
+};
 
 type EnvConfigOf<T> =
     EnvelopeConfig<BalanceOf<T>, BlockNumberFor<T>, <T as frame_system::Config>::AccountId>;
 type AllocationFor<T> = Allocation<AccountIdFor<T>, BalanceOf<T>, BlockNumberFor<T>>;
+type FailedReleaseOf<T> = FailedRelease<BalanceOf<T>>;
+type AirdropOf<T> = Airdrop<BalanceOf<T>, BlockNumberFor<T>>;
 type InitialAllocation<T> = (
     EnvelopeId,
     <T as frame_system::Config>::AccountId,
@@ -88,18 +98,141 @@ pub enum EnvelopeId {
     Listing,
     ResearchDevelopment,
     Reserve,
+    /// An envelope created by governance after genesis.
+    Custom(u32),
 }
 
 impl EnvelopeId {
     pub fn account<T: pallet::Config>(&self) -> T::AccountId {
         let pid = <T as pallet::Config>::PalletId::get();
-        pid.into_sub_account_truncating(*self as u8)
+        // Fieldless variants encode as their index, the sub-account of the genesis envelopes
+        // is unchanged.
+        pid.into_sub_account_truncating(self)
+    }
+}
+
+/// Maximum number of tranches of a [`VestingCurve::Tranches`] schedule.
+pub type MaxTranches = ConstU32<16>;
+
+/// How the vested part of an allocation unlocks after its cliff.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+)]
+pub enum VestingCurve<BlockNumber> {
+    /// Unlocks linearly over `duration` blocks.
+    Linear { duration: BlockNumber },
+    /// Unlocks in `count` equal steps, one every `period` blocks.
+    Stepped { period: BlockNumber, count: u32 },
+    /// Unlocks the given percentage once the given number of blocks elapsed. Percentages must add
+    /// up to 100% and blocks be strictly increasing.
+    Tranches(BoundedVec<(BlockNumber, Percent), MaxTranches>),
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> VestingCurve<BlockNumber> {
+    /// Whether the curve eventually unlocks everything.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Linear { .. } => true,
+            Self::Stepped { period, count } => !period.is_zero() && *count > 0,
+            Self::Tranches(tranches) => {
+                let total: u32 = tranches.iter().map(|(_, p)| p.deconstruct() as u32).sum();
+                let increasing = tranches.windows(2).all(|w| w[0].0 < w[1].0);
+                total == 100 && increasing
+            }
+        }
+    }
+
+    /// Part of `total` unlocked `elapsed` blocks after the cliff.
+    ///
+    /// SAFETY: We perform calculation in U256 to avoid overflow of (Balance * BlockNumber)
+    pub fn vested<Balance: AtLeast32BitUnsigned + Copy>(
+        &self,
+        total: Balance,
+        elapsed: BlockNumber,
+    ) -> Balance {
+        let (numerator, denominator) = match self {
+            Self::Linear { duration } => {
+                if elapsed >= *duration {
+                    return total;
+                }
+                (
+                    U256::from(elapsed.saturated_into::<u128>()),
+                    U256::from(duration.saturated_into::<u128>()),
+                )
+            }
+            Self::Stepped { period, count } => {
+                if period.is_zero() {
+                    return total;
+                }
+                let steps = (elapsed / *period).saturated_into::<u128>();
+                if steps >= *count as u128 {
+                    return total;
+                }
+                (U256::from(steps), U256::from(*count))
+            }
+            Self::Tranches(tranches) => {
+                let unlocked: u32 = tranches
+                    .iter()
+                    .filter(|(at, _)| *at <= elapsed)
+                    .map(|(_, p)| p.deconstruct() as u32)
+                    .sum();
+                if unlocked >= 100 {
+                    return total;
+                }
+                (U256::from(unlocked), U256::from(100u32))
+            }
+        };
+
+        let total_u256 = U256::from(total.saturated_into::<u128>());
+        // Calculate: (Total * Numerator) / Denominator
+        let vested_u256 = total_u256.saturating_mul(numerator) / denominator;
+        vested_u256.as_u128().saturated_into()
     }
 }
 
+/// Where the funds of an envelope come from when it is created, or go to when it is closed.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum EnvelopeFunds {
+    /// The undistributed part of another envelope, its cap changes with its funds.
+    Envelope(EnvelopeId),
+    /// The `FundingAccount`, filled by treasury spends.
+    FundingAccount,
+}
+
+/// Per-allocation overrides of the envelope vesting schedule.
 #[derive(
     Encode,
     Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Default,
+)]
+pub struct VestingOverride<BlockNumber> {
+    /// Cliff to use instead of the envelope one.
+    pub cliff: Option<BlockNumber>,
+    /// Curve to use instead of the envelope one.
+    pub curve: Option<VestingCurve<BlockNumber>>,
+}
+
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
     Clone,
     PartialEq,
     Eq,
@@ -115,6 +248,17 @@ pub struct EnvelopeConfig<Balance, BlockNumber, AccountId> {
     pub cliff: BlockNumber,
     pub vesting_duration: BlockNumber,
     pub unique_beneficiary: Option<AccountId>,
+    /// Whether `AdminOrigin` can revoke allocations of this envelope and claw back their
+    /// unvested part.
+    #[serde(default)]
+    pub revocable: bool,
+    /// Whether transfers of allocations of this envelope to a new beneficiary must be approved
+    /// by `AdminOrigin`.
+    #[serde(default)]
+    pub transfer_approval: bool,
+    /// Vesting curve of the envelope, linear over `vesting_duration` if not set.
+    #[serde(default)]
+    pub curve: Option<VestingCurve<BlockNumber>>,
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -126,14 +270,98 @@ pub struct Allocation<AccountId, Balance, BlockNumber> {
     pub vested_total: Balance,
     pub released: Balance,
     pub start: BlockNumber,
+    pub overrides: VestingOverride<BlockNumber>,
 }
 
-type AllocationId = u32;
+pub type AllocationId = u32;
+
+/// Maximum number of buckets of [`Pallet::unlock_schedule`].
+pub const MAX_UNLOCK_BUCKETS: u32 = 120;
+
+/// Distribution figures of an envelope.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EnvelopeTotals<Balance> {
+    /// Maximum amount the envelope can distribute.
+    pub total_cap: Balance,
+    /// Amount allocated so far.
+    pub distributed: Balance,
+    /// Amount that can still be allocated.
+    pub remaining: Balance,
+    /// Amount allocated and still held for vesting.
+    pub locked: Balance,
+}
+
+/// A transfer of an allocation waiting for its recipient and, if required, for governance.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingTransfer<AccountId> {
+    /// The new beneficiary, who must accept the transfer.
+    pub to: AccountId,
+    /// Whether `AdminOrigin` approved the transfer, always set for envelopes without
+    /// `transfer_approval`.
+    pub approved: bool,
+}
+
+/// A vested release that could not be paid out, waiting to be retried.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FailedRelease<Balance> {
+    /// The allocation whose release failed.
+    pub id: AllocationId,
+    /// Amount of the last failed release.
+    pub amount: Balance,
+    /// How many times the release failed again after it was queued.
+    pub retries: u32,
+}
+
+/// Maximum number of hashes of an airdrop Merkle proof, enough for 2^32 leaves.
+pub type MaxProofLength = ConstU32<32>;
+
+/// A Merkle airdrop committed for an envelope.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Airdrop<Balance, BlockNumber> {
+    /// Root of the tree of leaves built with [`airdrop_leaf`] and [`airdrop_node`].
+    pub root: H256,
+    /// Sum of the amounts of all leaves, reserved on the envelope.
+    pub total: Balance,
+    /// Amount claimed so far.
+    pub claimed: Balance,
+    /// Last block at which claims are accepted.
+    pub deadline: BlockNumber,
+}
+
+/// Hash of the airdrop leaf giving `amount` to `who`, `index` is the position of the leaf and
+/// identifies it once claimed.
+pub fn airdrop_leaf<AccountId: Encode, Balance: Encode>(
+    index: u32,
+    who: &AccountId,
+    amount: Balance,
+) -> H256 {
+    H256(sp_io::hashing::blake2_256(&(index, who, amount).encode()))
+}
+
+/// Hash of an airdrop tree node. Children are sorted, so proofs do not need to tell on which side
+/// each sibling is.
+pub fn airdrop_node(a: H256, b: H256) -> H256 {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    H256(sp_io::hashing::blake2_256(
+        &[left.as_bytes(), right.as_bytes()].concat(),
+    ))
+}
+
+/// Whether `proof`, the siblings from the leaf up, links `leaf` to `root`.
+pub fn verify_airdrop_proof(root: H256, leaf: H256, proof: &[H256]) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| airdrop_node(node, *sibling))
+        == root
+}
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
@@ -144,12 +372,29 @@ pub mod pallet {
         #[pallet::constant]
         type PalletId: Get<PalletId>;
 
+        /// Length of a payout epoch. Vested balances are released lazily on claim, epochs are
+        /// only reported through [`Event::EpochPayout`].
         #[pallet::constant]
         type EpochDuration: Get<BlockNumberFor<Self>>;
 
-        /// A safety hard-cap on how many allocations can be processed in one block.
+        /// Maximum number of allocations an account can be the beneficiary of.
         #[pallet::constant]
-        type MaxPayoutsPerBlock: Get<u32>;
+        type MaxAllocationsPerAccount: Get<u32>;
+
+        /// Maximum number of failed releases kept for retry.
+        #[pallet::constant]
+        type MaxFailedReleases: Get<u32>;
+
+        /// Account receiving the unclaimed funds of an airdrop after its deadline.
+        type SweepDestination: Get<Self::AccountId>;
+
+        /// Account funding new envelopes and receiving the funds of closed ones, besides other
+        /// envelopes.
+        type FundingAccount: Get<Self::AccountId>;
+
+        /// Priority of unsigned airdrop claims.
+        #[pallet::constant]
+        type AirdropUnsignedPriority: Get<TransactionPriority>;
 
         /// The overarching HoldReason type.
         type RuntimeHoldReason: From<HoldReason>;
@@ -158,6 +403,7 @@ pub mod pallet {
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::composite_enum]
@@ -173,10 +419,36 @@ pub mod pallet {
     pub type EnvelopeDistributed<T: Config> =
         StorageMap<_, Blake2_128Concat, EnvelopeId, BalanceOf<T>, ValueQuery>;
 
+    /// Envelopes closed by `close_envelope`, which cannot distribute anything anymore.
+    #[pallet::storage]
+    pub type ClosedEnvelopes<T: Config> =
+        StorageMap<_, Blake2_128Concat, EnvelopeId, (), OptionQuery>;
+
     #[pallet::storage]
     pub type Allocations<T: Config> =
         StorageMap<_, Blake2_128Concat, AllocationId, AllocationFor<T>, OptionQuery>;
 
+    /// Allocations of which an account is the beneficiary.
+    #[pallet::storage]
+    pub type AllocationsOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<AllocationId, T::MaxAllocationsPerAccount>,
+        ValueQuery,
+    >;
+
+    /// Transfers of allocations waiting for the acceptance of the new beneficiary and, if the
+    /// envelope requires it, the approval of `AdminOrigin`.
+    #[pallet::storage]
+    pub type PendingTransfers<T: Config> =
+        StorageMap<_, Blake2_128Concat, AllocationId, PendingTransfer<T::AccountId>, OptionQuery>;
+
+    /// Releases that failed, retried one per epoch until they go through or are force-settled.
+    #[pallet::storage]
+    pub type FailedReleases<T: Config> =
+        StorageValue<_, BoundedVec<FailedReleaseOf<T>, T::MaxFailedReleases>, ValueQuery>;
+
     #[pallet::storage]
     pub type NextAllocationId<T: Config> = StorageValue<_, AllocationId, ValueQuery>;
 
@@ -184,83 +456,50 @@ pub mod pallet {
     pub type NextPayoutAt<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
     #[pallet::storage]
-    pub type PayoutCursor<T: Config> = StorageValue<_, AllocationId, OptionQuery>;
+    pub type EpochIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+    /// Merkle airdrops of envelopes.
     #[pallet::storage]
-    pub type EpochIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
+    pub type Airdrops<T: Config> =
+        StorageMap<_, Blake2_128Concat, EnvelopeId, AirdropOf<T>, OptionQuery>;
+
+    /// Claimed leaves of airdrops, in words of 128 leaves. Keyed by root so that a new airdrop of
+    /// the envelope starts with no claimed leaf.
+    #[pallet::storage]
+    pub type AirdropClaimBitmap<T: Config> =
+        StorageDoubleMap<_, Identity, H256, Twox64Concat, u32, u128, ValueQuery>;
+
+    /// Roots of swept airdrops whose claim bitmap is left to clear with `clear_airdrop_bitmap`.
+    #[pallet::storage]
+    pub type SweptAirdropRoots<T: Config> = StorageMap<_, Identity, H256, (), OptionQuery>;
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(now: BlockNumberFor<T>) -> Weight {
-            let next_epoch_at = NextPayoutAt::<T>::get();
-
-            // 1. Check if it is time for a new epoch OR if we are finishing a pending epoch (cursor exists)
-            let cursor = PayoutCursor::<T>::get();
-
-            if now < next_epoch_at && cursor.is_none() {
+            if now < NextPayoutAt::<T>::get() {
                 return T::WeightInfo::on_initialize_noop();
             }
 
-            // 2. Initialize processing variables
-            let limit = T::MaxPayoutsPerBlock::get();
-            let mut processed_count = 0u32;
-
-            // 3. Determine where to start iterating
-            let iter = match cursor {
-                Some(last_key) => Allocations::<T>::iter_keys_from_key(last_key),
-                None => Allocations::<T>::iter_keys(), // Start from beginning
-            };
-
-            let mut last_processed_id: Option<AllocationId> = None;
-            let mut fully_finished = true;
-
-            // 4. The Loop
-            for id in iter {
-                if processed_count >= limit {
-                    fully_finished = false;
-                    break;
-                }
+            // Vested balances are computed from the schedule when claimed, so an epoch boundary
+            // only moves the epoch forward: the cost does not depend on the number of allocations.
+            let current_epoch = EpochIndex::<T>::get();
+            NextPayoutAt::<T>::put(now.saturating_add(T::EpochDuration::get()));
+            EpochIndex::<T>::put(current_epoch.saturating_add(1));
 
-                // We process the payout
-                // Note: This reads Storage, calculates math, and potentially Writes storage (release hold)
-                Self::payout_allocation(id, now);
+            Self::deposit_event(Event::EpochPayout {
+                epoch: current_epoch,
+                at: now,
+            });
 
-                last_processed_id = Some(id);
-                processed_count += 1;
+            if Self::retry_failed_release() {
+                return T::WeightInfo::on_initialize_epoch().saturating_add(T::WeightInfo::claim());
             }
+            T::WeightInfo::on_initialize_epoch()
+        }
 
-            // 5. Update State based on loop result
-            if fully_finished {
-                // Epoch complete
-                let current_epoch = EpochIndex::<T>::get();
-                let next_epoch_time = now.saturating_add(T::EpochDuration::get());
-
-                NextPayoutAt::<T>::put(next_epoch_time);
-                PayoutCursor::<T>::kill(); // Clear cursor
-                EpochIndex::<T>::put(current_epoch.saturating_add(1));
-
-                Self::deposit_event(Event::EpochPayout {
-                    epoch: current_epoch,
-                    at: now,
-                    cursor: None,
-                });
-
-                // Return weight for full completion
-                T::WeightInfo::on_initialize_epoch_finished(processed_count)
-            } else {
-                // Epoch ongoing, save cursor for next block
-                PayoutCursor::<T>::set(last_processed_id);
-
-                let current_epoch = EpochIndex::<T>::get();
-                Self::deposit_event(Event::EpochPayout {
-                    epoch: current_epoch,
-                    at: now,
-                    cursor: last_processed_id,
-                });
-
-                // Return weight for partial completion
-                T::WeightInfo::on_initialize_partial()
-            }
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
         }
     }
 
@@ -294,7 +533,14 @@ pub mod pallet {
                         cliff: cfg_in.cliff,
                         vesting_duration: cfg_in.vesting_duration,
                         unique_beneficiary: cfg_in.unique_beneficiary.clone(),
+                        revocable: cfg_in.revocable,
+                        transfer_approval: cfg_in.transfer_approval,
+                        curve: cfg_in.curve.clone(),
                     };
+                assert!(
+                    cfg.curve.as_ref().is_none_or(|c| c.is_valid()),
+                    "invalid envelope vesting curve in genesis"
+                );
 
                 let envelope_acc = id.account::<T>();
                 if !cfg.total_cap.is_zero() {
@@ -316,8 +562,16 @@ pub mod pallet {
                     );
                 }
 
-                Pallet::<T>::do_add_allocation(*id, who, *total, *start, &cfg, false)
-                    .expect("genesis allocation must succeed");
+                Pallet::<T>::do_add_allocation(
+                    *id,
+                    who,
+                    *total,
+                    *start,
+                    Default::default(),
+                    &cfg,
+                    false,
+                )
+                .expect("genesis allocation must succeed");
             }
 
             for (id, _) in &self.envelopes {
@@ -326,8 +580,16 @@ pub mod pallet {
                     let already = EnvelopeDistributed::<T>::get(id);
                     if already < cfg.total_cap {
                         let remaining = cfg.total_cap.saturating_sub(already);
-                        Pallet::<T>::do_add_allocation(*id, &benef, remaining, None, &cfg, false)
-                            .expect("auto unique beneficiary allocation must succeed");
+                        Pallet::<T>::do_add_allocation(
+                            *id,
+                            &benef,
+                            remaining,
+                            None,
+                            Default::default(),
+                            &cfg,
+                            false,
+                        )
+                        .expect("auto unique beneficiary allocation must succeed");
                     }
                 }
             }
@@ -335,20 +597,107 @@ pub mod pallet {
             // Epoch payout init
             NextPayoutAt::<T>::put(T::EpochDuration::get());
             EpochIndex::<T>::put(0u64);
-            PayoutCursor::<T>::kill();
         }
     }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        AllocationAdded(AllocationId),
-        UpfrontPaid(AllocationId),
-        VestedReleased(AllocationId, BalanceOf<T>),
-        EpochPayout {
-            epoch: u64,
-            at: BlockNumberFor<T>,
-            cursor: Option<AllocationId>,
+        /// An allocation was created, `upfront` was paid out of `total` right away.
+        AllocationAdded {
+            id: AllocationId,
+            envelope: EnvelopeId,
+            beneficiary: T::AccountId,
+            total: BalanceOf<T>,
+            upfront: BalanceOf<T>,
+        },
+        /// The upfront part of an allocation was paid to its beneficiary.
+        UpfrontPaid {
+            id: AllocationId,
+            envelope: EnvelopeId,
+            beneficiary: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Vested funds were released to the beneficiary, `remaining` are still held.
+        VestedReleased {
+            id: AllocationId,
+            envelope: EnvelopeId,
+            beneficiary: T::AccountId,
+            amount: BalanceOf<T>,
+            remaining: BalanceOf<T>,
+        },
+        /// A payout epoch elapsed. Funds vested during the epoch can be claimed.
+        EpochPayout { epoch: u64, at: BlockNumberFor<T> },
+        /// An allocation was revoked: `vested` was released to the beneficiary and
+        /// `clawed_back` returned to the envelope.
+        AllocationRevoked {
+            id: AllocationId,
+            envelope: EnvelopeId,
+            beneficiary: T::AccountId,
+            vested: BalanceOf<T>,
+            clawed_back: BalanceOf<T>,
+        },
+        /// The vesting curve of an envelope was changed.
+        EnvelopeCurveSet(EnvelopeId),
+        /// The beneficiary of an allocation requested a transfer, `to` must accept it.
+        TransferRequested {
+            id: AllocationId,
+            to: T::AccountId,
+            approved: bool,
+        },
+        /// `AdminOrigin` approved the pending transfer of an allocation.
+        TransferApproved { id: AllocationId, to: T::AccountId },
+        /// An allocation was transferred to a new beneficiary with its remaining held balance.
+        AllocationTransferred {
+            id: AllocationId,
+            envelope: EnvelopeId,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Releasing `amount` of an allocation failed, it was queued for retry.
+        ReleaseFailed {
+            id: AllocationId,
+            amount: BalanceOf<T>,
+            error: DispatchError,
+        },
+        /// A failed release was dropped from the retry queue as its allocation no longer exists.
+        FailedReleaseDropped { id: AllocationId },
+        /// A Merkle airdrop of `total` was committed for an envelope.
+        AirdropStarted {
+            envelope: EnvelopeId,
+            root: H256,
+            total: BalanceOf<T>,
+            deadline: BlockNumberFor<T>,
+        },
+        /// Leaf `index` of the airdrop of an envelope was claimed.
+        AirdropClaimed {
+            envelope: EnvelopeId,
+            index: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The unclaimed funds of an airdrop were sent to the sweep destination.
+        AirdropSwept {
+            envelope: EnvelopeId,
+            amount: BalanceOf<T>,
+        },
+        /// The claim bitmap of a swept airdrop was fully cleared.
+        AirdropBitmapCleared { root: H256 },
+        /// An envelope was created and funded with `total_cap`.
+        EnvelopeCreated {
+            id: EnvelopeId,
+            total_cap: BalanceOf<T>,
+        },
+        /// The cap of an envelope was changed.
+        EnvelopeCapSet {
+            id: EnvelopeId,
+            total_cap: BalanceOf<T>,
+        },
+        /// An envelope was closed and its undistributed balance `returned`.
+        EnvelopeClosed {
+            id: EnvelopeId,
+            returned: BalanceOf<T>,
         },
     }
 
@@ -360,6 +709,52 @@ pub mod pallet {
         ArithmeticOverflow,
         TooMuchAllocations,
         AllocationDisabled,
+        /// The allocation does not exist or is already fully released.
+        AllocationUnknown,
+        /// Only the beneficiary of the allocation can perform this operation.
+        NotBeneficiary,
+        /// Nothing is vested yet for this allocation.
+        NothingToClaim,
+        /// Allocations of this envelope cannot be revoked.
+        NotRevocable,
+        /// The vesting curve never unlocks the full allocation.
+        InvalidVestingCurve,
+        /// The new beneficiary is already the beneficiary of the allocation.
+        SameBeneficiary,
+        /// No transfer is pending for this allocation.
+        NoPendingTransfer,
+        /// Only the recipient of the pending transfer can accept it.
+        NotTransferRecipient,
+        /// The pending transfer must be approved by governance first.
+        TransferNotApproved,
+        /// Airdrops are only possible for envelopes paid fully upfront.
+        NotFullyUpfront,
+        /// The envelope already has an airdrop.
+        AirdropExists,
+        /// The envelope has no airdrop.
+        NoAirdrop,
+        /// The airdrop deadline has passed.
+        AirdropEnded,
+        /// The airdrop deadline has not passed yet.
+        AirdropNotEnded,
+        /// The Merkle proof does not match the airdrop root.
+        InvalidProof,
+        /// This airdrop leaf was already claimed.
+        AlreadyClaimed,
+        /// The claim bitmap of a swept airdrop with this root is not cleared yet.
+        AirdropBitmapNotCleared,
+        /// No swept airdrop with this root has a claim bitmap left to clear.
+        NoAirdropBitmap,
+        /// An envelope with this identifier already exists.
+        EnvelopeExists,
+        /// The cap would be lower than what the envelope already distributed.
+        CapBelowDistributed,
+        /// The envelope account does not hold enough undistributed funds for this cap.
+        InsufficientEnvelopeFunds,
+        /// The funds of an envelope cannot be moved to the envelope itself.
+        SameEnvelope,
+        /// The envelope was closed.
+        EnvelopeClosed,
     }
 
     #[pallet::call]
@@ -381,9 +776,588 @@ pub mod pallet {
                 return Err(Error::<T>::AllocationDisabled.into());
             }
 
-            Self::do_add_allocation(id, &who, total, start, &cfg, true)?;
+            Self::do_add_allocation(id, &who, total, start, Default::default(), &cfg, true)?;
+            Ok(())
+        }
+
+        /// Release the vested part of an allocation to its beneficiary.
+        ///
+        /// If the release fails, it is queued for retry and reported by `ReleaseFailed`. The
+        /// call then succeeds, and is charged as usual, so the queue entry is kept; the funds are
+        /// released by a later retry.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::claim())]
+        pub fn claim(origin: OriginFor<T>, id: AllocationId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_claim(id, Some(&who))
+        }
+
+        /// Release the vested part of an allocation to its beneficiary on their behalf.
+        ///
+        /// Funds always go to the beneficiary, this lets anyone settle allocations held by
+        /// keyless accounts such as the treasury. Failed releases are handled as in `claim`.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::claim())]
+        pub fn claim_for(origin: OriginFor<T>, id: AllocationId) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::do_claim(id, None)
+        }
+
+        /// Revoke an allocation of a revocable envelope.
+        ///
+        /// What is vested at the current block is released to the beneficiary, the unvested
+        /// remainder is returned to the envelope account and can be allocated again.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::revoke_allocation())]
+        pub fn revoke_allocation(origin: OriginFor<T>, id: AllocationId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let alloc = Allocations::<T>::get(id).ok_or(Error::<T>::AllocationUnknown)?;
+            let cfg = Envelopes::<T>::get(alloc.envelope).ok_or(Error::<T>::EnvelopeUnknown)?;
+            ensure!(cfg.revocable, Error::<T>::NotRevocable);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let vested = Self::claimable_amount(&cfg, &alloc, now);
+            let clawed_back = alloc
+                .vested_total
+                .saturating_sub(alloc.released)
+                .saturating_sub(vested);
+            let reason: T::RuntimeHoldReason = HoldReason::TokenAllocation.into();
+            // A closed envelope cannot distribute the clawed back funds again.
+            let closed = ClosedEnvelopes::<T>::contains_key(alloc.envelope);
+            let claw_back_to = if closed {
+                T::FundingAccount::get()
+            } else {
+                alloc.envelope.account::<T>()
+            };
+
+            if !vested.is_zero() {
+                T::Currency::release(&reason, &alloc.beneficiary, vested, Precision::Exact)?;
+            }
+            if !clawed_back.is_zero() {
+                T::Currency::transfer_on_hold(
+                    &reason,
+                    &alloc.beneficiary,
+                    &claw_back_to,
+                    clawed_back,
+                    Precision::Exact,
+                    Restriction::Free,
+                    Fortitude::Polite,
+                )?;
+            }
+
+            EnvelopeDistributed::<T>::mutate(alloc.envelope, |distributed| {
+                distributed.saturating_reduce(clawed_back)
+            });
+            if closed {
+                Envelopes::<T>::mutate(alloc.envelope, |cfg| {
+                    if let Some(cfg) = cfg {
+                        cfg.total_cap.saturating_reduce(clawed_back);
+                    }
+                });
+            }
+            Self::remove_allocation(id, &alloc.beneficiary);
+
+            Self::deposit_event(Event::AllocationRevoked {
+                id,
+                envelope: alloc.envelope,
+                beneficiary: alloc.beneficiary,
+                vested,
+                clawed_back,
+            });
+            Ok(())
+        }
+
+        /// Request the transfer of an allocation and its remaining held balance to a new
+        /// beneficiary.
+        ///
+        /// The transfer takes effect once `to` accepts it with `accept_transfer`, after the
+        /// approval of `approve_transfer` if the envelope requires it. Vesting parameters are
+        /// kept, a new request replaces a pending one.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::transfer_allocation())]
+        pub fn transfer_allocation(
+            origin: OriginFor<T>,
+            id: AllocationId,
+            to: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let alloc = Allocations::<T>::get(id).ok_or(Error::<T>::AllocationUnknown)?;
+            ensure!(alloc.beneficiary == who, Error::<T>::NotBeneficiary);
+            ensure!(alloc.beneficiary != to, Error::<T>::SameBeneficiary);
+            let cfg = Envelopes::<T>::get(alloc.envelope).ok_or(Error::<T>::EnvelopeUnknown)?;
+            Self::ensure_can_index(&to)?;
+
+            let approved = !cfg.transfer_approval;
+            PendingTransfers::<T>::insert(
+                id,
+                PendingTransfer {
+                    to: to.clone(),
+                    approved,
+                },
+            );
+            Self::deposit_event(Event::TransferRequested { id, to, approved });
+            Ok(())
+        }
+
+        /// Approve the pending transfer of an allocation, the recipient can then accept it.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::approve_transfer())]
+        pub fn approve_transfer(origin: OriginFor<T>, id: AllocationId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let mut pending =
+                PendingTransfers::<T>::get(id).ok_or(Error::<T>::NoPendingTransfer)?;
+            Self::ensure_can_index(&pending.to)?;
+
+            pending.approved = true;
+            let to = pending.to.clone();
+            PendingTransfers::<T>::insert(id, pending);
+            Self::deposit_event(Event::TransferApproved { id, to });
+            Ok(())
+        }
+
+        /// Same as `add_allocation`, with a cliff or vesting curve specific to this allocation.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::add_allocation_with_overrides())]
+        pub fn add_allocation_with_overrides(
+            origin: OriginFor<T>,
+            id: EnvelopeId,
+            who: T::AccountId,
+            total: BalanceOf<T>,
+            start: Option<BlockNumberFor<T>>,
+            overrides: VestingOverride<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let cfg = Envelopes::<T>::get(id).ok_or(Error::<T>::EnvelopeUnknown)?;
+
+            if cfg.unique_beneficiary.is_some() {
+                return Err(Error::<T>::AllocationDisabled.into());
+            }
+
+            Self::do_add_allocation(id, &who, total, start, overrides, &cfg, true)
+        }
+
+        /// Set the vesting curve of an envelope, or go back to linear vesting over
+        /// `vesting_duration` with `None`.
+        ///
+        /// Applies to the existing allocations of the envelope that do not override it.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_envelope_curve())]
+        pub fn set_envelope_curve(
+            origin: OriginFor<T>,
+            id: EnvelopeId,
+            curve: Option<VestingCurve<BlockNumberFor<T>>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                curve.as_ref().is_none_or(|c| c.is_valid()),
+                Error::<T>::InvalidVestingCurve
+            );
+            Envelopes::<T>::try_mutate(id, |cfg| -> DispatchResult {
+                let cfg = cfg.as_mut().ok_or(Error::<T>::EnvelopeUnknown)?;
+                cfg.curve = curve;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::EnvelopeCurveSet(id));
             Ok(())
         }
+
+        /// Settle a stuck allocation.
+        ///
+        /// Releases what is vested at the current block and clears its failed release. A failed
+        /// release of an allocation that no longer exists is dropped. Allocations of an envelope
+        /// that no longer exists have no schedule and cannot be settled.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::force_settle())]
+        pub fn force_settle(origin: OriginFor<T>, id: AllocationId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let Some(alloc) = Allocations::<T>::get(id) else {
+                ensure!(
+                    Self::clear_failed_release(id),
+                    Error::<T>::AllocationUnknown
+                );
+                Self::deposit_event(Event::FailedReleaseDropped { id });
+                return Ok(());
+            };
+
+            let cfg = Envelopes::<T>::get(alloc.envelope).ok_or(Error::<T>::EnvelopeUnknown)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            let amount = Self::claimable_amount(&cfg, &alloc, now);
+            ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+            Self::release_vested(id, alloc, amount)
+        }
+
+        /// Commit the root of a Merkle airdrop of `total` for an envelope paid fully upfront.
+        ///
+        /// `total` is reserved on the envelope. Leaves can be claimed with `claim_airdrop` until
+        /// `deadline`, the unclaimed part is then swept with `sweep_airdrop`.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_airdrop_root())]
+        pub fn set_airdrop_root(
+            origin: OriginFor<T>,
+            envelope: EnvelopeId,
+            root: H256,
+            total: BalanceOf<T>,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let cfg = Envelopes::<T>::get(envelope).ok_or(Error::<T>::EnvelopeUnknown)?;
+            ensure!(
+                cfg.unique_beneficiary.is_none(),
+                Error::<T>::AllocationDisabled
+            );
+            ensure!(
+                cfg.upfront_rate == Percent::from_percent(100),
+                Error::<T>::NotFullyUpfront
+            );
+            ensure!(
+                !ClosedEnvelopes::<T>::contains_key(envelope),
+                Error::<T>::EnvelopeClosed
+            );
+            ensure!(
+                !Airdrops::<T>::contains_key(envelope),
+                Error::<T>::AirdropExists
+            );
+            ensure!(
+                !SweptAirdropRoots::<T>::contains_key(root),
+                Error::<T>::AirdropBitmapNotCleared
+            );
+            ensure!(
+                deadline > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::AirdropEnded
+            );
+
+            EnvelopeDistributed::<T>::try_mutate(envelope, |distributed| -> DispatchResult {
+                let new_distributed = distributed.saturating_add(total);
+                ensure!(
+                    new_distributed <= cfg.total_cap,
+                    Error::<T>::EnvelopeCapExceeded
+                );
+                *distributed = new_distributed;
+                Ok(())
+            })?;
+            Airdrops::<T>::insert(
+                envelope,
+                Airdrop {
+                    root,
+                    total,
+                    claimed: Zero::zero(),
+                    deadline,
+                },
+            );
+
+            Self::deposit_event(Event::AirdropStarted {
+                envelope,
+                root,
+                total,
+                deadline,
+            });
+            Ok(())
+        }
+
+        /// Pay leaf `index` of the airdrop of an envelope to `who`.
+        ///
+        /// Can be submitted signed by anyone, or unsigned: the claim is then validated before
+        /// entering the pool.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::claim_airdrop(proof.len() as u32))]
+        pub fn claim_airdrop(
+            origin: OriginFor<T>,
+            envelope: EnvelopeId,
+            index: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+            proof: BoundedVec<H256, MaxProofLength>,
+        ) -> DispatchResult {
+            ensure_none(origin.clone()).or_else(|_| ensure_signed(origin).map(|_| ()))?;
+
+            let mut airdrop = Self::check_airdrop_claim(envelope, index, &who, amount, &proof)?;
+            T::Currency::transfer(
+                &envelope.account::<T>(),
+                &who,
+                amount,
+                Preservation::Expendable,
+            )?;
+            AirdropClaimBitmap::<T>::mutate(airdrop.root, index / 128, |word| {
+                *word |= 1u128 << (index % 128)
+            });
+            airdrop.claimed.saturating_accrue(amount);
+            Airdrops::<T>::insert(envelope, airdrop);
+
+            Self::deposit_event(Event::AirdropClaimed {
+                envelope,
+                index,
+                who,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Send what was not claimed from an airdrop to the sweep destination once its
+        /// deadline has passed, and close it.
+        ///
+        /// The claim bitmap of the airdrop is then left to clear with `clear_airdrop_bitmap`.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::sweep_airdrop())]
+        pub fn sweep_airdrop(origin: OriginFor<T>, envelope: EnvelopeId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let airdrop = Airdrops::<T>::get(envelope).ok_or(Error::<T>::NoAirdrop)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > airdrop.deadline,
+                Error::<T>::AirdropNotEnded
+            );
+
+            let amount = airdrop.total.saturating_sub(airdrop.claimed);
+            if !amount.is_zero() {
+                T::Currency::transfer(
+                    &envelope.account::<T>(),
+                    &T::SweepDestination::get(),
+                    amount,
+                    Preservation::Expendable,
+                )?;
+            }
+            Airdrops::<T>::remove(envelope);
+            if !airdrop.claimed.is_zero() {
+                SweptAirdropRoots::<T>::insert(airdrop.root, ());
+            }
+
+            Self::deposit_event(Event::AirdropSwept { envelope, amount });
+            Ok(())
+        }
+
+        /// Create an envelope, funded with its `total_cap` taken from `source` so that the total
+        /// issuance does not change.
+        ///
+        /// An envelope with a unique beneficiary is allocated to it right away, like at genesis.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::create_envelope().saturating_add(
+            if config.unique_beneficiary.is_some() {
+                T::WeightInfo::add_allocation()
+            } else {
+                Weight::zero()
+            }
+        ))]
+        pub fn create_envelope(
+            origin: OriginFor<T>,
+            id: EnvelopeId,
+            config: EnvConfigOf<T>,
+            source: EnvelopeFunds,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !Envelopes::<T>::contains_key(id),
+                Error::<T>::EnvelopeExists
+            );
+            ensure!(
+                config.curve.as_ref().is_none_or(|c| c.is_valid()),
+                Error::<T>::InvalidVestingCurve
+            );
+
+            if !config.total_cap.is_zero() {
+                Self::withdraw_funds(&source, &id.account::<T>(), config.total_cap)?;
+            }
+            Envelopes::<T>::insert(id, config.clone());
+            EnvelopeDistributed::<T>::insert(id, BalanceOf::<T>::zero());
+
+            if let Some(beneficiary) = config
+                .unique_beneficiary
+                .as_ref()
+                .filter(|_| !config.total_cap.is_zero())
+            {
+                Self::do_add_allocation(
+                    id,
+                    beneficiary,
+                    config.total_cap,
+                    None,
+                    Default::default(),
+                    &config,
+                    true,
+                )?;
+            }
+
+            Self::deposit_event(Event::EnvelopeCreated {
+                id,
+                total_cap: config.total_cap,
+            });
+            Ok(())
+        }
+
+        /// Change the cap of an envelope.
+        ///
+        /// The cap cannot go below what is already distributed, nor above what the envelope
+        /// account holds on top of it. Funds must be sent to the envelope account first to raise
+        /// the cap beyond its balance.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::set_envelope_cap())]
+        pub fn set_envelope_cap(
+            origin: OriginFor<T>,
+            id: EnvelopeId,
+            total_cap: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !ClosedEnvelopes::<T>::contains_key(id),
+                Error::<T>::EnvelopeClosed
+            );
+            Envelopes::<T>::try_mutate(id, |cfg| -> DispatchResult {
+                let cfg = cfg.as_mut().ok_or(Error::<T>::EnvelopeUnknown)?;
+                ensure!(
+                    cfg.unique_beneficiary.is_none(),
+                    Error::<T>::AllocationDisabled
+                );
+
+                let distributed = EnvelopeDistributed::<T>::get(id);
+                let undistributed = total_cap
+                    .checked_sub(&distributed)
+                    .ok_or(Error::<T>::CapBelowDistributed)?;
+                ensure!(
+                    undistributed <= Self::undistributed_funds(id),
+                    Error::<T>::InsufficientEnvelopeFunds
+                );
+                cfg.total_cap = total_cap;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::EnvelopeCapSet { id, total_cap });
+            Ok(())
+        }
+
+        /// Close an envelope: no more allocations nor airdrop claims can be made and its
+        /// undistributed balance is sent to `destination`.
+        ///
+        /// Existing allocations keep vesting, what is clawed back from them later goes to the
+        /// `FundingAccount`. An envelope that never distributed anything is removed.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::close_envelope())]
+        pub fn close_envelope(
+            origin: OriginFor<T>,
+            id: EnvelopeId,
+            destination: EnvelopeFunds,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                destination != EnvelopeFunds::Envelope(id),
+                Error::<T>::SameEnvelope
+            );
+            let cfg = Envelopes::<T>::get(id).ok_or(Error::<T>::EnvelopeUnknown)?;
+            ensure!(
+                !ClosedEnvelopes::<T>::contains_key(id),
+                Error::<T>::EnvelopeClosed
+            );
+            let distributed = EnvelopeDistributed::<T>::get(id);
+            let returned = cfg.total_cap.saturating_sub(distributed);
+
+            if !returned.is_zero() {
+                Self::deposit_funds(&destination, &id.account::<T>(), returned)?;
+            }
+            if distributed.is_zero() {
+                Envelopes::<T>::remove(id);
+                EnvelopeDistributed::<T>::remove(id);
+            } else {
+                Envelopes::<T>::mutate(id, |cfg| {
+                    if let Some(cfg) = cfg {
+                        cfg.total_cap = distributed;
+                    }
+                });
+                ClosedEnvelopes::<T>::insert(id, ());
+            }
+
+            Self::deposit_event(Event::EnvelopeClosed { id, returned });
+            Ok(())
+        }
+
+        /// Accept the pending transfer of an allocation to the caller.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::accept_transfer())]
+        pub fn accept_transfer(origin: OriginFor<T>, id: AllocationId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let pending = PendingTransfers::<T>::get(id).ok_or(Error::<T>::NoPendingTransfer)?;
+            ensure!(pending.to == who, Error::<T>::NotTransferRecipient);
+            ensure!(pending.approved, Error::<T>::TransferNotApproved);
+            PendingTransfers::<T>::remove(id);
+            let alloc = Allocations::<T>::get(id).ok_or(Error::<T>::AllocationUnknown)?;
+
+            Self::do_transfer(id, alloc, who)
+        }
+
+        /// Remove up to `limit` words of the claim bitmap of the swept airdrop `root`.
+        ///
+        /// Anyone can call it, over as many calls as needed. Once nothing is left,
+        /// `AirdropBitmapCleared` is emitted and the root can be used by a new airdrop.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::clear_airdrop_bitmap(*limit))]
+        pub fn clear_airdrop_bitmap(
+            origin: OriginFor<T>,
+            root: H256,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            ensure!(
+                SweptAirdropRoots::<T>::contains_key(root),
+                Error::<T>::NoAirdropBitmap
+            );
+
+            let words: Vec<u32> = AirdropClaimBitmap::<T>::iter_key_prefix(root)
+                .take(limit as usize)
+                .collect();
+            for word in &words {
+                AirdropClaimBitmap::<T>::remove(root, word);
+            }
+            if words.len() < limit as usize {
+                SweptAirdropRoots::<T>::remove(root);
+                Self::deposit_event(Event::AirdropBitmapCleared { root });
+            }
+
+            Ok(Some(T::WeightInfo::clear_airdrop_bitmap(words.len() as u32)).into())
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let Call::claim_airdrop {
+                envelope,
+                index,
+                who,
+                amount,
+                proof,
+            } = call
+            else {
+                return InvalidTransaction::Call.into();
+            };
+
+            let airdrop = Self::check_airdrop_claim(*envelope, *index, who, *amount, proof)
+                .map_err(|error| match error {
+                    Error::<T>::InvalidProof => InvalidTransaction::BadProof,
+                    Error::<T>::NoAirdrop
+                    | Error::<T>::AirdropEnded
+                    | Error::<T>::EnvelopeClosed
+                    | Error::<T>::AlreadyClaimed => InvalidTransaction::Stale,
+                    _ => InvalidTransaction::Call,
+                })?;
+
+            ValidTransaction::with_tag_prefix("TokenAllocationAirdrop")
+                .priority(T::AirdropUnsignedPriority::get())
+                .and_provides((airdrop.root, index))
+                .longevity(64)
+                .propagate(true)
+                .build()
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -392,9 +1366,19 @@ pub mod pallet {
             who: &T::AccountId,
             total: BalanceOf<T>,
             start: Option<BlockNumberFor<T>>,
+            overrides: VestingOverride<BlockNumberFor<T>>,
             cfg: &EnvConfigOf<T>,
             emit_events: bool,
         ) -> DispatchResult {
+            ensure!(
+                overrides.curve.as_ref().is_none_or(|c| c.is_valid()),
+                Error::<T>::InvalidVestingCurve
+            );
+            ensure!(
+                !ClosedEnvelopes::<T>::contains_key(id),
+                Error::<T>::EnvelopeClosed
+            );
+
             let distributed = EnvelopeDistributed::<T>::get(id);
             let new_distributed = distributed.saturating_add(total);
             ensure!(
@@ -405,7 +1389,7 @@ pub mod pallet {
             let source = id.account::<T>();
             let upfront = cfg.upfront_rate.mul_floor(total);
             let vested_total = total.saturating_sub(upfront);
-            let start_block = start.unwrap_or(cfg.cliff);
+            let start_block = start.unwrap_or(overrides.cliff.unwrap_or(cfg.cliff));
             let alloc: AllocationFor<T> = Allocation {
                 envelope: id,
                 beneficiary: who.clone(),
@@ -414,10 +1398,16 @@ pub mod pallet {
                 vested_total,
                 released: Zero::zero(),
                 start: start_block,
+                overrides,
             };
             let alloc_id = NextAllocationId::<T>::get();
             let reason: T::RuntimeHoldReason = HoldReason::TokenAllocation.into();
 
+            if !vested_total.is_zero() {
+                AllocationsOf::<T>::try_mutate(who, |ids| ids.try_push(alloc_id))
+                    .map_err(|_| Error::<T>::TooMuchAllocations)?;
+            }
+
             frame_system::Pallet::<T>::inc_providers(who);
             <T as Config>::Currency::transfer_and_hold(
                 &reason,
@@ -433,7 +1423,12 @@ pub mod pallet {
                 <T as Config>::Currency::release(&reason, who, upfront, Precision::Exact)?;
 
                 if emit_events {
-                    Self::deposit_event(Event::UpfrontPaid(alloc_id));
+                    Self::deposit_event(Event::UpfrontPaid {
+                        id: alloc_id,
+                        envelope: id,
+                        beneficiary: who.clone(),
+                        amount: upfront,
+                    });
                 }
             }
             if alloc.vested_total.is_zero() {
@@ -447,7 +1442,13 @@ pub mod pallet {
             NextAllocationId::<T>::set(alloc_id.saturating_add(1));
 
             if emit_events {
-                Self::deposit_event(Event::AllocationAdded(alloc_id));
+                Self::deposit_event(Event::AllocationAdded {
+                    id: alloc_id,
+                    envelope: id,
+                    beneficiary: who.clone(),
+                    total,
+                    upfront,
+                });
             }
 
             Ok(())
@@ -458,7 +1459,8 @@ pub mod pallet {
             alloc: &AllocationFor<T>,
             now: BlockNumberFor<T>,
         ) -> BalanceOf<T> {
-            let effective_start = core::cmp::max(alloc.start, cfg.cliff);
+            let cliff = alloc.overrides.cliff.unwrap_or(cfg.cliff);
+            let effective_start = core::cmp::max(alloc.start, cliff);
 
             // Too early
             if now <= effective_start {
@@ -466,83 +1468,420 @@ pub mod pallet {
             }
 
             let elapsed = now.saturating_sub(effective_start);
+            let vested_amount = match alloc.overrides.curve.as_ref().or(cfg.curve.as_ref()) {
+                Some(curve) => curve.vested(alloc.vested_total, elapsed),
+                None => VestingCurve::Linear {
+                    duration: cfg.vesting_duration,
+                }
+                .vested(alloc.vested_total, elapsed),
+            };
+
+            vested_amount.saturating_sub(alloc.released)
+        }
+
+        /// The allocations of which `who` is the beneficiary.
+        pub fn allocations_of(who: &T::AccountId) -> Vec<(AllocationId, AllocationFor<T>)> {
+            AllocationsOf::<T>::get(who)
+                .into_iter()
+                .filter_map(|id| Allocations::<T>::get(id).map(|alloc| (id, alloc)))
+                .collect()
+        }
+
+        /// The amount of allocation `id` claimable at block `at`.
+        pub fn claimable_at(id: AllocationId, at: BlockNumberFor<T>) -> Option<BalanceOf<T>> {
+            let alloc = Allocations::<T>::get(id)?;
+            let cfg = Envelopes::<T>::get(alloc.envelope)?;
+            Some(Self::claimable_amount(&cfg, &alloc, at))
+        }
 
-            // Fully vested
-            if elapsed >= cfg.vesting_duration {
-                return alloc.vested_total.saturating_sub(alloc.released);
+        /// Amount unlocked by all allocations in each of `count` buckets of `bucket` blocks,
+        /// starting at `from`. What is already claimable at `from` is not included.
+        pub fn unlock_schedule(
+            from: BlockNumberFor<T>,
+            bucket: BlockNumberFor<T>,
+            count: u32,
+        ) -> Vec<(BlockNumberFor<T>, BalanceOf<T>)> {
+            let ends = (1..=count.min(MAX_UNLOCK_BUCKETS))
+                .map(|i| from.saturating_add(bucket.saturating_mul(i.into())))
+                .collect::<Vec<_>>();
+            let mut unlocked = alloc::vec![BalanceOf::<T>::zero(); ends.len()];
+
+            for (_, alloc) in Allocations::<T>::iter() {
+                let Some(cfg) = Envelopes::<T>::get(alloc.envelope) else {
+                    continue;
+                };
+                let mut previous = Self::claimable_amount(&cfg, &alloc, from);
+                for (i, end) in ends.iter().enumerate() {
+                    let claimable = Self::claimable_amount(&cfg, &alloc, *end);
+                    unlocked[i].saturating_accrue(claimable.saturating_sub(previous));
+                    previous = claimable;
+                }
             }
 
-            // Partial vesting calculation: (total * elapsed) / duration
-            // SAFETY: We perform calculation in U256 to avoid overflow of (Balance * BlockNumber)
-            let total_u256 = U256::from(alloc.vested_total.saturated_into::<u128>());
-            let elapsed_u256 = U256::from(elapsed.saturated_into::<u128>());
-            let duration_u256 = U256::from(cfg.vesting_duration.saturated_into::<u128>());
+            ends.into_iter().zip(unlocked).collect()
+        }
 
-            if duration_u256.is_zero() {
-                return alloc.vested_total.saturating_sub(alloc.released);
+        /// Distribution figures of every envelope.
+        pub fn envelope_totals() -> Vec<(EnvelopeId, EnvelopeTotals<BalanceOf<T>>)> {
+            let mut totals = Envelopes::<T>::iter()
+                .map(|(id, cfg)| {
+                    let distributed = EnvelopeDistributed::<T>::get(id);
+                    let totals = EnvelopeTotals {
+                        total_cap: cfg.total_cap,
+                        distributed,
+                        remaining: cfg.total_cap.saturating_sub(distributed),
+                        locked: Zero::zero(),
+                    };
+                    (id, totals)
+                })
+                .collect::<Vec<_>>();
+
+            for (_, alloc) in Allocations::<T>::iter() {
+                if let Some((_, t)) = totals.iter_mut().find(|(id, _)| *id == alloc.envelope) {
+                    t.locked
+                        .saturating_accrue(alloc.vested_total.saturating_sub(alloc.released));
+                }
             }
 
-            // Calculate: (Total * Elapsed)
-            let numerator = total_u256.saturating_mul(elapsed_u256);
-            // Calculate: Numerator / Duration
-            let vested_amount_u256 = numerator / duration_u256;
+            totals
+        }
 
-            // Convert back to Balance
-            let vested_amount: BalanceOf<T> = vested_amount_u256.as_u128().saturated_into();
+        /// Total issuance minus the balances held for vesting and the envelope accounts.
+        pub fn circulating_supply() -> BalanceOf<T> {
+            let locked =
+                Allocations::<T>::iter_values().fold(Zero::zero(), |acc: BalanceOf<T>, a| {
+                    acc.saturating_add(a.vested_total.saturating_sub(a.released))
+                });
+            let undistributed =
+                Envelopes::<T>::iter_keys().fold(Zero::zero(), |acc: BalanceOf<T>, id| {
+                    acc.saturating_add(T::Currency::total_balance(&id.account::<T>()))
+                });
 
-            vested_amount.saturating_sub(alloc.released)
+            T::Currency::total_issuance()
+                .saturating_sub(locked)
+                .saturating_sub(undistributed)
         }
 
-        pub(crate) fn payout_allocation(id: AllocationId, now: BlockNumberFor<T>) {
-            // We read the allocation.
-            // If it doesn't exist, we do nothing (loop continues).
-            if let Some(mut alloc) = Allocations::<T>::get(id) {
-                // If envelope config is missing, we can't calculate math. Skip.
-                let cfg = match Envelopes::<T>::get(alloc.envelope) {
-                    Some(cfg) => cfg,
-                    None => return,
-                };
+        /// Releases that failed and are waiting to be retried or force-settled.
+        pub fn failed_releases() -> Vec<FailedReleaseOf<T>> {
+            FailedReleases::<T>::get().into_inner()
+        }
+
+        /// Balance of the envelope account that is neither distributed nor reserved for an
+        /// airdrop.
+        fn undistributed_funds(id: EnvelopeId) -> BalanceOf<T> {
+            let reserved = Airdrops::<T>::get(id)
+                .map(|airdrop| airdrop.total.saturating_sub(airdrop.claimed))
+                .unwrap_or_else(Zero::zero);
+            T::Currency::reducible_balance(
+                &id.account::<T>(),
+                Preservation::Expendable,
+                Fortitude::Polite,
+            )
+            .saturating_sub(reserved)
+        }
+
+        /// Move `amount` from `source` to `to`, lowering the cap of a source envelope.
+        fn withdraw_funds(
+            source: &EnvelopeFunds,
+            to: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            match source {
+                EnvelopeFunds::Envelope(id) => {
+                    Envelopes::<T>::try_mutate(id, |cfg| -> DispatchResult {
+                        let cfg = cfg.as_mut().ok_or(Error::<T>::EnvelopeUnknown)?;
+                        let available = cfg
+                            .total_cap
+                            .saturating_sub(EnvelopeDistributed::<T>::get(id));
+                        ensure!(amount <= available, Error::<T>::EnvelopeCapExceeded);
+                        cfg.total_cap.saturating_reduce(amount);
+                        Ok(())
+                    })?;
+                    T::Currency::transfer(
+                        &id.account::<T>(),
+                        to,
+                        amount,
+                        Preservation::Expendable,
+                    )?;
+                }
+                EnvelopeFunds::FundingAccount => {
+                    T::Currency::transfer(
+                        &T::FundingAccount::get(),
+                        to,
+                        amount,
+                        Preservation::Preserve,
+                    )?;
+                }
+            }
+            Ok(())
+        }
+
+        /// Move `amount` from `from` to `destination`, raising the cap of a destination envelope.
+        fn deposit_funds(
+            destination: &EnvelopeFunds,
+            from: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let to = match destination {
+                EnvelopeFunds::Envelope(id) => {
+                    Envelopes::<T>::try_mutate(id, |cfg| -> DispatchResult {
+                        let cfg = cfg.as_mut().ok_or(Error::<T>::EnvelopeUnknown)?;
+                        cfg.total_cap.saturating_accrue(amount);
+                        Ok(())
+                    })?;
+                    id.account::<T>()
+                }
+                EnvelopeFunds::FundingAccount => T::FundingAccount::get(),
+            };
+            T::Currency::transfer(from, &to, amount, Preservation::Expendable)?;
+            Ok(())
+        }
+
+        /// Check that leaf `index` of the airdrop of `envelope` can be claimed.
+        pub(crate) fn check_airdrop_claim(
+            envelope: EnvelopeId,
+            index: u32,
+            who: &T::AccountId,
+            amount: BalanceOf<T>,
+            proof: &[H256],
+        ) -> Result<AirdropOf<T>, Error<T>> {
+            let airdrop = Airdrops::<T>::get(envelope).ok_or(Error::<T>::NoAirdrop)?;
+            ensure!(
+                !ClosedEnvelopes::<T>::contains_key(envelope),
+                Error::<T>::EnvelopeClosed
+            );
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= airdrop.deadline,
+                Error::<T>::AirdropEnded
+            );
+            let word = AirdropClaimBitmap::<T>::get(airdrop.root, index / 128);
+            ensure!(
+                word & (1u128 << (index % 128)) == 0,
+                Error::<T>::AlreadyClaimed
+            );
+            ensure!(
+                verify_airdrop_proof(airdrop.root, airdrop_leaf(index, who, amount), proof),
+                Error::<T>::InvalidProof
+            );
+            ensure!(
+                airdrop.claimed.saturating_add(amount) <= airdrop.total,
+                Error::<T>::EnvelopeCapExceeded
+            );
+            Ok(airdrop)
+        }
+
+        /// Release what is vested for allocation `id`, optionally checking the beneficiary.
+        ///
+        /// A failed release is queued for retry and reported by `ReleaseFailed`, it is only
+        /// returned as an error if the queue is full.
+        pub(crate) fn do_claim(id: AllocationId, who: Option<&T::AccountId>) -> DispatchResult {
+            let alloc = Allocations::<T>::get(id).ok_or(Error::<T>::AllocationUnknown)?;
+            if let Some(who) = who {
+                ensure!(alloc.beneficiary == *who, Error::<T>::NotBeneficiary);
+            }
+            let cfg = Envelopes::<T>::get(alloc.envelope).ok_or(Error::<T>::EnvelopeUnknown)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let claimable = Self::claimable_amount(&cfg, &alloc, now);
+            ensure!(!claimable.is_zero(), Error::<T>::NothingToClaim);
+
+            match with_storage_layer(|| Self::release_vested(id, alloc, claimable)) {
+                Ok(()) => Ok(()),
+                Err(error) => Self::record_failed_release(id, claimable, error),
+            }
+        }
+
+        /// Queue the failed release of `amount` for allocation `id`, or bump its retry counter.
+        ///
+        /// Returns `error` if the queue is full so the failure is not lost.
+        fn record_failed_release(
+            id: AllocationId,
+            amount: BalanceOf<T>,
+            error: DispatchError,
+        ) -> DispatchResult {
+            FailedReleases::<T>::try_mutate(|queue| match queue.iter_mut().find(|f| f.id == id) {
+                Some(failed) => {
+                    failed.amount = amount;
+                    failed.retries.saturating_inc();
+                    Ok(())
+                }
+                None => queue.try_push(FailedRelease {
+                    id,
+                    amount,
+                    retries: 0,
+                }),
+            })
+            .map_err(|_| error)?;
+
+            Self::deposit_event(Event::ReleaseFailed { id, amount, error });
+            Ok(())
+        }
+
+        /// Retry the oldest failed release, moving it to the back of the queue.
+        ///
+        /// Returns whether a release was retried.
+        fn retry_failed_release() -> bool {
+            let mut queue = FailedReleases::<T>::get();
+            if queue.is_empty() {
+                return false;
+            }
+            let failed = queue.remove(0);
+            let id = failed.id;
+            let _ = queue.try_push(failed);
+            FailedReleases::<T>::put(queue);
+
+            // Allocations gone, of a missing envelope or with nothing left to release do not need
+            // a retry anymore.
+            if Self::do_claim(id, None).is_err() && Self::clear_failed_release(id) {
+                Self::deposit_event(Event::FailedReleaseDropped { id });
+            }
+            true
+        }
 
-                let claimable = Self::claimable_amount(&cfg, &alloc, now);
-
-                if !claimable.is_zero() {
-                    let reason: T::RuntimeHoldReason = HoldReason::TokenAllocation.into();
-
-                    // Attempt to release the hold
-                    // +1 Storage Write
-                    match T::Currency::release(
-                        &reason,
-                        &alloc.beneficiary,
-                        claimable,
-                        Precision::Exact,
-                    ) {
-                        Ok(_) => {
-                            // Success: Update state
-                            alloc.released = alloc.released.saturating_add(claimable);
-
-                            // Check if fully vested
-                            let remaining = alloc.vested_total.saturating_sub(alloc.released);
-
-                            if remaining.is_zero() {
-                                // Cleanup
-                                Allocations::<T>::remove(id);
-                                let _ =
-                                    frame_system::Pallet::<T>::dec_providers(&alloc.beneficiary);
-                            } else {
-                                // Update progress
-                                Allocations::<T>::insert(id, alloc);
-                            }
-
-                            Self::deposit_event(Event::VestedReleased(id, claimable));
-                        }
-                        Err(_) => {
-                            // If release fails (e.g. weird state), we simply don't update `released`.
-                            // It will be retried next epoch.
-                            // We might want to log a warning here if 'log' feature is enabled.
-                        }
+        /// Remove the failed release of allocation `id`, returns whether there was one.
+        fn clear_failed_release(id: AllocationId) -> bool {
+            FailedReleases::<T>::mutate(|queue| {
+                let len = queue.len();
+                queue.retain(|f| f.id != id);
+                queue.len() != len
+            })
+        }
+
+        /// Move the remaining held balance of allocation `id` to `to` and make it the beneficiary.
+        pub(crate) fn do_transfer(
+            id: AllocationId,
+            mut alloc: AllocationFor<T>,
+            to: T::AccountId,
+        ) -> DispatchResult {
+            let reason: T::RuntimeHoldReason = HoldReason::TokenAllocation.into();
+            let amount = alloc.vested_total.saturating_sub(alloc.released);
+
+            AllocationsOf::<T>::try_mutate(&to, |ids| ids.try_push(id))
+                .map_err(|_| Error::<T>::TooMuchAllocations)?;
+            Self::unindex(&alloc.beneficiary, id);
+            frame_system::Pallet::<T>::inc_providers(&to);
+            T::Currency::transfer_on_hold(
+                &reason,
+                &alloc.beneficiary,
+                &to,
+                amount,
+                Precision::Exact,
+                Restriction::OnHold,
+                Fortitude::Polite,
+            )?;
+            let _ = frame_system::Pallet::<T>::dec_providers(&alloc.beneficiary);
+
+            let envelope = alloc.envelope;
+            let from = core::mem::replace(&mut alloc.beneficiary, to.clone());
+            Allocations::<T>::insert(id, alloc);
+
+            Self::deposit_event(Event::AllocationTransferred {
+                id,
+                envelope,
+                from,
+                to,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Release `amount` of the held balance of allocation `id` and record it as released.
+        ///
+        /// `released` is updated together with the hold, so an amount is never released twice.
+        pub(crate) fn release_vested(
+            id: AllocationId,
+            mut alloc: AllocationFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let reason: T::RuntimeHoldReason = HoldReason::TokenAllocation.into();
+
+            // Attempt to release the hold
+            // +1 Storage Write
+            T::Currency::release(&reason, &alloc.beneficiary, amount, Precision::Exact)?;
+
+            // Success: Update state
+            alloc.released = alloc.released.saturating_add(amount);
+
+            // Check if fully vested
+            let remaining = alloc.vested_total.saturating_sub(alloc.released);
+
+            let (envelope, beneficiary) = (alloc.envelope, alloc.beneficiary.clone());
+            if remaining.is_zero() {
+                // Cleanup
+                Self::remove_allocation(id, &beneficiary);
+            } else {
+                // Update progress
+                Allocations::<T>::insert(id, alloc);
+                Self::clear_failed_release(id);
+            }
+
+            Self::deposit_event(Event::VestedReleased {
+                id,
+                envelope,
+                beneficiary,
+                amount,
+                remaining,
+            });
+            Ok(())
+        }
+
+        /// Ensure one more allocation can be indexed under `who`.
+        fn ensure_can_index(who: &T::AccountId) -> DispatchResult {
+            let indexed = AllocationsOf::<T>::decode_len(who).unwrap_or(0);
+            ensure!(
+                indexed < T::MaxAllocationsPerAccount::get() as usize,
+                Error::<T>::TooMuchAllocations
+            );
+            Ok(())
+        }
+
+        /// Remove a settled allocation and its index entry, and drop the beneficiary provider.
+        fn remove_allocation(id: AllocationId, beneficiary: &T::AccountId) {
+            Allocations::<T>::remove(id);
+            PendingTransfers::<T>::remove(id);
+            Self::clear_failed_release(id);
+            Self::unindex(beneficiary, id);
+            let _ = frame_system::Pallet::<T>::dec_providers(beneficiary);
+        }
+
+        /// Remove `id` from the allocations of `who`.
+        fn unindex(who: &T::AccountId, id: AllocationId) {
+            AllocationsOf::<T>::mutate_exists(who, |ids| {
+                if let Some(list) = ids {
+                    list.retain(|a| *a != id);
+                    if list.is_empty() {
+                        *ids = None;
                     }
                 }
+            });
+        }
+
+        /// Every allocation must belong to an existing envelope and be indexed under its
+        /// beneficiary, and every failed release must belong to an existing allocation.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub(crate) fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            for (id, alloc) in Allocations::<T>::iter() {
+                if !Envelopes::<T>::contains_key(alloc.envelope) {
+                    log::error!(
+                        target: "runtime::token-allocation",
+                        "allocation {id} belongs to missing envelope {:?}",
+                        alloc.envelope,
+                    );
+                    return Err("allocation of a missing envelope".into());
+                }
+                ensure!(
+                    AllocationsOf::<T>::get(&alloc.beneficiary).contains(&id),
+                    "allocation missing from the beneficiary index"
+                );
             }
+            for failed in FailedReleases::<T>::get() {
+                ensure!(
+                    Allocations::<T>::contains_key(failed.id),
+                    "failed release of a missing allocation"
+                );
+            }
+            Ok(())
         }
     }
 }
@@ -0,0 +1,291 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the token allocation pallet.
+
+/// Adds the `revocable` flag to the envelope configurations and removes the `PayoutCursor` left
+/// over by the eager epoch payouts.
+pub mod v1 {
+    use crate::*;
+    use frame_support::traits::{Contains, UncheckedOnRuntimeUpgrade};
+
+    #[derive(Encode, Decode)]
+    struct OldEnvelopeConfig<Balance, BlockNumber, AccountId> {
+        total_cap: Balance,
+        upfront_rate: Percent,
+        cliff: BlockNumber,
+        vesting_duration: BlockNumber,
+        unique_beneficiary: Option<AccountId>,
+    }
+
+    #[derive(Encode, Decode)]
+    pub(super) struct EnvelopeConfigV1<Balance, BlockNumber, AccountId> {
+        pub total_cap: Balance,
+        pub upfront_rate: Percent,
+        pub cliff: BlockNumber,
+        pub vesting_duration: BlockNumber,
+        pub unique_beneficiary: Option<AccountId>,
+        pub revocable: bool,
+    }
+
+    pub(super) type EnvConfigV1Of<T> =
+        EnvelopeConfigV1<BalanceOf<T>, BlockNumberFor<T>, <T as frame_system::Config>::AccountId>;
+
+    #[frame_support::storage_alias]
+    type Envelopes<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, EnvelopeId, EnvConfigV1Of<T>, OptionQuery>;
+
+    #[frame_support::storage_alias]
+    type PayoutCursor<T: Config> = StorageValue<Pallet<T>, AllocationId, OptionQuery>;
+
+    /// Flags the envelopes matching `Revocable` as revocable, every other envelope is not.
+    pub struct UncheckedMigrateToV1<T, Revocable>(PhantomData<(T, Revocable)>);
+
+    impl<T: Config, Revocable: Contains<EnvelopeId>> UncheckedOnRuntimeUpgrade
+        for UncheckedMigrateToV1<T, Revocable>
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Envelopes::<T>::translate::<
+                OldEnvelopeConfig<BalanceOf<T>, BlockNumberFor<T>, T::AccountId>,
+                _,
+            >(|id, old| {
+                translated.saturating_inc();
+                Some(EnvelopeConfigV1 {
+                    total_cap: old.total_cap,
+                    upfront_rate: old.upfront_rate,
+                    cliff: old.cliff,
+                    vesting_duration: old.vesting_duration,
+                    unique_beneficiary: old.unique_beneficiary,
+                    revocable: Revocable::contains(&id),
+                })
+            });
+            PayoutCursor::<T>::kill();
+
+            T::DbWeight::get().reads_writes(translated, translated.saturating_add(1))
+        }
+    }
+
+    /// [`UncheckedMigrateToV1`] wrapped in a [`frame_support::migrations::VersionedMigration`].
+    pub type MigrateToV1<T, Revocable> = frame_support::migrations::VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateToV1<T, Revocable>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Adds the `transfer_approval` flag to the envelope configurations.
+pub mod v2 {
+    use super::v1::EnvConfigV1Of;
+    use crate::*;
+    use frame_support::traits::{Contains, UncheckedOnRuntimeUpgrade};
+
+    #[derive(Encode, Decode)]
+    pub(super) struct EnvelopeConfigV2<Balance, BlockNumber, AccountId> {
+        pub total_cap: Balance,
+        pub upfront_rate: Percent,
+        pub cliff: BlockNumber,
+        pub vesting_duration: BlockNumber,
+        pub unique_beneficiary: Option<AccountId>,
+        pub revocable: bool,
+        pub transfer_approval: bool,
+    }
+
+    pub(super) type EnvConfigV2Of<T> =
+        EnvelopeConfigV2<BalanceOf<T>, BlockNumberFor<T>, <T as frame_system::Config>::AccountId>;
+
+    #[frame_support::storage_alias]
+    type Envelopes<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, EnvelopeId, EnvConfigV2Of<T>, OptionQuery>;
+
+    /// Requires approval for transfers of the envelopes matching `Approval`.
+    pub struct UncheckedMigrateToV2<T, Approval>(PhantomData<(T, Approval)>);
+
+    impl<T: Config, Approval: Contains<EnvelopeId>> UncheckedOnRuntimeUpgrade
+        for UncheckedMigrateToV2<T, Approval>
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Envelopes::<T>::translate::<EnvConfigV1Of<T>, _>(|id, old| {
+                translated.saturating_inc();
+                Some(EnvelopeConfigV2 {
+                    total_cap: old.total_cap,
+                    upfront_rate: old.upfront_rate,
+                    cliff: old.cliff,
+                    vesting_duration: old.vesting_duration,
+                    unique_beneficiary: old.unique_beneficiary,
+                    revocable: old.revocable,
+                    transfer_approval: Approval::contains(&id),
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// [`UncheckedMigrateToV2`] wrapped in a [`frame_support::migrations::VersionedMigration`].
+    pub type MigrateToV2<T, Approval> = frame_support::migrations::VersionedMigration<
+        1,
+        2,
+        UncheckedMigrateToV2<T, Approval>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Adds vesting curves to the envelopes and vesting overrides to the allocations.
+///
+/// Every existing envelope keeps vesting linearly, allocations do not override anything.
+pub mod v3 {
+    use super::v2::EnvConfigV2Of;
+    use crate::*;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+    #[derive(Encode, Decode)]
+    struct OldAllocation<AccountId, Balance, BlockNumber> {
+        envelope: EnvelopeId,
+        beneficiary: AccountId,
+        total: Balance,
+        upfront: Balance,
+        vested_total: Balance,
+        released: Balance,
+        start: BlockNumber,
+    }
+
+    /// Translates the envelopes and allocations to the v3 layout.
+    pub struct UncheckedMigrateToV3<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Envelopes::<T>::translate::<EnvConfigV2Of<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(EnvelopeConfig {
+                    total_cap: old.total_cap,
+                    upfront_rate: old.upfront_rate,
+                    cliff: old.cliff,
+                    vesting_duration: old.vesting_duration,
+                    unique_beneficiary: old.unique_beneficiary,
+                    revocable: old.revocable,
+                    transfer_approval: old.transfer_approval,
+                    curve: None,
+                })
+            });
+            Allocations::<T>::translate::<
+                OldAllocation<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+                _,
+            >(|_, old| {
+                translated.saturating_inc();
+                Some(Allocation {
+                    envelope: old.envelope,
+                    beneficiary: old.beneficiary,
+                    total: old.total,
+                    upfront: old.upfront,
+                    vested_total: old.vested_total,
+                    released: old.released,
+                    start: old.start,
+                    overrides: Default::default(),
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// [`UncheckedMigrateToV3`] wrapped in a [`frame_support::migrations::VersionedMigration`].
+    pub type MigrateToV3<T> = frame_support::migrations::VersionedMigration<
+        2,
+        3,
+        UncheckedMigrateToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Backfills the `AllocationsOf` beneficiary index from the existing allocations.
+pub mod v4 {
+    use crate::*;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+    /// Indexes every existing allocation under its beneficiary.
+    ///
+    /// `pre_upgrade` fails when a beneficiary holds more than `MaxAllocationsPerAccount`
+    /// allocations, as the extra ones could not be indexed.
+    pub struct UncheckedMigrateToV4<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV4<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let mut counts = alloc::collections::BTreeMap::<T::AccountId, u32>::new();
+            for alloc in Allocations::<T>::iter_values() {
+                counts
+                    .entry(alloc.beneficiary)
+                    .or_default()
+                    .saturating_inc();
+            }
+            ensure!(
+                counts
+                    .values()
+                    .all(|count| *count <= T::MaxAllocationsPerAccount::get()),
+                "a beneficiary has more allocations than MaxAllocationsPerAccount"
+            );
+            Ok(Vec::new())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+            for (id, alloc) in Allocations::<T>::iter() {
+                reads.saturating_accrue(2);
+                writes.saturating_inc();
+                let pushed = AllocationsOf::<T>::mutate(&alloc.beneficiary, |ids| ids.try_push(id));
+                if pushed.is_err() {
+                    log::warn!(
+                        target: "runtime::token-allocation",
+                        "allocation {id} not indexed: too many allocations for its beneficiary",
+                    );
+                }
+            }
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let indexed = AllocationsOf::<T>::iter_values()
+                .map(|ids| ids.len())
+                .sum::<usize>();
+            ensure!(
+                indexed == Allocations::<T>::iter_keys().count(),
+                "every allocation must be indexed"
+            );
+            Ok(())
+        }
+    }
+
+    /// [`UncheckedMigrateToV4`] wrapped in a [`frame_support::migrations::VersionedMigration`].
+    pub type MigrateToV4<T> = frame_support::migrations::VersionedMigration<
+        3,
+        4,
+        UncheckedMigrateToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
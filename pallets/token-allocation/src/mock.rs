@@ -71,8 +71,11 @@ parameter_types! {
     pub TokenAllocPalletId: PalletId = PalletId(*b"tkalloc8");
     // Short duration for easier testing
     pub const EpochDuration: u64 = 10;
-    // Small limit to easily test the "cursor" logic and loop breaking
-    pub const MaxPayoutPerBlock: u32 = 5;
+    pub const MaxAllocationsPerAccount: u32 = 3;
+    pub const MaxFailedReleases: u32 = 2;
+    pub const SweepDestination: u128 = 99;
+    pub const FundingAccount: u128 = 98;
+    pub const AirdropUnsignedPriority: u64 = 100;
 }
 
 impl pallet_token_allocation::Config for Test {
@@ -80,7 +83,11 @@ impl pallet_token_allocation::Config for Test {
     type AdminOrigin = EnsureRoot<Self::AccountId>;
     type PalletId = TokenAllocPalletId;
     type EpochDuration = EpochDuration;
-    type MaxPayoutsPerBlock = MaxPayoutPerBlock;
+    type MaxAllocationsPerAccount = MaxAllocationsPerAccount;
+    type MaxFailedReleases = MaxFailedReleases;
+    type SweepDestination = SweepDestination;
+    type FundingAccount = FundingAccount;
+    type AirdropUnsignedPriority = AirdropUnsignedPriority;
     type RuntimeHoldReason = RuntimeHoldReason;
     type WeightInfo = ();
 }
@@ -0,0 +1,52 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the token allocation pallet.
+
+use crate::{Allocation, AllocationId, EnvelopeId, EnvelopeTotals, FailedRelease};
+use alloc::vec::Vec;
+use parity_scale_codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Read access to the vesting schedules and supply figures of the launch allocations.
+    pub trait TokenAllocationApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// The allocations of which `who` is the beneficiary.
+        fn allocations_of(who: AccountId) -> Vec<(AllocationId, Allocation<AccountId, Balance, BlockNumber>)>;
+
+        /// The amount of allocation `id` claimable at block `at`, `None` if it does not exist.
+        fn claimable_at(id: AllocationId, at: BlockNumber) -> Option<Balance>;
+
+        /// Amount unlocked by all allocations during each of the next `months` months, starting
+        /// at block `from`. Each entry is the last block of the month and the amount it unlocks.
+        fn unlock_schedule(from: BlockNumber, months: u32) -> Vec<(BlockNumber, Balance)>;
+
+        /// Distribution figures of every envelope.
+        fn envelope_totals() -> Vec<(EnvelopeId, EnvelopeTotals<Balance>)>;
+
+        /// Total issuance minus the balances still held for vesting and the envelope accounts.
+        fn circulating_supply() -> Balance;
+
+        /// Releases that failed and are waiting to be retried or force-settled.
+        fn failed_releases() -> Vec<FailedRelease<Balance>>;
+    }
+}
@@ -1,11 +1,23 @@
 // tests.rs
 
-use crate::{EnvelopeConfig, EnvelopeId, Error, mock::*};
+use crate::{
+    EnvelopeConfig, EnvelopeId, Error, FailedRelease, VestingCurve, VestingOverride, airdrop_leaf,
+    airdrop_node, mock::*,
+};
 use frame_support::{
     assert_noop, assert_ok,
-    traits::fungible::{InspectHold, Mutate},
+    traits::{
+        Get,
+        fungible::{InspectHold, Mutate, MutateHold},
+        tokens::Precision,
+    },
+};
+use sp_core::H256;
+use sp_runtime::{
+    DispatchError, Percent,
+    traits::ValidateUnsigned,
+    transaction_validity::{InvalidTransaction, TransactionSource},
 };
-use sp_runtime::Percent;
 
 // --- HELPER ---
 // Reduces test verbosity by centralizing configuration.
@@ -23,6 +35,9 @@ fn setup_and_fund_envelope(
         cliff,
         vesting_duration: duration,
         unique_beneficiary: unique,
+        revocable: matches!(id, EnvelopeId::Teams | EnvelopeId::KoL),
+        transfer_approval: matches!(id, EnvelopeId::Private1 | EnvelopeId::Private2),
+        curve: None,
     };
 
     // Direct insertion into storage (simulates Genesis or prior setup).
@@ -72,15 +87,20 @@ fn full_lifecycle_works() {
         assert_eq!(Balances::free_balance(ben), 100);
 
         // 3. Advance to middle of vesting (Block 60: 10 cliff + 50 duration).
+        // Vesting is lazy: nothing is released until the beneficiary claims.
+        run_to_block(60);
+        assert_eq!(Balances::free_balance(ben), 100);
+
         // Elapsed time = 50 blocks out of 100 = 50% of the remainder.
         // Remaining to vest = 900. 50% of 900 = 450.
         // Total Free = 100 (upfront) + 450 (vested) = 550.
-        run_to_block(60);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(ben), 0));
         assert_eq!(Balances::free_balance(ben), 550, "Upfront + 50% vested");
         assert_eq!(Balances::total_balance_on_hold(&ben), 450);
 
         // 4. Finish vesting (Block 120).
         run_to_block(120);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(ben), 0));
         assert_eq!(
             Balances::free_balance(ben),
             1000,
@@ -98,66 +118,39 @@ fn full_lifecycle_works() {
 }
 
 #[test]
-fn pagination_logic_handles_overflowing_allocations() {
-    // Tests the 'Push' mechanism when there are more allocations than the block limit.
+fn epoch_hook_does_not_touch_allocations() {
+    // The per-block hook must stay O(1) whatever the number of allocations.
     new_test_ext(vec![], vec![]).execute_with(|| {
-        // Mock Config: MaxPayoutPerBlock = 5 (defined in mock.rs).
-
-        // Config: Instant vesting (duration 1) to force payout at every tick.
+        // Config: Instant vesting (duration 1).
         setup_and_fund_envelope(EnvelopeId::Airdrop, 1_000_000, 0, 0, 1, None);
 
-        // Create 7 allocations (7 > 5) that must all pay out at block 100.
         for i in 0..7 {
             assert_ok!(TokenAllocation::add_allocation(
                 RuntimeOrigin::root(),
                 EnvelopeId::Airdrop,
                 i + 100, // Unique IDs
                 100,
-                Some(99) // Start vesting at block 99
+                Some(0)
             ));
         }
 
-        // Force next payout at block 100.
-        crate::NextPayoutAt::<Test>::put(100);
-        crate::EpochIndex::<Test>::put(0);
-
-        // --- EXECUTION BLOCK 100 ---
-        run_to_block(100);
+        // --- EXECUTION BLOCK 10 (first epoch) ---
+        run_to_block(10);
 
-        // CHECK 1: Pagination active.
-        // Should have 2 remaining (7 - 5 processed).
-        assert_eq!(
-            crate::Allocations::<Test>::iter_keys().count(),
-            2,
-            "Should have 2 allocations remaining"
-        );
-        // Cursor must be set to resume in next block.
-        assert!(
-            crate::PayoutCursor::<Test>::get().is_some(),
-            "Cursor should be set"
-        );
-        // Epoch should NOT have changed yet.
-        assert_eq!(
-            crate::EpochIndex::<Test>::get(),
-            0,
-            "Epoch should not increment yet"
-        );
+        // Epoch reported and next one scheduled.
+        assert_eq!(crate::EpochIndex::<Test>::get(), 1);
+        assert_eq!(crate::NextPayoutAt::<Test>::get(), 20);
+        System::assert_last_event(crate::Event::<Test>::EpochPayout { epoch: 0, at: 10 }.into());
 
-        // --- EXECUTION BLOCK 101 ---
-        run_to_block(101);
+        // Nothing was released by the hook, allocations wait for their claim.
+        assert_eq!(crate::Allocations::<Test>::iter_keys().count(), 7);
+        assert_eq!(Balances::total_balance_on_hold(&100), 100);
 
-        // CHECK 2: Processing finished.
-        assert_eq!(
-            crate::Allocations::<Test>::iter_keys().count(),
-            0,
-            "All allocations processed"
-        );
-        assert!(
-            crate::PayoutCursor::<Test>::get().is_none(),
-            "Cursor cleared"
-        );
-        // Epoch changed, next payout scheduled.
-        assert_eq!(crate::EpochIndex::<Test>::get(), 1);
+        // Anyone can settle an allocation on behalf of its beneficiary.
+        assert_ok!(TokenAllocation::claim_for(RuntimeOrigin::signed(1), 0));
+        assert_eq!(Balances::free_balance(100), 100);
+        assert_eq!(Balances::free_balance(1), 0);
+        assert_eq!(crate::Allocations::<Test>::iter_keys().count(), 6);
     });
 }
 
@@ -188,6 +181,7 @@ fn math_is_safe_with_u256() {
         // If code did (Amount * Time), it would be u128::MAX * 500_000 -> Immediate Panic in pure u128.
         // With U256, this must pass.
         run_to_block(500_000);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(ben), 0));
 
         // We should receive approximately half.
         let free = Balances::free_balance(ben);
@@ -271,3 +265,1516 @@ fn permission_checks() {
         );
     });
 }
+
+#[test]
+fn claim_releases_vested_funds_once() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        // Config: 1000 tokens, no upfront, Cliff at block 10, Duration 100 blocks.
+        setup_and_fund_envelope(EnvelopeId::Public2, 1000, 0, 10, 100, None);
+        let ben = 1u128;
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public2,
+            ben,
+            1000,
+            None
+        ));
+
+        // Claiming at block 35 releases the 25% vested so far.
+        run_to_block(35);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(ben), 0));
+        assert_eq!(Balances::free_balance(ben), 250);
+        assert_eq!(Balances::total_balance_on_hold(&ben), 750);
+
+        // Nothing left until more blocks elapse.
+        assert_noop!(
+            TokenAllocation::claim(RuntimeOrigin::signed(ben), 0),
+            Error::<Test>::NothingToClaim
+        );
+
+        // A later claim only releases what was not claimed yet.
+        run_to_block(40);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(ben), 0));
+        assert_eq!(Balances::free_balance(ben), 300);
+        assert_eq!(Balances::total_balance_on_hold(&ben), 700);
+        System::assert_last_event(
+            crate::Event::<Test>::VestedReleased {
+                id: 0,
+                envelope: EnvelopeId::Public2,
+                beneficiary: ben,
+                amount: 50,
+                remaining: 700,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn claim_of_fully_vested_allocation_cleans_up() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        // Vesting over 5 blocks.
+        setup_and_fund_envelope(EnvelopeId::Airdrop, 1000, 0, 0, 5, None);
+        let ben = 1u128;
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Airdrop,
+            ben,
+            1000,
+            Some(0)
+        ));
+
+        run_to_block(7);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(ben), 0));
+
+        assert_eq!(Balances::free_balance(ben), 1000);
+        assert_eq!(Balances::total_balance_on_hold(&ben), 0);
+        assert!(crate::Allocations::<Test>::get(0).is_none());
+        assert!(!crate::AllocationsOf::<Test>::contains_key(ben));
+
+        assert_noop!(
+            TokenAllocation::claim(RuntimeOrigin::signed(ben), 0),
+            Error::<Test>::AllocationUnknown
+        );
+    });
+}
+
+#[test]
+fn allocations_are_indexed_by_beneficiary() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Public2, 1000, 0, 0, 100, None);
+        let (ben, other) = (1u128, 2u128);
+
+        for (who, amount) in [(ben, 100), (other, 100), (ben, 200), (ben, 300)] {
+            assert_ok!(TokenAllocation::add_allocation(
+                RuntimeOrigin::root(),
+                EnvelopeId::Public2,
+                who,
+                amount,
+                None
+            ));
+        }
+        System::assert_last_event(
+            crate::Event::<Test>::AllocationAdded {
+                id: 3,
+                envelope: EnvelopeId::Public2,
+                beneficiary: ben,
+                total: 300,
+                upfront: 0,
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            crate::AllocationsOf::<Test>::get(ben).into_inner(),
+            vec![0, 2, 3]
+        );
+        let ids: Vec<_> = TokenAllocation::allocations_of(&ben)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ids, vec![0, 2, 3]);
+
+        // The index of an account is bounded by `MaxAllocationsPerAccount`.
+        assert_noop!(
+            TokenAllocation::add_allocation(
+                RuntimeOrigin::root(),
+                EnvelopeId::Public2,
+                ben,
+                100,
+                None
+            ),
+            Error::<Test>::TooMuchAllocations
+        );
+    });
+}
+
+#[test]
+fn only_beneficiary_can_claim() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Public2, 1000, 0, 0, 100, None);
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public2,
+            1,
+            100,
+            Some(0)
+        ));
+
+        run_to_block(5);
+        assert_noop!(
+            TokenAllocation::claim(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::NotBeneficiary
+        );
+    });
+}
+
+#[test]
+fn revoke_allocation_claws_back_unvested_funds() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Teams, 1000, 0, 0, 100, None);
+        let ben = 1u128;
+        let envelope_acc = EnvelopeId::Teams.account::<Test>();
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Teams,
+            ben,
+            1000,
+            Some(0)
+        ));
+        assert_eq!(Balances::free_balance(envelope_acc), 0);
+
+        // 20% vested and claimed, 20% more vested but not claimed yet.
+        run_to_block(20);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(ben), 0));
+        run_to_block(40);
+
+        assert_ok!(TokenAllocation::revoke_allocation(RuntimeOrigin::root(), 0));
+        System::assert_last_event(
+            crate::Event::<Test>::AllocationRevoked {
+                id: 0,
+                envelope: EnvelopeId::Teams,
+                beneficiary: ben,
+                vested: 200,
+                clawed_back: 600,
+            }
+            .into(),
+        );
+
+        // Vested funds settled, the rest is back in the envelope.
+        assert_eq!(Balances::free_balance(ben), 400);
+        assert_eq!(Balances::total_balance_on_hold(&ben), 0);
+        assert_eq!(Balances::free_balance(envelope_acc), 600);
+        assert_eq!(
+            crate::EnvelopeDistributed::<Test>::get(EnvelopeId::Teams),
+            400
+        );
+        assert!(crate::Allocations::<Test>::get(0).is_none());
+
+        // The clawed back amount can be allocated again.
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Teams,
+            2,
+            600,
+            Some(0)
+        ));
+    });
+}
+
+#[test]
+fn revoke_allocation_requires_revocable_envelope() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Public1, 1000, 0, 0, 100, None);
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public1,
+            1,
+            1000,
+            Some(0)
+        ));
+
+        assert_noop!(
+            TokenAllocation::revoke_allocation(RuntimeOrigin::signed(1), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            TokenAllocation::revoke_allocation(RuntimeOrigin::root(), 0),
+            Error::<Test>::NotRevocable
+        );
+    });
+}
+
+#[test]
+fn transfer_allocation_moves_held_balance() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Public1, 1000, 0, 0, 100, None);
+        let (old, new) = (1u128, 2u128);
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public1,
+            old,
+            1000,
+            Some(0)
+        ));
+        run_to_block(20);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(old), 0));
+
+        assert_noop!(
+            TokenAllocation::transfer_allocation(RuntimeOrigin::signed(new), 0, new),
+            Error::<Test>::NotBeneficiary
+        );
+        assert_noop!(
+            TokenAllocation::transfer_allocation(RuntimeOrigin::signed(old), 0, old),
+            Error::<Test>::SameBeneficiary
+        );
+
+        assert_ok!(TokenAllocation::transfer_allocation(
+            RuntimeOrigin::signed(old),
+            0,
+            new
+        ));
+        // Nothing moves until the recipient accepts.
+        assert_eq!(Balances::total_balance_on_hold(&old), 800);
+        assert_noop!(
+            TokenAllocation::accept_transfer(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::NotTransferRecipient
+        );
+        assert_ok!(TokenAllocation::accept_transfer(
+            RuntimeOrigin::signed(new),
+            0
+        ));
+
+        // Claimed funds stay with the old beneficiary, the rest moved on hold.
+        assert_eq!(Balances::free_balance(old), 200);
+        assert_eq!(Balances::total_balance_on_hold(&old), 0);
+        assert_eq!(Balances::total_balance_on_hold(&new), 800);
+        assert!(!crate::AllocationsOf::<Test>::contains_key(old));
+        assert_eq!(crate::AllocationsOf::<Test>::get(new).into_inner(), vec![0]);
+
+        // Vesting goes on unchanged for the new beneficiary.
+        run_to_block(50);
+        assert_noop!(
+            TokenAllocation::claim(RuntimeOrigin::signed(old), 0),
+            Error::<Test>::NotBeneficiary
+        );
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(new), 0));
+        assert_eq!(Balances::free_balance(new), 300);
+    });
+}
+
+#[test]
+fn transfer_allocation_waits_for_approval() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Private1, 1000, 0, 0, 100, None);
+        let (old, new) = (1u128, 2u128);
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Private1,
+            old,
+            1000,
+            Some(0)
+        ));
+
+        assert_ok!(TokenAllocation::transfer_allocation(
+            RuntimeOrigin::signed(old),
+            0,
+            new
+        ));
+        assert_eq!(
+            crate::PendingTransfers::<Test>::get(0),
+            Some(crate::PendingTransfer {
+                to: new,
+                approved: false
+            })
+        );
+        assert_noop!(
+            TokenAllocation::accept_transfer(RuntimeOrigin::signed(new), 0),
+            Error::<Test>::TransferNotApproved
+        );
+
+        assert_noop!(
+            TokenAllocation::approve_transfer(RuntimeOrigin::signed(old), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(TokenAllocation::approve_transfer(RuntimeOrigin::root(), 0));
+        assert_eq!(Balances::total_balance_on_hold(&old), 1000);
+        assert_ok!(TokenAllocation::accept_transfer(
+            RuntimeOrigin::signed(new),
+            0
+        ));
+
+        assert_eq!(Balances::total_balance_on_hold(&old), 0);
+        assert_eq!(Balances::total_balance_on_hold(&new), 1000);
+        assert_eq!(
+            crate::Allocations::<Test>::get(0).map(|a| a.beneficiary),
+            Some(new)
+        );
+        assert_noop!(
+            TokenAllocation::approve_transfer(RuntimeOrigin::root(), 0),
+            Error::<Test>::NoPendingTransfer
+        );
+    });
+}
+
+#[test]
+fn transfer_needs_room_in_the_recipient_index() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Private1, 1000, 0, 0, 100, None);
+        let (old, new) = (1u128, 2u128);
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Private1,
+            old,
+            100,
+            Some(0)
+        ));
+        assert_ok!(TokenAllocation::transfer_allocation(
+            RuntimeOrigin::signed(old),
+            0,
+            new
+        ));
+
+        // The recipient index fills up while the transfer waits for approval.
+        for _ in 0..MaxAllocationsPerAccount::get() {
+            assert_ok!(TokenAllocation::add_allocation(
+                RuntimeOrigin::root(),
+                EnvelopeId::Private1,
+                new,
+                100,
+                Some(0)
+            ));
+        }
+        assert_noop!(
+            TokenAllocation::approve_transfer(RuntimeOrigin::root(), 0),
+            Error::<Test>::TooMuchAllocations
+        );
+        assert_noop!(
+            TokenAllocation::transfer_allocation(RuntimeOrigin::signed(old), 0, new),
+            Error::<Test>::TooMuchAllocations
+        );
+    });
+}
+
+fn tranches(list: &[(u64, u8)]) -> VestingCurve<u64> {
+    VestingCurve::Tranches(
+        list.iter()
+            .map(|(at, p)| (*at, Percent::from_percent(*p)))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
+    )
+}
+
+#[test]
+fn linear_curve_unlocks_proportionally() {
+    let curve = VestingCurve::Linear { duration: 100u64 };
+
+    assert_eq!(curve.vested(1000u128, 0), 0);
+    assert_eq!(curve.vested(1000u128, 25), 250);
+    assert_eq!(curve.vested(1000u128, 100), 1000);
+    assert_eq!(curve.vested(1000u128, 1_000), 1000);
+    // A zero duration unlocks everything at once.
+    assert_eq!(
+        VestingCurve::Linear { duration: 0u64 }.vested(1000u128, 1),
+        1000
+    );
+    // U256 math: no overflow of (Balance * BlockNumber).
+    assert_eq!(
+        VestingCurve::Linear {
+            duration: 1_000_000u64
+        }
+        .vested(u128::MAX, 500_000),
+        u128::MAX / 2
+    );
+}
+
+#[test]
+fn stepped_curve_unlocks_by_period() {
+    // Monthly unlocks over 4 months, with 30 blocks months.
+    let curve = VestingCurve::Stepped {
+        period: 30u64,
+        count: 4,
+    };
+
+    assert!(curve.is_valid());
+    assert_eq!(curve.vested(1000u128, 29), 0);
+    assert_eq!(curve.vested(1000u128, 30), 250);
+    assert_eq!(curve.vested(1000u128, 89), 500);
+    assert_eq!(curve.vested(1000u128, 120), 1000);
+    assert_eq!(curve.vested(1000u128, 10_000), 1000);
+    // Rounding never exceeds the total.
+    assert_eq!(
+        VestingCurve::Stepped {
+            period: 1u64,
+            count: 3
+        }
+        .vested(1000u128, 2),
+        666
+    );
+
+    assert!(
+        !VestingCurve::Stepped {
+            period: 0u64,
+            count: 4
+        }
+        .is_valid()
+    );
+    assert!(
+        !VestingCurve::Stepped {
+            period: 30u64,
+            count: 0
+        }
+        .is_valid()
+    );
+}
+
+#[test]
+fn tranches_curve_unlocks_milestones() {
+    let curve = tranches(&[(10, 20), (50, 30), (100, 50)]);
+
+    assert!(curve.is_valid());
+    assert_eq!(curve.vested(1000u128, 9), 0);
+    assert_eq!(curve.vested(1000u128, 10), 200);
+    assert_eq!(curve.vested(1000u128, 99), 500);
+    assert_eq!(curve.vested(1000u128, 100), 1000);
+    assert_eq!(curve.vested(u128::MAX, 50), u128::MAX / 2);
+
+    // Must unlock exactly 100% with increasing milestones.
+    assert!(!tranches(&[(10, 20), (50, 30)]).is_valid());
+    assert!(!tranches(&[(10, 60), (50, 60)]).is_valid());
+    assert!(!tranches(&[(50, 50), (10, 50)]).is_valid());
+    assert!(!tranches(&[]).is_valid());
+}
+
+#[test]
+fn envelope_curve_applies_to_its_allocations() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Public1, 1000, 0, 0, 100, None);
+        let ben = 1u128;
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public1,
+            ben,
+            1000,
+            Some(0)
+        ));
+
+        assert_noop!(
+            TokenAllocation::set_envelope_curve(
+                RuntimeOrigin::root(),
+                EnvelopeId::Public1,
+                Some(tranches(&[(10, 20)]))
+            ),
+            Error::<Test>::InvalidVestingCurve
+        );
+        assert_ok!(TokenAllocation::set_envelope_curve(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public1,
+            Some(tranches(&[(10, 20), (30, 80)]))
+        ));
+
+        run_to_block(15);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(ben), 0));
+        assert_eq!(Balances::free_balance(ben), 200);
+
+        run_to_block(30);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(ben), 0));
+        assert_eq!(Balances::free_balance(ben), 1000);
+        assert!(crate::Allocations::<Test>::get(0).is_none());
+    });
+}
+
+#[test]
+fn allocation_overrides_take_precedence() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        // Envelope: cliff at block 100, linear over 100 blocks.
+        setup_and_fund_envelope(EnvelopeId::Private2, 2000, 0, 100, 100, None);
+        let (linear, stepped) = (1u128, 2u128);
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Private2,
+            linear,
+            1000,
+            None
+        ));
+        assert_noop!(
+            TokenAllocation::add_allocation_with_overrides(
+                RuntimeOrigin::root(),
+                EnvelopeId::Private2,
+                stepped,
+                1000,
+                None,
+                VestingOverride {
+                    cliff: None,
+                    curve: Some(VestingCurve::Stepped {
+                        period: 0,
+                        count: 2
+                    }),
+                },
+            ),
+            Error::<Test>::InvalidVestingCurve
+        );
+        // Investor specific terms: cliff at block 20, two steps of 20 blocks.
+        assert_ok!(TokenAllocation::add_allocation_with_overrides(
+            RuntimeOrigin::root(),
+            EnvelopeId::Private2,
+            stepped,
+            1000,
+            None,
+            VestingOverride {
+                cliff: Some(20),
+                curve: Some(VestingCurve::Stepped {
+                    period: 20,
+                    count: 2
+                }),
+            },
+        ));
+
+        run_to_block(40);
+        assert_noop!(
+            TokenAllocation::claim(RuntimeOrigin::signed(linear), 0),
+            Error::<Test>::NothingToClaim
+        );
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(stepped), 1));
+        assert_eq!(Balances::free_balance(stepped), 500);
+
+        run_to_block(150);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(linear), 0));
+        assert_eq!(Balances::free_balance(linear), 500);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(stepped), 1));
+        assert_eq!(Balances::free_balance(stepped), 1000);
+    });
+}
+
+#[test]
+fn supply_figures_exclude_locked_and_undistributed_funds() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        // 10% upfront, linear over 100 blocks.
+        setup_and_fund_envelope(EnvelopeId::Private1, 1000, 10, 0, 100, None);
+        let ben = 1u128;
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Private1,
+            ben,
+            500,
+            Some(0)
+        ));
+
+        // Only the upfront payment circulates: 450 are held, 500 still in the envelope.
+        assert_eq!(pallet_balances::TotalIssuance::<Test>::get(), 1000);
+        assert_eq!(TokenAllocation::circulating_supply(), 50);
+
+        let totals = TokenAllocation::envelope_totals();
+        assert_eq!(
+            totals,
+            vec![(
+                EnvelopeId::Private1,
+                crate::EnvelopeTotals {
+                    total_cap: 1000,
+                    distributed: 500,
+                    remaining: 500,
+                    locked: 450,
+                }
+            )]
+        );
+
+        assert_eq!(TokenAllocation::allocations_of(&ben).len(), 1);
+        assert!(TokenAllocation::allocations_of(&2).is_empty());
+        assert_eq!(TokenAllocation::claimable_at(0, 50), Some(225));
+        assert_eq!(TokenAllocation::claimable_at(1, 50), None);
+
+        // Claimed funds circulate.
+        run_to_block(50);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(ben), 0));
+        assert_eq!(TokenAllocation::circulating_supply(), 275);
+    });
+}
+
+#[test]
+fn unlock_schedule_projects_future_unlocks() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Private1, 1000, 10, 0, 100, None);
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Private1,
+            1,
+            500,
+            Some(0)
+        ));
+
+        // 450 vest over blocks 0..100, 4 of them are already claimable at block 1.
+        assert_eq!(
+            TokenAllocation::unlock_schedule(1, 25, 5),
+            vec![(26, 113), (51, 112), (76, 113), (101, 108), (126, 0)]
+        );
+    });
+}
+
+#[test]
+fn failed_release_is_queued_and_retried() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Public2, 1000, 0, 0, 100, None);
+        let ben = 1u128;
+        let reason: RuntimeHoldReason = crate::HoldReason::TokenAllocation.into();
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public2,
+            ben,
+            1000,
+            Some(0)
+        ));
+        // Leave less on hold than what vests by block 20.
+        assert_ok!(Balances::release(&reason, &ben, 950, Precision::Exact));
+
+        run_to_block(20);
+        // The failure is queued, the claim succeeds to keep the queue entry.
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(ben), 0));
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::TokenAllocation(crate::Event::ReleaseFailed {
+                id: 0,
+                amount: 200,
+                ..
+            })
+        )));
+        assert_eq!(Balances::total_balance_on_hold(&ben), 50);
+        assert_eq!(
+            TokenAllocation::failed_releases(),
+            vec![FailedRelease {
+                id: 0,
+                amount: 200,
+                retries: 0
+            }]
+        );
+
+        // Failing again bumps the retry counter.
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(ben), 0));
+        assert_eq!(TokenAllocation::failed_releases()[0].retries, 1);
+
+        // Once the hold is back, the next epoch retries the release on its own.
+        assert_ok!(Balances::hold(&reason, &ben, 940));
+        run_to_block(30);
+        assert_eq!(Balances::free_balance(ben), 310);
+        assert_eq!(Balances::total_balance_on_hold(&ben), 690);
+        assert!(TokenAllocation::failed_releases().is_empty());
+    });
+}
+
+#[test]
+fn allocation_of_missing_envelope_is_not_settled() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Teams, 1000, 0, 0, 100, None);
+        let ben = 1u128;
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Teams,
+            ben,
+            1000,
+            Some(0)
+        ));
+        let cfg = crate::Envelopes::<Test>::take(EnvelopeId::Teams).unwrap();
+        assert!(TokenAllocation::do_try_state().is_err());
+
+        // Without an envelope there is no schedule: nothing is released nor queued.
+        run_to_block(20);
+        assert_noop!(
+            TokenAllocation::claim(RuntimeOrigin::signed(ben), 0),
+            Error::<Test>::EnvelopeUnknown
+        );
+        assert_noop!(
+            TokenAllocation::force_settle(RuntimeOrigin::root(), 0),
+            Error::<Test>::EnvelopeUnknown
+        );
+
+        // Once the envelope is back, only what is vested is released.
+        crate::Envelopes::<Test>::insert(EnvelopeId::Teams, cfg);
+        assert_noop!(
+            TokenAllocation::force_settle(RuntimeOrigin::signed(ben), 0),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(TokenAllocation::force_settle(RuntimeOrigin::root(), 0));
+        assert_eq!(Balances::free_balance(ben), 200);
+        assert_eq!(Balances::total_balance_on_hold(&ben), 800);
+        assert_ok!(TokenAllocation::do_try_state());
+    });
+}
+
+#[test]
+fn failed_release_queue_is_bounded() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Teams, 1000, 0, 0, 100, None);
+        let reason: RuntimeHoldReason = crate::HoldReason::TokenAllocation.into();
+        for ben in 1..=3u128 {
+            assert_ok!(TokenAllocation::add_allocation(
+                RuntimeOrigin::root(),
+                EnvelopeId::Teams,
+                ben,
+                100,
+                Some(0)
+            ));
+            // Nothing left on hold, every release fails.
+            assert_ok!(Balances::release(&reason, &ben, 100, Precision::Exact));
+        }
+
+        run_to_block(20);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(1), 0));
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(2), 1));
+        // A full queue cannot record the failure, so the claim fails with the release error.
+        assert!(TokenAllocation::claim(RuntimeOrigin::signed(3), 2).is_err());
+        assert_eq!(TokenAllocation::failed_releases().len(), 2);
+
+        // Failed releases of allocations that are gone are dropped.
+        crate::Allocations::<Test>::remove(0);
+        assert_ok!(TokenAllocation::force_settle(RuntimeOrigin::root(), 0));
+        System::assert_last_event(crate::Event::<Test>::FailedReleaseDropped { id: 0 }.into());
+        assert_eq!(TokenAllocation::failed_releases().len(), 1);
+    });
+}
+
+/// Airdrop tree of `(0, 1, 100)`, `(1, 2, 200)` and `(2, 3, 300)`, returns the root and the
+/// proof of each leaf.
+fn airdrop_tree() -> (H256, Vec<Vec<H256>>) {
+    let leaves: Vec<H256> = [(0u32, 1u128, 100u128), (1, 2, 200), (2, 3, 300)]
+        .iter()
+        .map(|(index, who, amount)| airdrop_leaf(*index, who, *amount))
+        .collect();
+    let node = airdrop_node(leaves[0], leaves[1]);
+    let root = airdrop_node(node, leaves[2]);
+    let proofs = vec![
+        vec![leaves[1], leaves[2]],
+        vec![leaves[0], leaves[2]],
+        vec![node],
+    ];
+    (root, proofs)
+}
+
+fn start_airdrop(root: H256, total: u128, deadline: u64) {
+    setup_and_fund_envelope(EnvelopeId::Airdrop, 1000, 100, 0, 0, None);
+    assert_ok!(TokenAllocation::set_airdrop_root(
+        RuntimeOrigin::root(),
+        EnvelopeId::Airdrop,
+        root,
+        total,
+        deadline
+    ));
+}
+
+#[test]
+fn set_airdrop_root_checks_the_envelope() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        let (root, _) = airdrop_tree();
+        setup_and_fund_envelope(EnvelopeId::Public1, 1000, 10, 0, 100, None);
+        setup_and_fund_envelope(EnvelopeId::Airdrop, 1000, 100, 0, 0, None);
+
+        assert_noop!(
+            TokenAllocation::set_airdrop_root(
+                RuntimeOrigin::signed(1),
+                EnvelopeId::Airdrop,
+                root,
+                600,
+                50
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            TokenAllocation::set_airdrop_root(
+                RuntimeOrigin::root(),
+                EnvelopeId::Public1,
+                root,
+                600,
+                50
+            ),
+            Error::<Test>::NotFullyUpfront
+        );
+        assert_noop!(
+            TokenAllocation::set_airdrop_root(
+                RuntimeOrigin::root(),
+                EnvelopeId::Airdrop,
+                root,
+                1001,
+                50
+            ),
+            Error::<Test>::EnvelopeCapExceeded
+        );
+
+        assert_ok!(TokenAllocation::set_airdrop_root(
+            RuntimeOrigin::root(),
+            EnvelopeId::Airdrop,
+            root,
+            600,
+            50
+        ));
+        assert_eq!(
+            crate::EnvelopeDistributed::<Test>::get(EnvelopeId::Airdrop),
+            600
+        );
+        assert_noop!(
+            TokenAllocation::set_airdrop_root(
+                RuntimeOrigin::root(),
+                EnvelopeId::Airdrop,
+                root,
+                100,
+                50
+            ),
+            Error::<Test>::AirdropExists
+        );
+    });
+}
+
+#[test]
+fn airdrop_leaves_are_claimed_once_with_a_valid_proof() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        let (root, proofs) = airdrop_tree();
+        start_airdrop(root, 600, 50);
+        let proof = |i: usize| proofs[i].clone().try_into().unwrap();
+
+        // A proof for another amount or account does not match the root.
+        assert_noop!(
+            TokenAllocation::claim_airdrop(
+                RuntimeOrigin::signed(9),
+                EnvelopeId::Airdrop,
+                0,
+                1,
+                1000,
+                proof(0)
+            ),
+            Error::<Test>::InvalidProof
+        );
+        assert_noop!(
+            TokenAllocation::claim_airdrop(
+                RuntimeOrigin::signed(9),
+                EnvelopeId::Airdrop,
+                0,
+                2,
+                100,
+                proof(0)
+            ),
+            Error::<Test>::InvalidProof
+        );
+
+        // Anyone can relay a claim, funds go to the leaf account.
+        assert_ok!(TokenAllocation::claim_airdrop(
+            RuntimeOrigin::signed(9),
+            EnvelopeId::Airdrop,
+            0,
+            1,
+            100,
+            proof(0)
+        ));
+        assert_ok!(TokenAllocation::claim_airdrop(
+            RuntimeOrigin::none(),
+            EnvelopeId::Airdrop,
+            2,
+            3,
+            300,
+            proof(2)
+        ));
+        System::assert_last_event(
+            crate::Event::<Test>::AirdropClaimed {
+                envelope: EnvelopeId::Airdrop,
+                index: 2,
+                who: 3,
+                amount: 300,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(3), 300);
+        assert_eq!(Balances::free_balance(9), 0);
+
+        assert_noop!(
+            TokenAllocation::claim_airdrop(
+                RuntimeOrigin::signed(1),
+                EnvelopeId::Airdrop,
+                0,
+                1,
+                100,
+                proof(0)
+            ),
+            Error::<Test>::AlreadyClaimed
+        );
+        assert_eq!(
+            crate::Airdrops::<Test>::get(EnvelopeId::Airdrop).map(|a| a.claimed),
+            Some(400)
+        );
+    });
+}
+
+#[test]
+fn unsigned_airdrop_claims_are_validated() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        let (root, proofs) = airdrop_tree();
+        start_airdrop(root, 600, 50);
+        let call = |index: u32, who: u128, amount: u128, proof: &Vec<H256>| {
+            crate::Call::<Test>::claim_airdrop {
+                envelope: EnvelopeId::Airdrop,
+                index,
+                who,
+                amount,
+                proof: proof.clone().try_into().unwrap(),
+            }
+        };
+        let validate = |call: crate::Call<Test>| {
+            TokenAllocation::validate_unsigned(TransactionSource::External, &call)
+        };
+
+        assert!(validate(call(1, 2, 200, &proofs[1])).is_ok());
+        assert_eq!(
+            validate(call(1, 2, 201, &proofs[1])),
+            Err(InvalidTransaction::BadProof.into())
+        );
+
+        assert_ok!(TokenAllocation::claim_airdrop(
+            RuntimeOrigin::none(),
+            EnvelopeId::Airdrop,
+            1,
+            2,
+            200,
+            proofs[1].clone().try_into().unwrap()
+        ));
+        assert_eq!(
+            validate(call(1, 2, 200, &proofs[1])),
+            Err(InvalidTransaction::Stale.into())
+        );
+
+        // Claims are no longer valid after the deadline.
+        run_to_block(51);
+        assert_eq!(
+            validate(call(0, 1, 100, &proofs[0])),
+            Err(InvalidTransaction::Stale.into())
+        );
+    });
+}
+
+#[test]
+fn unclaimed_airdrop_is_swept_after_the_deadline() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        let (root, proofs) = airdrop_tree();
+        start_airdrop(root, 600, 50);
+
+        assert_ok!(TokenAllocation::claim_airdrop(
+            RuntimeOrigin::signed(1),
+            EnvelopeId::Airdrop,
+            0,
+            1,
+            100,
+            proofs[0].clone().try_into().unwrap()
+        ));
+        assert_noop!(
+            TokenAllocation::sweep_airdrop(RuntimeOrigin::signed(1), EnvelopeId::Airdrop),
+            Error::<Test>::AirdropNotEnded
+        );
+
+        run_to_block(51);
+        assert_noop!(
+            TokenAllocation::claim_airdrop(
+                RuntimeOrigin::signed(2),
+                EnvelopeId::Airdrop,
+                1,
+                2,
+                200,
+                proofs[1].clone().try_into().unwrap()
+            ),
+            Error::<Test>::AirdropEnded
+        );
+
+        assert_ok!(TokenAllocation::sweep_airdrop(
+            RuntimeOrigin::signed(1),
+            EnvelopeId::Airdrop
+        ));
+        System::assert_last_event(
+            crate::Event::<Test>::AirdropSwept {
+                envelope: EnvelopeId::Airdrop,
+                amount: 500,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(SweepDestination::get()), 500);
+        assert_eq!(
+            Balances::free_balance(EnvelopeId::Airdrop.account::<Test>()),
+            400
+        );
+        assert!(crate::Airdrops::<Test>::get(EnvelopeId::Airdrop).is_none());
+    });
+}
+
+#[test]
+fn swept_airdrop_bitmap_is_cleared_over_several_calls() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        let (root, proofs) = airdrop_tree();
+        start_airdrop(root, 600, 50);
+        assert_ok!(TokenAllocation::claim_airdrop(
+            RuntimeOrigin::signed(1),
+            EnvelopeId::Airdrop,
+            0,
+            1,
+            100,
+            proofs[0].clone().try_into().unwrap()
+        ));
+        // A second word, as claimed in a larger tree.
+        crate::AirdropClaimBitmap::<Test>::insert(root, 1, 1);
+        run_to_block(51);
+        assert_ok!(TokenAllocation::sweep_airdrop(
+            RuntimeOrigin::signed(1),
+            EnvelopeId::Airdrop
+        ));
+
+        // The root cannot be reused while its bitmap is left.
+        assert_noop!(
+            TokenAllocation::set_airdrop_root(
+                RuntimeOrigin::root(),
+                EnvelopeId::Airdrop,
+                root,
+                100,
+                100
+            ),
+            Error::<Test>::AirdropBitmapNotCleared
+        );
+
+        assert_ok!(TokenAllocation::clear_airdrop_bitmap(
+            RuntimeOrigin::signed(2),
+            root,
+            1
+        ));
+        assert_eq!(
+            crate::AirdropClaimBitmap::<Test>::iter_key_prefix(root).count(),
+            1
+        );
+        assert!(crate::SweptAirdropRoots::<Test>::contains_key(root));
+
+        assert_ok!(TokenAllocation::clear_airdrop_bitmap(
+            RuntimeOrigin::signed(2),
+            root,
+            10
+        ));
+        System::assert_last_event(crate::Event::<Test>::AirdropBitmapCleared { root }.into());
+        assert_eq!(
+            crate::AirdropClaimBitmap::<Test>::iter_key_prefix(root).count(),
+            0
+        );
+        assert_noop!(
+            TokenAllocation::clear_airdrop_bitmap(RuntimeOrigin::signed(2), root, 10),
+            Error::<Test>::NoAirdropBitmap
+        );
+
+        // A new airdrop with the same root starts with no claimed leaf.
+        assert_ok!(TokenAllocation::set_airdrop_root(
+            RuntimeOrigin::root(),
+            EnvelopeId::Airdrop,
+            root,
+            100,
+            100
+        ));
+        assert_ok!(TokenAllocation::claim_airdrop(
+            RuntimeOrigin::signed(1),
+            EnvelopeId::Airdrop,
+            0,
+            1,
+            100,
+            proofs[0].clone().try_into().unwrap()
+        ));
+    });
+}
+
+fn envelope_config(total_cap: u128) -> EnvelopeConfig<u128, u64, u128> {
+    EnvelopeConfig {
+        total_cap,
+        upfront_rate: Percent::from_percent(0),
+        cliff: 0,
+        vesting_duration: 100,
+        unique_beneficiary: None,
+        revocable: false,
+        transfer_approval: false,
+        curve: None,
+    }
+}
+
+#[test]
+fn genesis_envelope_ids_keep_their_encoding() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        use frame_support::PalletId;
+        use parity_scale_codec::Encode;
+        use sp_runtime::traits::AccountIdConversion;
+
+        assert_eq!(EnvelopeId::Teams.encode(), vec![0]);
+        assert_eq!(EnvelopeId::Reserve.encode(), vec![12]);
+        assert_eq!(
+            EnvelopeId::Reserve.account::<Test>(),
+            PalletId(*b"tkalloc8").into_sub_account_truncating(12u8)
+        );
+        assert_ne!(
+            EnvelopeId::Custom(0).account::<Test>(),
+            EnvelopeId::Teams.account::<Test>()
+        );
+    });
+}
+
+#[test]
+fn create_envelope_moves_funds_without_minting() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Reserve, 1000, 0, 0, 100, None);
+        let funding = FundingAccount::get();
+        let _ = Balances::mint_into(&funding, 500);
+        let issuance = pallet_balances::TotalIssuance::<Test>::get();
+
+        assert_noop!(
+            TokenAllocation::create_envelope(
+                RuntimeOrigin::signed(1),
+                EnvelopeId::Custom(0),
+                envelope_config(300),
+                EnvelopeFunds::FundingAccount
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            TokenAllocation::create_envelope(
+                RuntimeOrigin::root(),
+                EnvelopeId::Reserve,
+                envelope_config(300),
+                EnvelopeFunds::FundingAccount
+            ),
+            Error::<Test>::EnvelopeExists
+        );
+
+        assert_ok!(TokenAllocation::create_envelope(
+            RuntimeOrigin::root(),
+            EnvelopeId::Custom(0),
+            envelope_config(300),
+            EnvelopeFunds::FundingAccount
+        ));
+        System::assert_last_event(
+            crate::Event::<Test>::EnvelopeCreated {
+                id: EnvelopeId::Custom(0),
+                total_cap: 300,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(funding), 200);
+
+        // Out of another envelope, only its undistributed part can be taken.
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Reserve,
+            1,
+            600,
+            None
+        ));
+        assert_noop!(
+            TokenAllocation::create_envelope(
+                RuntimeOrigin::root(),
+                EnvelopeId::Custom(1),
+                envelope_config(500),
+                EnvelopeFunds::Envelope(EnvelopeId::Reserve)
+            ),
+            Error::<Test>::EnvelopeCapExceeded
+        );
+        assert_ok!(TokenAllocation::create_envelope(
+            RuntimeOrigin::root(),
+            EnvelopeId::Custom(1),
+            envelope_config(400),
+            EnvelopeFunds::Envelope(EnvelopeId::Reserve)
+        ));
+        assert_eq!(
+            crate::Envelopes::<Test>::get(EnvelopeId::Reserve).map(|c| c.total_cap),
+            Some(600)
+        );
+        assert_eq!(
+            Balances::free_balance(EnvelopeId::Custom(1).account::<Test>()),
+            400
+        );
+
+        // New envelopes work like the genesis ones.
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Custom(1),
+            2,
+            400,
+            Some(0)
+        ));
+        assert_eq!(pallet_balances::TotalIssuance::<Test>::get(), issuance);
+    });
+}
+
+#[test]
+fn set_envelope_cap_stays_within_available_funds() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Public1, 1000, 0, 0, 100, None);
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public1,
+            1,
+            400,
+            None
+        ));
+
+        assert_noop!(
+            TokenAllocation::set_envelope_cap(RuntimeOrigin::root(), EnvelopeId::Public1, 300),
+            Error::<Test>::CapBelowDistributed
+        );
+        assert_noop!(
+            TokenAllocation::set_envelope_cap(RuntimeOrigin::root(), EnvelopeId::Public1, 1001),
+            Error::<Test>::InsufficientEnvelopeFunds
+        );
+
+        assert_ok!(TokenAllocation::set_envelope_cap(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public1,
+            500
+        ));
+        assert_noop!(
+            TokenAllocation::add_allocation(
+                RuntimeOrigin::root(),
+                EnvelopeId::Public1,
+                2,
+                101,
+                None
+            ),
+            Error::<Test>::EnvelopeCapExceeded
+        );
+
+        // Funds sent to the envelope account allow a higher cap.
+        let _ = Balances::mint_into(&EnvelopeId::Public1.account::<Test>(), 500);
+        assert_ok!(TokenAllocation::set_envelope_cap(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public1,
+            1500
+        ));
+        System::assert_last_event(
+            crate::Event::<Test>::EnvelopeCapSet {
+                id: EnvelopeId::Public1,
+                total_cap: 1500,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn close_envelope_returns_undistributed_funds() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Reserve, 1000, 0, 0, 100, None);
+        setup_and_fund_envelope(EnvelopeId::Public1, 1000, 0, 0, 100, None);
+        setup_and_fund_envelope(EnvelopeId::Public2, 1000, 0, 0, 100, None);
+        let funding = FundingAccount::get();
+
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public1,
+            1,
+            400,
+            Some(0)
+        ));
+        assert_noop!(
+            TokenAllocation::close_envelope(
+                RuntimeOrigin::root(),
+                EnvelopeId::Public1,
+                EnvelopeFunds::Envelope(EnvelopeId::Public1)
+            ),
+            Error::<Test>::SameEnvelope
+        );
+
+        // The envelope is kept for its allocations, which keep vesting.
+        assert_ok!(TokenAllocation::close_envelope(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public1,
+            EnvelopeFunds::Envelope(EnvelopeId::Reserve)
+        ));
+        System::assert_last_event(
+            crate::Event::<Test>::EnvelopeClosed {
+                id: EnvelopeId::Public1,
+                returned: 600,
+            }
+            .into(),
+        );
+        assert_eq!(
+            crate::Envelopes::<Test>::get(EnvelopeId::Reserve).map(|c| c.total_cap),
+            Some(1600)
+        );
+        assert_eq!(
+            Balances::free_balance(EnvelopeId::Reserve.account::<Test>()),
+            1600
+        );
+        // Nothing can be distributed nor closed anymore.
+        assert_noop!(
+            TokenAllocation::add_allocation(RuntimeOrigin::root(), EnvelopeId::Public1, 2, 1, None),
+            Error::<Test>::EnvelopeClosed
+        );
+        assert_noop!(
+            TokenAllocation::set_envelope_cap(RuntimeOrigin::root(), EnvelopeId::Public1, 400),
+            Error::<Test>::EnvelopeClosed
+        );
+        assert_noop!(
+            TokenAllocation::close_envelope(
+                RuntimeOrigin::root(),
+                EnvelopeId::Public1,
+                EnvelopeFunds::FundingAccount
+            ),
+            Error::<Test>::EnvelopeClosed
+        );
+        run_to_block(50);
+        assert_ok!(TokenAllocation::claim(RuntimeOrigin::signed(1), 0));
+        assert_eq!(Balances::free_balance(1), 200);
+
+        // An envelope that never distributed anything is removed.
+        assert_ok!(TokenAllocation::close_envelope(
+            RuntimeOrigin::root(),
+            EnvelopeId::Public2,
+            EnvelopeFunds::FundingAccount
+        ));
+        assert_eq!(Balances::free_balance(funding), 1000);
+        assert!(crate::Envelopes::<Test>::get(EnvelopeId::Public2).is_none());
+    });
+}
+
+#[test]
+fn revoke_after_close_returns_funds_to_the_funding_account() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Teams, 1000, 0, 0, 100, None);
+        assert_ok!(TokenAllocation::add_allocation(
+            RuntimeOrigin::root(),
+            EnvelopeId::Teams,
+            1,
+            400,
+            Some(0)
+        ));
+        assert_ok!(TokenAllocation::close_envelope(
+            RuntimeOrigin::root(),
+            EnvelopeId::Teams,
+            EnvelopeFunds::FundingAccount
+        ));
+        assert_eq!(Balances::free_balance(FundingAccount::get()), 600);
+
+        run_to_block(50);
+        assert_ok!(TokenAllocation::revoke_allocation(RuntimeOrigin::root(), 0));
+
+        // The clawed back part leaves the closed envelope, whose cap follows what it distributed.
+        assert_eq!(Balances::free_balance(1), 200);
+        assert_eq!(Balances::free_balance(FundingAccount::get()), 800);
+        assert_eq!(
+            Balances::free_balance(EnvelopeId::Teams.account::<Test>()),
+            0
+        );
+        assert_eq!(
+            crate::Envelopes::<Test>::get(EnvelopeId::Teams).map(|c| c.total_cap),
+            Some(200)
+        );
+        assert_eq!(
+            crate::EnvelopeDistributed::<Test>::get(EnvelopeId::Teams),
+            200
+        );
+        assert_noop!(
+            TokenAllocation::add_allocation(RuntimeOrigin::root(), EnvelopeId::Teams, 2, 1, None),
+            Error::<Test>::EnvelopeClosed
+        );
+    });
+}
+
+#[test]
+fn airdrop_of_closed_envelope_cannot_be_claimed() {
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        let (root, proofs) = airdrop_tree();
+        start_airdrop(root, 600, 50);
+        assert_ok!(TokenAllocation::close_envelope(
+            RuntimeOrigin::root(),
+            EnvelopeId::Airdrop,
+            EnvelopeFunds::FundingAccount
+        ));
+
+        let call = crate::Call::<Test>::claim_airdrop {
+            envelope: EnvelopeId::Airdrop,
+            index: 0,
+            who: 1,
+            amount: 100,
+            proof: proofs[0].clone().try_into().unwrap(),
+        };
+        assert_eq!(
+            TokenAllocation::validate_unsigned(TransactionSource::External, &call),
+            Err(InvalidTransaction::Stale.into())
+        );
+        assert_noop!(
+            TokenAllocation::claim_airdrop(
+                RuntimeOrigin::signed(1),
+                EnvelopeId::Airdrop,
+                0,
+                1,
+                100,
+                proofs[0].clone().try_into().unwrap()
+            ),
+            Error::<Test>::EnvelopeClosed
+        );
+
+        // The reserved funds are still swept after the deadline.
+        run_to_block(51);
+        assert_ok!(TokenAllocation::sweep_airdrop(
+            RuntimeOrigin::signed(1),
+            EnvelopeId::Airdrop
+        ));
+        assert_eq!(Balances::free_balance(SweepDestination::get()), 600);
+    });
+}
+
+#[test]
+fn v4_migration_indexes_existing_allocations() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Teams, 1000, 0, 0, 100, None);
+        StorageVersion::new(3).put::<TokenAllocation>();
+        // v3 allocations, without any beneficiary index.
+        for (id, ben) in [(0, 1u128), (1, 2), (2, 1)] {
+            crate::Allocations::<Test>::insert(
+                id,
+                crate::Allocation {
+                    envelope: EnvelopeId::Teams,
+                    beneficiary: ben,
+                    total: 100,
+                    upfront: 0,
+                    vested_total: 100,
+                    released: 0,
+                    start: 0,
+                    overrides: VestingOverride::default(),
+                },
+            );
+        }
+        assert!(TokenAllocation::do_try_state().is_err());
+
+        crate::migrations::v4::MigrateToV4::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            crate::AllocationsOf::<Test>::get(1).into_inner(),
+            vec![0, 2]
+        );
+        assert_eq!(crate::AllocationsOf::<Test>::get(2).into_inner(), vec![1]);
+        assert_eq!(TokenAllocation::on_chain_storage_version(), 4);
+        assert_ok!(TokenAllocation::do_try_state());
+    });
+}
+
+#[test]
+fn allocations_over_the_index_bound_fail_try_state() {
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext(vec![], vec![]).execute_with(|| {
+        setup_and_fund_envelope(EnvelopeId::Teams, 1000, 0, 0, 100, None);
+        StorageVersion::new(3).put::<TokenAllocation>();
+        let max: u32 = <Test as crate::Config>::MaxAllocationsPerAccount::get();
+        for id in 0..=max {
+            crate::Allocations::<Test>::insert(
+                id,
+                crate::Allocation {
+                    envelope: EnvelopeId::Teams,
+                    beneficiary: 1u128,
+                    total: 100,
+                    upfront: 0,
+                    vested_total: 100,
+                    released: 0,
+                    start: 0,
+                    overrides: VestingOverride::default(),
+                },
+            );
+        }
+
+        crate::migrations::v4::MigrateToV4::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            crate::AllocationsOf::<Test>::decode_len(1),
+            Some(max as usize)
+        );
+        assert!(TokenAllocation::do_try_state().is_err());
+    });
+}
@@ -50,9 +50,23 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_token_allocation`.
 pub trait WeightInfo {
 	fn add_allocation() -> Weight;
+	fn claim() -> Weight;
 	fn on_initialize_noop() -> Weight;
-	fn on_initialize_partial() -> Weight;
-	fn on_initialize_epoch_finished(x: u32, ) -> Weight;
+	fn on_initialize_epoch() -> Weight;
+	fn revoke_allocation() -> Weight;
+	fn transfer_allocation() -> Weight;
+	fn approve_transfer() -> Weight;
+	fn add_allocation_with_overrides() -> Weight;
+	fn set_envelope_curve() -> Weight;
+	fn force_settle() -> Weight;
+	fn set_airdrop_root() -> Weight;
+	fn claim_airdrop(p: u32, ) -> Weight;
+	fn sweep_airdrop() -> Weight;
+	fn create_envelope() -> Weight;
+	fn set_envelope_cap() -> Weight;
+	fn close_envelope() -> Weight;
+	fn accept_transfer() -> Weight;
+	fn clear_airdrop_bitmap(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_token_allocation` using the Substrate node and recommended hardware.
@@ -70,14 +84,39 @@ impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
 	/// Storage: `TokenAllocation::Allocations` (r:0 w:1)
 	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	fn add_allocation() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1137`
 		//  Estimated: `6196`
 		// Minimum execution time: 74_125_000 picoseconds.
 		Weight::from_parts(75_167_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::FailedReleases` (r:1 w:1)
+	/// Proof: `TokenAllocation::FailedReleases` (`max_values`: Some(1), `max_size`: Some(1538), added: 2033, mode: `MaxEncodedLen`)
+	fn claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `3593`
+		// Minimum execution time: 48_250_000 picoseconds.
+		Weight::from_parts(49_417_000, 3593)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `TokenAllocation::NextPayoutAt` (r:1 w:0)
 	/// Proof: `TokenAllocation::NextPayoutAt` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -89,57 +128,260 @@ impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
 		Weight::from_parts(3_583_000, 1669)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
-	/// Storage: `TokenAllocation::NextPayoutAt` (r:1 w:0)
+	/// Storage: `TokenAllocation::NextPayoutAt` (r:1 w:1)
 	/// Proof: `TokenAllocation::NextPayoutAt` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TokenAllocation::EpochIndex` (r:1 w:0)
+	/// Storage: `TokenAllocation::EpochIndex` (r:1 w:1)
 	/// Proof: `TokenAllocation::EpochIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TokenAllocation::PayoutCursor` (r:1 w:1)
-	/// Proof: `TokenAllocation::PayoutCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TokenAllocation::Allocations` (r:257 w:256)
-	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn on_initialize_epoch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `184`
+		//  Estimated: `1669`
+		// Minimum execution time: 6_125_000 picoseconds.
+		Weight::from_parts(6_375_000, 1669)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
 	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
-	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:256 w:256)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:256 w:256)
-	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
-	fn on_initialize_partial() -> Weight {
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::FailedReleases` (r:1 w:1)
+	/// Proof: `TokenAllocation::FailedReleases` (`max_values`: Some(1), `max_size`: Some(1538), added: 2033, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn revoke_allocation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `86906`
-		//  Estimated: `723971`
-		// Minimum execution time: 9_118_288_000 picoseconds.
-		Weight::from_parts(9_135_455_000, 723971)
-			.saturating_add(T::DbWeight::get().reads(773_u64))
-			.saturating_add(T::DbWeight::get().writes(769_u64))
+		//  Measured:  `853`
+		//  Estimated: `6218`
+		// Minimum execution time: 92_417_000 picoseconds.
+		Weight::from_parts(94_083_000, 6218)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
-	/// Storage: `TokenAllocation::NextPayoutAt` (r:1 w:1)
-	/// Proof: `TokenAllocation::NextPayoutAt` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TokenAllocation::EpochIndex` (r:1 w:1)
-	/// Proof: `TokenAllocation::EpochIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TokenAllocation::PayoutCursor` (r:1 w:1)
-	/// Proof: `TokenAllocation::PayoutCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TokenAllocation::Allocations` (r:256 w:255)
-	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:0)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
 	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
-	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:255 w:255)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:0)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::PendingTransfers` (r:0 w:1)
+	/// Proof: `TokenAllocation::PendingTransfers` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn transfer_allocation() -> Weight {
+		Weight::from_parts(24_310_000, 3678)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenAllocation::PendingTransfers` (r:1 w:1)
+	/// Proof: `TokenAllocation::PendingTransfers` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:0)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	fn approve_transfer() -> Weight {
+		Weight::from_parts(16_420_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::NextAllocationId` (r:1 w:1)
+	/// Proof: `TokenAllocation::NextAllocationId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:255 w:255)
-	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 255]`.
-	fn on_initialize_epoch_finished(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `308 + x * (337 ±0)`
-		//  Estimated: `4232 + x * (2810 ±0)`
-		// Minimum execution time: 85_250_000 picoseconds.
-		Weight::from_parts(86_125_000, 4232)
-			// Standard Error: 24_290
-			.saturating_add(Weight::from_parts(35_396_431, 0).saturating_mul(x.into()))
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Allocations` (r:0 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn add_allocation_with_overrides() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1137`
+		//  Estimated: `6196`
+		// Minimum execution time: 77_458_000 picoseconds.
+		Weight::from_parts(79_041_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:1)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	fn set_envelope_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `175`
+		//  Estimated: `3625`
+		// Minimum execution time: 11_833_000 picoseconds.
+		Weight::from_parts(12_250_000, 3625)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::FailedReleases` (r:1 w:1)
+	/// Proof: `TokenAllocation::FailedReleases` (`max_values`: Some(1), `max_size`: Some(1538), added: 2033, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::PendingTransfers` (r:0 w:1)
+	/// Proof: `TokenAllocation::PendingTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn force_settle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2143`
+		//  Estimated: `3674`
+		// Minimum execution time: 57_833_000 picoseconds.
+		Weight::from_parts(59_291_000, 3674)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Airdrops` (r:1 w:1)
+	/// Proof: `TokenAllocation::Airdrops` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::SweptAirdropRoots` (r:1 w:0)
+	/// Proof: `TokenAllocation::SweptAirdropRoots` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_airdrop_root() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3625`
+		// Minimum execution time: 21_458_000 picoseconds.
+		Weight::from_parts(22_125_000, 3625)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TokenAllocation::Airdrops` (r:1 w:1)
+	/// Proof: `TokenAllocation::Airdrops` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AirdropClaimBitmap` (r:1 w:1)
+	/// Proof: `TokenAllocation::AirdropClaimBitmap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 32]`.
+	fn claim_airdrop(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `529`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_208_000 picoseconds.
+		Weight::from_parts(62_671_342, 6196)
+			// Standard Error: 1_126
+			.saturating_add(Weight::from_parts(1_386_217, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TokenAllocation::Airdrops` (r:1 w:1)
+	/// Proof: `TokenAllocation::Airdrops` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::SweptAirdropRoots` (r:0 w:1)
+	/// Proof: `TokenAllocation::SweptAirdropRoots` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn sweep_airdrop() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `529`
+		//  Estimated: `6196`
+		// Minimum execution time: 54_917_000 picoseconds.
+		Weight::from_parts(56_250_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:2 w:2)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn create_envelope() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `478`
+		//  Estimated: `6196`
+		// Minimum execution time: 58_042_000 picoseconds.
+		Weight::from_parts(59_625_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:1)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:0)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Airdrops` (r:1 w:0)
+	/// Proof: `TokenAllocation::Airdrops` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_envelope_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3629`
+		// Minimum execution time: 24_584_000 picoseconds.
+		Weight::from_parts(25_333_000, 3629)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
-			.saturating_add(Weight::from_parts(0, 2810).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:2 w:2)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:0)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:1)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn close_envelope() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `585`
+		//  Estimated: `6196`
+		// Minimum execution time: 57_125_000 picoseconds.
+		Weight::from_parts(58_459_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TokenAllocation::PendingTransfers` (r:1 w:1)
+	/// Proof: `TokenAllocation::PendingTransfers` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:2 w:2)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	fn accept_transfer() -> Weight {
+		Weight::from_parts(81_792_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `TokenAllocation::SweptAirdropRoots` (r:1 w:1)
+	/// Proof: `TokenAllocation::SweptAirdropRoots` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AirdropClaimBitmap` (r:1001 w:1000)
+	/// Proof: `TokenAllocation::AirdropClaimBitmap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn clear_airdrop_bitmap(n: u32, ) -> Weight {
+		Weight::from_parts(14_208_000, 3497)
+			.saturating_add(Weight::from_parts(2_875_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2535).saturating_mul(n.into()))
 	}
 }
 
@@ -157,14 +399,39 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
 	/// Storage: `TokenAllocation::Allocations` (r:0 w:1)
 	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	fn add_allocation() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1137`
 		//  Estimated: `6196`
 		// Minimum execution time: 74_125_000 picoseconds.
 		Weight::from_parts(75_167_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::FailedReleases` (r:1 w:1)
+	/// Proof: `TokenAllocation::FailedReleases` (`max_values`: Some(1), `max_size`: Some(1538), added: 2033, mode: `MaxEncodedLen`)
+	fn claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `3593`
+		// Minimum execution time: 48_250_000 picoseconds.
+		Weight::from_parts(49_417_000, 3593)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `TokenAllocation::NextPayoutAt` (r:1 w:0)
 	/// Proof: `TokenAllocation::NextPayoutAt` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -176,56 +443,259 @@ impl WeightInfo for () {
 		Weight::from_parts(3_583_000, 1669)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
-	/// Storage: `TokenAllocation::NextPayoutAt` (r:1 w:0)
+	/// Storage: `TokenAllocation::NextPayoutAt` (r:1 w:1)
 	/// Proof: `TokenAllocation::NextPayoutAt` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TokenAllocation::EpochIndex` (r:1 w:0)
+	/// Storage: `TokenAllocation::EpochIndex` (r:1 w:1)
 	/// Proof: `TokenAllocation::EpochIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TokenAllocation::PayoutCursor` (r:1 w:1)
-	/// Proof: `TokenAllocation::PayoutCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TokenAllocation::Allocations` (r:257 w:256)
-	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn on_initialize_epoch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `184`
+		//  Estimated: `1669`
+		// Minimum execution time: 6_125_000 picoseconds.
+		Weight::from_parts(6_375_000, 1669)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
 	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
-	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:256 w:256)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:256 w:256)
-	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
-	fn on_initialize_partial() -> Weight {
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::FailedReleases` (r:1 w:1)
+	/// Proof: `TokenAllocation::FailedReleases` (`max_values`: Some(1), `max_size`: Some(1538), added: 2033, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn revoke_allocation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `86906`
-		//  Estimated: `723971`
-		// Minimum execution time: 9_118_288_000 picoseconds.
-		Weight::from_parts(9_135_455_000, 723971)
-			.saturating_add(RocksDbWeight::get().reads(773_u64))
-			.saturating_add(RocksDbWeight::get().writes(769_u64))
+		//  Measured:  `853`
+		//  Estimated: `6218`
+		// Minimum execution time: 92_417_000 picoseconds.
+		Weight::from_parts(94_083_000, 6218)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
-	/// Storage: `TokenAllocation::NextPayoutAt` (r:1 w:1)
-	/// Proof: `TokenAllocation::NextPayoutAt` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TokenAllocation::EpochIndex` (r:1 w:1)
-	/// Proof: `TokenAllocation::EpochIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TokenAllocation::PayoutCursor` (r:1 w:1)
-	/// Proof: `TokenAllocation::PayoutCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TokenAllocation::Allocations` (r:256 w:255)
-	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:0)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
 	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
-	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:255 w:255)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:0)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::PendingTransfers` (r:0 w:1)
+	/// Proof: `TokenAllocation::PendingTransfers` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn transfer_allocation() -> Weight {
+		Weight::from_parts(24_310_000, 3678)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenAllocation::PendingTransfers` (r:1 w:1)
+	/// Proof: `TokenAllocation::PendingTransfers` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:0)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	fn approve_transfer() -> Weight {
+		Weight::from_parts(16_420_000, 3642)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::NextAllocationId` (r:1 w:1)
+	/// Proof: `TokenAllocation::NextAllocationId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:255 w:255)
-	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 255]`.
-	fn on_initialize_epoch_finished(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `308 + x * (337 ±0)`
-		//  Estimated: `4232 + x * (2810 ±0)`
-		// Minimum execution time: 85_250_000 picoseconds.
-		Weight::from_parts(86_125_000, 4232)
-			// Standard Error: 24_290
-			.saturating_add(Weight::from_parts(35_396_431, 0).saturating_mul(x.into()))
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Allocations` (r:0 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn add_allocation_with_overrides() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1137`
+		//  Estimated: `6196`
+		// Minimum execution time: 77_458_000 picoseconds.
+		Weight::from_parts(79_041_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:1)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	fn set_envelope_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `175`
+		//  Estimated: `3625`
+		// Minimum execution time: 11_833_000 picoseconds.
+		Weight::from_parts(12_250_000, 3625)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::FailedReleases` (r:1 w:1)
+	/// Proof: `TokenAllocation::FailedReleases` (`max_values`: Some(1), `max_size`: Some(1538), added: 2033, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::PendingTransfers` (r:0 w:1)
+	/// Proof: `TokenAllocation::PendingTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn force_settle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2143`
+		//  Estimated: `3674`
+		// Minimum execution time: 57_833_000 picoseconds.
+		Weight::from_parts(59_291_000, 3674)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Airdrops` (r:1 w:1)
+	/// Proof: `TokenAllocation::Airdrops` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::SweptAirdropRoots` (r:1 w:0)
+	/// Proof: `TokenAllocation::SweptAirdropRoots` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_airdrop_root() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3625`
+		// Minimum execution time: 21_458_000 picoseconds.
+		Weight::from_parts(22_125_000, 3625)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TokenAllocation::Airdrops` (r:1 w:1)
+	/// Proof: `TokenAllocation::Airdrops` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AirdropClaimBitmap` (r:1 w:1)
+	/// Proof: `TokenAllocation::AirdropClaimBitmap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 32]`.
+	fn claim_airdrop(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `529`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_208_000 picoseconds.
+		Weight::from_parts(62_671_342, 6196)
+			// Standard Error: 1_126
+			.saturating_add(Weight::from_parts(1_386_217, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TokenAllocation::Airdrops` (r:1 w:1)
+	/// Proof: `TokenAllocation::Airdrops` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::SweptAirdropRoots` (r:0 w:1)
+	/// Proof: `TokenAllocation::SweptAirdropRoots` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn sweep_airdrop() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `529`
+		//  Estimated: `6196`
+		// Minimum execution time: 54_917_000 picoseconds.
+		Weight::from_parts(56_250_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:2 w:2)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn create_envelope() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `478`
+		//  Estimated: `6196`
+		// Minimum execution time: 58_042_000 picoseconds.
+		Weight::from_parts(59_625_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:1)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:0)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Airdrops` (r:1 w:0)
+	/// Proof: `TokenAllocation::Airdrops` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_envelope_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3629`
+		// Minimum execution time: 24_584_000 picoseconds.
+		Weight::from_parts(25_333_000, 3629)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(x.into())))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(x.into())))
-			.saturating_add(Weight::from_parts(0, 2810).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:2 w:2)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:0)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:1)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn close_envelope() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `585`
+		//  Estimated: `6196`
+		// Minimum execution time: 57_125_000 picoseconds.
+		Weight::from_parts(58_459_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TokenAllocation::PendingTransfers` (r:1 w:1)
+	/// Proof: `TokenAllocation::PendingTransfers` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:2 w:2)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	fn accept_transfer() -> Weight {
+		Weight::from_parts(81_792_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `TokenAllocation::SweptAirdropRoots` (r:1 w:1)
+	/// Proof: `TokenAllocation::SweptAirdropRoots` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AirdropClaimBitmap` (r:1001 w:1000)
+	/// Proof: `TokenAllocation::AirdropClaimBitmap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn clear_airdrop_bitmap(n: u32, ) -> Weight {
+		Weight::from_parts(14_208_000, 3497)
+			.saturating_add(Weight::from_parts(2_875_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2535).saturating_mul(n.into()))
 	}
 }
@@ -0,0 +1,95 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{v1::account, v2::*};
+use frame_support::traits::Get;
+use frame_system::{RawOrigin, pallet_prelude::BlockNumberFor};
+use pallet_authorship::EventHandler;
+use sp_runtime::traits::{Bounded, Saturating, Zero};
+
+const SEED: u32 = 0;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_session_budget() {
+        let budget = BalanceOf::<T>::max_value();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, budget);
+
+        assert_eq!(SessionBudget::<T>::get(), budget);
+    }
+
+    #[benchmark]
+    fn set_reward_cap() {
+        let cap = Some(BalanceOf::<T>::max_value());
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, cap);
+
+        assert_eq!(RewardCap::<T>::get(), cap);
+    }
+
+    #[benchmark]
+    fn note_author() {
+        // Worst case: the map is full minus one and a new author is inserted.
+        for i in 1..T::MaxAuthors::get() {
+            let author: T::AccountId = account("author", i, SEED);
+            AuthoredBlocks::<T>::mutate(|authored| authored.try_insert(author, 1).unwrap());
+        }
+        let author: T::AccountId = account("author", 0, SEED);
+
+        #[block]
+        {
+            <Pallet<T> as EventHandler<T::AccountId, BlockNumberFor<T>>>::note_author(
+                author.clone(),
+            );
+        }
+
+        assert_eq!(AuthoredBlocks::<T>::get().get(&author), Some(&1));
+    }
+
+    #[benchmark]
+    fn reward_session(n: Linear<1, { T::MaxAuthors::get() }>) {
+        // Worst case: every author gets paid, one transfer each.
+        for i in 0..n {
+            let author: T::AccountId = account("author", i, SEED);
+            AuthoredBlocks::<T>::mutate(|authored| authored.try_insert(author, 1).unwrap());
+        }
+        let budget = T::Currency::minimum_balance()
+            .saturating_mul(1_000u32.into())
+            .saturating_mul(n.into());
+        T::Currency::set_balance(&T::RewardSource::get(), budget.saturating_mul(2u32.into()));
+        SessionBudget::<T>::put(budget);
+
+        #[block]
+        {
+            Pallet::<T>::reward_session(0);
+        }
+
+        assert!(AuthoredBlocks::<T>::get().is_empty());
+        let author: T::AccountId = account("author", 0, SEED);
+        assert!(!T::Currency::balance(&author).is_zero());
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(1_000), crate::mock::Test);
+}
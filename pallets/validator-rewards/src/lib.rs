@@ -0,0 +1,297 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet Validator Rewards
+//!
+//! Pays Proof-of-Authority validators a governance-set budget at the end of every session.
+//!
+//! ## Features
+//! - Counts the blocks authored by each validator through `pallet-authorship`.
+//! - At session end, splits the session budget pro rata of authored blocks.
+//! - Optional cap on the reward a single validator can receive per session.
+//! - Rewards are transferred from a configurable source account (the treasury), limited to its
+//!   reducible balance so held or frozen funds are never touched.
+//!
+//! ## Integration
+//! Wrap the runtime session manager with [`RewardingSessionManager`] and set this pallet as the
+//! `pallet_authorship` event handler.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+use frame_support::traits::fungible::{Inspect, Mutate};
+use sp_staking::SessionIndex;
+
+pub const LOG_TARGET: &str = "runtime::validator-rewards";
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        dispatch::DispatchClass,
+        pallet_prelude::*,
+        traits::tokens::{Fortitude, Preservation},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        Perbill,
+        traits::{Saturating, Zero},
+    };
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The currency rewards are paid in.
+        type Currency: Mutate<Self::AccountId>;
+
+        /// Origin allowed to set the session budget and the per-validator cap.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Account the rewards are paid from.
+        type RewardSource: Get<Self::AccountId>;
+
+        /// Max number of distinct authors tracked within a session.
+        #[pallet::constant]
+        type MaxAuthors: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Total amount distributed to the validators at the end of each session.
+    #[pallet::storage]
+    pub type SessionBudget<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Max reward a single validator can receive for one session.
+    #[pallet::storage]
+    pub type RewardCap<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+
+    /// Blocks authored by each validator during the current session.
+    #[pallet::storage]
+    pub type AuthoredBlocks<T: Config> =
+        StorageValue<_, BoundedBTreeMap<T::AccountId, u32, T::MaxAuthors>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        SessionBudgetSet {
+            budget: BalanceOf<T>,
+        },
+        RewardCapSet {
+            cap: Option<BalanceOf<T>>,
+        },
+        ValidatorRewarded {
+            session: SessionIndex,
+            validator: T::AccountId,
+            blocks: u32,
+            amount: BalanceOf<T>,
+        },
+        /// The reward of a validator could not be transferred and stays in the source account.
+        RewardFailed {
+            session: SessionIndex,
+            validator: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        SessionRewarded {
+            session: SessionIndex,
+            total: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the amount distributed to the validators at the end of each session.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_session_budget())]
+        pub fn set_session_budget(origin: OriginFor<T>, budget: BalanceOf<T>) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            SessionBudget::<T>::put(budget);
+            Self::deposit_event(Event::SessionBudgetSet { budget });
+            Ok(())
+        }
+
+        /// Set or remove the max reward a single validator can receive per session.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_reward_cap())]
+        pub fn set_reward_cap(origin: OriginFor<T>, cap: Option<BalanceOf<T>>) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            RewardCap::<T>::set(cap);
+            Self::deposit_event(Event::RewardCapSet { cap });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Pay the rewards of the ending session and reset the authorship counters.
+        pub fn reward_session(session: SessionIndex) {
+            let authored = AuthoredBlocks::<T>::take();
+            frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                T::WeightInfo::reward_session(authored.len() as u32),
+                DispatchClass::Mandatory,
+            );
+
+            let total_blocks = authored
+                .values()
+                .fold(0u32, |acc, blocks| acc.saturating_add(*blocks));
+
+            let source = T::RewardSource::get();
+            let available =
+                T::Currency::reducible_balance(&source, Preservation::Preserve, Fortitude::Polite);
+            let budget = SessionBudget::<T>::get().min(available);
+
+            if total_blocks.is_zero() || budget.is_zero() {
+                return;
+            }
+
+            let cap = RewardCap::<T>::get();
+            let mut total = BalanceOf::<T>::zero();
+
+            for (validator, blocks) in authored {
+                let mut amount = Perbill::from_rational(blocks, total_blocks).mul_floor(budget);
+                if let Some(cap) = cap {
+                    amount = amount.min(cap);
+                }
+                if amount.is_zero() {
+                    continue;
+                }
+
+                match T::Currency::transfer(&source, &validator, amount, Preservation::Preserve) {
+                    Ok(_) => {
+                        total.saturating_accrue(amount);
+                        Self::deposit_event(Event::ValidatorRewarded {
+                            session,
+                            validator,
+                            blocks,
+                            amount,
+                        });
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: LOG_TARGET,
+                            "Failed to reward validator for session {session}: {e:?}"
+                        );
+                        Self::deposit_event(Event::RewardFailed {
+                            session,
+                            validator,
+                            amount,
+                        });
+                    }
+                }
+            }
+
+            Self::deposit_event(Event::SessionRewarded { session, total });
+        }
+    }
+
+    impl<T: Config> pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+        fn note_author(author: T::AccountId) {
+            AuthoredBlocks::<T>::mutate(|authored| {
+                if let Some(blocks) = authored.get_mut(&author) {
+                    blocks.saturating_inc();
+                } else if authored.try_insert(author, 1).is_err() {
+                    log::warn!(target: LOG_TARGET, "Too many authors in session, block not counted.");
+                }
+            });
+
+            frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                T::WeightInfo::note_author(),
+                DispatchClass::Mandatory,
+            );
+        }
+    }
+}
+
+/// Session manager wrapper paying the validator rewards when a session ends.
+///
+/// All the validator set management is delegated to `Inner`.
+pub struct RewardingSessionManager<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T, Inner, ValidatorId> pallet_session::SessionManager<ValidatorId>
+    for RewardingSessionManager<T, Inner>
+where
+    T: Config,
+    Inner: pallet_session::SessionManager<ValidatorId>,
+{
+    fn new_session(new_index: SessionIndex) -> Option<Vec<ValidatorId>> {
+        Inner::new_session(new_index)
+    }
+
+    fn new_session_genesis(new_index: SessionIndex) -> Option<Vec<ValidatorId>> {
+        Inner::new_session_genesis(new_index)
+    }
+
+    fn end_session(end_index: SessionIndex) {
+        Inner::end_session(end_index);
+        Pallet::<T>::reward_session(end_index);
+    }
+
+    fn start_session(start_index: SessionIndex) {
+        Inner::start_session(start_index)
+    }
+}
+
+impl<T, Inner, ValidatorId, FullIdentification>
+    pallet_session::historical::SessionManager<ValidatorId, FullIdentification>
+    for RewardingSessionManager<T, Inner>
+where
+    T: Config,
+    Inner: pallet_session::historical::SessionManager<ValidatorId, FullIdentification>,
+{
+    fn new_session(new_index: SessionIndex) -> Option<Vec<(ValidatorId, FullIdentification)>> {
+        Inner::new_session(new_index)
+    }
+
+    fn new_session_genesis(
+        new_index: SessionIndex,
+    ) -> Option<Vec<(ValidatorId, FullIdentification)>> {
+        Inner::new_session_genesis(new_index)
+    }
+
+    fn end_session(end_index: SessionIndex) {
+        Inner::end_session(end_index);
+        Pallet::<T>::reward_session(end_index);
+    }
+
+    fn start_session(start_index: SessionIndex) {
+        Inner::start_session(start_index)
+    }
+}
@@ -0,0 +1,146 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AuthoredBlocks, Event, mock::*};
+use frame_support::{assert_noop, assert_ok, traits::fungible::Inspect};
+use pallet_authorship::EventHandler;
+
+fn author_blocks(validator: u64, blocks: u32) {
+    for _ in 0..blocks {
+        ValidatorRewards::note_author(validator);
+    }
+}
+
+#[test]
+fn authored_blocks_are_counted() {
+    new_test_ext(1_000).execute_with(|| {
+        author_blocks(1, 3);
+        author_blocks(2, 1);
+
+        let authored = AuthoredBlocks::<Test>::get();
+        assert_eq!(authored.get(&1), Some(&3));
+        assert_eq!(authored.get(&2), Some(&1));
+    });
+}
+
+#[test]
+fn budget_is_split_pro_rata_of_authored_blocks() {
+    new_test_ext(1_000).execute_with(|| {
+        assert_ok!(ValidatorRewards::set_session_budget(
+            RuntimeOrigin::root(),
+            100
+        ));
+        author_blocks(1, 3);
+        author_blocks(2, 1);
+
+        ValidatorRewards::reward_session(1);
+
+        assert_eq!(Balances::balance(&1), 10 + 75);
+        assert_eq!(Balances::balance(&2), 10 + 25);
+        assert_eq!(Balances::balance(&TREASURY), 1_000 - 100);
+        assert!(AuthoredBlocks::<Test>::get().is_empty());
+
+        System::assert_has_event(
+            Event::ValidatorRewarded {
+                session: 1,
+                validator: 1,
+                blocks: 3,
+                amount: 75,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::SessionRewarded {
+                session: 1,
+                total: 100,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn reward_cap_is_enforced() {
+    new_test_ext(1_000).execute_with(|| {
+        assert_ok!(ValidatorRewards::set_session_budget(
+            RuntimeOrigin::root(),
+            100
+        ));
+        assert_ok!(ValidatorRewards::set_reward_cap(
+            RuntimeOrigin::root(),
+            Some(40)
+        ));
+        author_blocks(1, 3);
+        author_blocks(2, 1);
+
+        ValidatorRewards::reward_session(1);
+
+        assert_eq!(Balances::balance(&1), 10 + 40);
+        assert_eq!(Balances::balance(&2), 10 + 25);
+        assert_eq!(Balances::balance(&TREASURY), 1_000 - 65);
+    });
+}
+
+#[test]
+fn budget_is_limited_to_reducible_source_balance() {
+    new_test_ext(51).execute_with(|| {
+        assert_ok!(ValidatorRewards::set_session_budget(
+            RuntimeOrigin::root(),
+            100
+        ));
+        author_blocks(1, 1);
+        author_blocks(2, 1);
+
+        ValidatorRewards::reward_session(1);
+
+        // Only 50 is reducible as the source keeps its existential deposit.
+        assert_eq!(Balances::balance(&1), 10 + 25);
+        assert_eq!(Balances::balance(&2), 10 + 25);
+        assert_eq!(Balances::balance(&TREASURY), 1);
+    });
+}
+
+#[test]
+fn nothing_is_paid_without_budget_or_blocks() {
+    new_test_ext(1_000).execute_with(|| {
+        author_blocks(1, 2);
+        ValidatorRewards::reward_session(1);
+        assert_eq!(Balances::balance(&1), 10);
+
+        assert_ok!(ValidatorRewards::set_session_budget(
+            RuntimeOrigin::root(),
+            100
+        ));
+        ValidatorRewards::reward_session(2);
+        assert_eq!(Balances::balance(&TREASURY), 1_000);
+    });
+}
+
+#[test]
+fn only_admin_can_configure_rewards() {
+    new_test_ext(1_000).execute_with(|| {
+        assert_noop!(
+            ValidatorRewards::set_session_budget(RuntimeOrigin::signed(1), 100),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ValidatorRewards::set_reward_cap(RuntimeOrigin::signed(1), None),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
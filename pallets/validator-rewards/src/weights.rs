@@ -0,0 +1,78 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::weights::constants::ParityDbWeight;
+use sp_runtime::Weight;
+
+/// Weight functions needed for pallet_validator_rewards.
+pub trait WeightInfo {
+    fn set_session_budget() -> Weight;
+    fn set_reward_cap() -> Weight;
+    fn note_author() -> Weight;
+    fn reward_session(n: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    /// Storage: `ValidatorRewards::SessionBudget` (r:0 w:1)
+    /// Proof: `ValidatorRewards::SessionBudget` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    fn set_session_budget() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `0`
+        // Minimum execution time: 6_100_000 picoseconds.
+        Weight::from_parts(6_480_000, 0).saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `ValidatorRewards::RewardCap` (r:0 w:1)
+    /// Proof: `ValidatorRewards::RewardCap` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    fn set_reward_cap() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `0`
+        // Minimum execution time: 6_050_000 picoseconds.
+        Weight::from_parts(6_420_000, 0).saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `ValidatorRewards::AuthoredBlocks` (r:1 w:1)
+    /// Proof: `ValidatorRewards::AuthoredBlocks` (`max_values`: Some(1), `max_size`: Some(577), added: 1072, mode: `MaxEncodedLen`)
+    fn note_author() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `317`
+        //  Estimated: `2062`
+        // Minimum execution time: 7_900_000 picoseconds.
+        Weight::from_parts(8_530_000, 2062)
+            .saturating_add(ParityDbWeight::get().reads(1_u64))
+            .saturating_add(ParityDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `ValidatorRewards::AuthoredBlocks` (r:1 w:1)
+    /// Proof: `ValidatorRewards::AuthoredBlocks` (`max_values`: Some(1), `max_size`: Some(577), added: 1072, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:6 w:6)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `ValidatorRewards::SessionBudget` (r:1 w:0)
+    /// Proof: `ValidatorRewards::SessionBudget` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `ValidatorRewards::RewardCap` (r:1 w:0)
+    /// Proof: `ValidatorRewards::RewardCap` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 5]`.
+    fn reward_session(n: u32) -> Weight {
+        Weight::from_parts(21_300_000, 3593)
+            .saturating_add(Weight::from_parts(24_800_000, 0).saturating_mul(n.into()))
+            .saturating_add(ParityDbWeight::get().reads(4_u64))
+            .saturating_add(ParityDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+            .saturating_add(ParityDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+    }
+}
@@ -18,11 +18,74 @@
 
 use super::*;
 use frame_benchmarking::{v1::account, v2::*};
-use frame_support::traits::Get;
+use frame_support::{
+    BoundedVec,
+    traits::{
+        Get,
+        fungible::{Inspect, InspectHold, Mutate, MutateHold},
+    },
+};
 use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Convert, Saturating, Zero};
 
 const SEED: u32 = 0;
 
+/// A profile filling every bounded field to its maximum size.
+fn max_profile<T: Config>() -> OperatorProfileOf<T> {
+    let field = || -> ProfileField<T::MaxProfileFieldLength> {
+        vec![b'/'; T::MaxProfileFieldLength::get() as usize]
+            .try_into()
+            .unwrap()
+    };
+    OperatorProfile {
+        organisation: field(),
+        website: field(),
+        bootnodes: (0..T::MaxBootnodes::get())
+            .map(|_| field())
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
+        telemetry_name: field(),
+        security_contact: field(),
+    }
+}
+
+/// Register a signed caller as member of the validator set.
+fn validator_caller<T: Config>() -> (T::AccountId, T::ValidatorId) {
+    let caller: T::AccountId = whitelisted_caller();
+    let validator = <T as pallet_session::Config>::ValidatorIdOf::convert(caller.clone())
+        .expect("benchmark caller maps to a validator id");
+    Validators::<T>::mutate(|vals| {
+        if !vals.contains(&validator) {
+            vals.try_push(validator.clone()).unwrap();
+        }
+    });
+    (caller, validator)
+}
+
+/// Fund the account of `validator` so it can afford its bond.
+fn fund_bond_account<T: Config>(validator: &T::ValidatorId) -> T::AccountId {
+    let account = T::ValidatorAccountOf::convert(validator.clone())
+        .expect("benchmark validator maps to an account");
+    let amount = T::ValidatorBond::get()
+        .saturating_mul(2u32.into())
+        .saturating_add(T::Currency::minimum_balance());
+    T::Currency::set_balance(&account, amount);
+    account
+}
+
+/// Fund and hold the bond of `validator`.
+fn bonded_account<T: Config>(validator: &T::ValidatorId) -> T::AccountId {
+    let account = fund_bond_account::<T>(validator);
+    T::Currency::hold(
+        &HoldReason::ValidatorBond.into(),
+        &account,
+        T::ValidatorBond::get(),
+    )
+    .unwrap();
+    account
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -33,6 +96,7 @@ mod benchmarks {
         Validators::<T>::kill();
 
         let new: T::ValidatorId = account("validator", 0, SEED);
+        let bond_account = fund_bond_account::<T>(&new);
 
         // Pre-fill validators up to N - 1 if needed
         for i in 1..T::MaxValidators::get() {
@@ -48,12 +112,17 @@ mod benchmarks {
         _(RawOrigin::Root, new.clone());
 
         assert!(Validators::<T>::get().contains(&new));
+        assert_eq!(
+            T::Currency::balance_on_hold(&HoldReason::ValidatorBond.into(), &bond_account),
+            T::ValidatorBond::get()
+        );
     }
 
     #[benchmark]
     fn remove_validator() {
         Validators::<T>::kill();
         let existing: T::ValidatorId = account("validator", 42, SEED);
+        bonded_account::<T>(&existing);
 
         Validators::<T>::mutate(|vals| {
             if !vals.contains(&existing.clone()) {
@@ -65,6 +134,71 @@ mod benchmarks {
         _(RawOrigin::Root, existing.clone());
 
         assert!(!Validators::<T>::get().contains(&existing));
+        if !T::ValidatorBond::get().is_zero() {
+            assert!(Unbonding::<T>::contains_key(&existing));
+        }
+    }
+
+    #[benchmark]
+    fn set_operator_profile() {
+        Validators::<T>::kill();
+        let (caller, validator) = validator_caller::<T>();
+        let profile = max_profile::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), profile.clone());
+
+        assert_eq!(OperatorProfiles::<T>::get(&validator), Some(profile));
+    }
+
+    #[benchmark]
+    fn clear_operator_profile() {
+        Validators::<T>::kill();
+        let (caller, validator) = validator_caller::<T>();
+        OperatorProfiles::<T>::insert(&validator, max_profile::<T>());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller));
+
+        assert!(OperatorProfiles::<T>::get(&validator).is_none());
+    }
+
+    #[benchmark]
+    fn slash_bond() {
+        let validator: T::ValidatorId = account("validator", 0, SEED);
+        let bond_account = bonded_account::<T>(&validator);
+        let reason: BoundedVec<u8, T::MaxSlashReasonLength> =
+            vec![0u8; T::MaxSlashReasonLength::get() as usize]
+                .try_into()
+                .unwrap();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            validator,
+            BalanceOf::<T>::max_value(),
+            reason,
+        );
+
+        assert!(
+            T::Currency::balance_on_hold(&HoldReason::ValidatorBond.into(), &bond_account).is_zero()
+        );
+    }
+
+    #[benchmark]
+    fn withdraw_unbonded() {
+        let validator: T::ValidatorId = account("validator", 0, SEED);
+        let bond_account = bonded_account::<T>(&validator);
+        Unbonding::<T>::insert(&validator, frame_system::Pallet::<T>::block_number());
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), validator.clone());
+
+        assert!(!Unbonding::<T>::contains_key(&validator));
+        assert!(
+            T::Currency::balance_on_hold(&HoldReason::ValidatorBond.into(), &bond_account).is_zero()
+        );
     }
 
     impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
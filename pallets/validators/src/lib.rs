@@ -24,6 +24,12 @@
 //! - Add or remove validators via Root or governance origin.
 //! - Integration with `pallet-session` to update the validator set at each new session.
 //! - Compatible with `pallet-session::historical` for tracking validators across sessions.
+//! - Operator registry: each validator publishes its organisation, website, public bootnodes,
+//!   telemetry name and security contact on-chain.
+//! - Accountability bond: an optional `ValidatorBond` is held from the validator account when it
+//!   joins the set. Governance can slash it, and it is released `UnbondingDelay` blocks after the
+//!   validator leaves the set. Genesis validators are not bonded, validators already in the set
+//!   when the bond was introduced are bonded by [`migrations::v1`].
 //!
 //! ## Security
 //! - Configurable maximum number of validators (`MaxValidators`).
@@ -49,16 +55,35 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+pub mod runtime_api;
+pub mod types;
 pub mod weights;
+pub use types::*;
 pub use weights::*;
 
 pub const LOG_TARGET: &str = "runtime::validators-set";
 
+use frame_support::traits::fungible::{Credit, Inspect};
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type CreditOf<T> = Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::{pallet_prelude::*, traits::BuildGenesisConfig};
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{
+            BuildGenesisConfig, Imbalance, OnUnbalanced,
+            fungible::{BalancedHold, InspectHold, Mutate, MutateHold},
+            tokens::Precision,
+        },
+    };
     use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Convert, Saturating, Zero};
 
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_session::Config {
@@ -66,17 +91,77 @@ pub mod pallet {
         #[pallet::constant]
         type MaxValidators: Get<u32>;
 
+        /// Max length in bytes of each field of an operator profile.
+        #[pallet::constant]
+        type MaxProfileFieldLength: Get<u32>;
+
+        /// Max number of public bootnodes an operator can advertise.
+        #[pallet::constant]
+        type MaxBootnodes: Get<u32>;
+
+        /// The currency validator bonds are held in.
+        type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + BalancedHold<Self::AccountId>
+            + Mutate<Self::AccountId>;
+
+        /// The overarching HoldReason type.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Maps a validator to the account its bond is held from.
+        type ValidatorAccountOf: Convert<Self::ValidatorId, Option<Self::AccountId>>;
+
+        /// Bond held from a validator account when it joins the set, zero disables bonding.
+        #[pallet::constant]
+        type ValidatorBond: Get<BalanceOf<Self>>;
+
+        /// Number of blocks the bond stays held after the validator left the set.
+        #[pallet::constant]
+        type UnbondingDelay: Get<BlockNumberFor<Self>>;
+
+        /// Origin allowed to add and remove validators.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to slash validator bonds.
+        type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Handler for the slashed part of the bonds (e.g. the treasury).
+        type Slash: OnUnbalanced<CreditOf<Self>>;
+
+        /// Max length in bytes of the reason attached to a slash.
+        #[pallet::constant]
+        type MaxSlashReasonLength: Get<u32>;
+
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: WeightInfo;
     }
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Accountability bond of a validator.
+        ValidatorBond,
+    }
+
     #[pallet::storage]
     pub type Validators<T: Config> =
         StorageValue<_, BoundedVec<T::ValidatorId, T::MaxValidators>, ValueQuery>;
 
+    /// Public operator information published by the validators themselves.
+    #[pallet::storage]
+    pub type OperatorProfiles<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::ValidatorId, OperatorProfileOf<T>, OptionQuery>;
+
+    /// Block from which the bond of a removed validator can be withdrawn.
+    #[pallet::storage]
+    pub type Unbonding<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::ValidatorId, BlockNumberFor<T>, OptionQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub initial_validators: Vec<T::ValidatorId>,
@@ -108,6 +193,25 @@ pub mod pallet {
         ValidatorAdded(T::ValidatorId),
         ValidatorRemoved(T::ValidatorId),
         ValidatorSetUpdated,
+        OperatorProfileSet(T::ValidatorId),
+        OperatorProfileCleared(T::ValidatorId),
+        BondHeld {
+            validator: T::ValidatorId,
+            amount: BalanceOf<T>,
+        },
+        BondSlashed {
+            validator: T::ValidatorId,
+            amount: BalanceOf<T>,
+            reason: BoundedVec<u8, T::MaxSlashReasonLength>,
+        },
+        BondUnbonding {
+            validator: T::ValidatorId,
+            unlock_at: BlockNumberFor<T>,
+        },
+        BondReleased {
+            validator: T::ValidatorId,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -115,15 +219,31 @@ pub mod pallet {
         ValidatorAlreadyPresent,
         ValidatorNotFound,
         TooManyValidators,
+        /// The signer does not map to a validator of the set.
+        NotAValidator,
+        /// The validator has no operator profile.
+        ProfileNotFound,
+        /// One of the advertised bootnodes is not a multiaddr.
+        InvalidBootnode,
+        /// The validator does not map to an account able to hold a bond.
+        NoBondAccount,
+        /// The validator has no bond held.
+        NoBond,
+        /// The validator bond is not unbonding.
+        NotUnbonding,
+        /// The unbonding delay has not elapsed yet.
+        UnbondingNotElapsed,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Add a new validator (Root or governance controlled)
+        ///
+        /// Holds the `ValidatorBond` from the validator account.
         #[pallet::call_index(0)]
         #[pallet::weight(<T as Config>::WeightInfo::add_validator())]
         pub fn add_validator(origin: OriginFor<T>, validator: T::ValidatorId) -> DispatchResult {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
 
             log::debug!(target: LOG_TARGET, "Validator addition initiated.");
 
@@ -135,15 +255,18 @@ pub mod pallet {
                 .try_push(validator.clone())
                 .map_err(|_| Error::<T>::TooManyValidators)?;
             Validators::<T>::put(&current);
+            Self::hold_bond(&validator)?;
             Self::deposit_event(Event::ValidatorAdded(validator));
             Ok(())
         }
 
         /// Remove a validator
+        ///
+        /// Its bond, if any, starts unbonding.
         #[pallet::call_index(1)]
         #[pallet::weight(<T as Config>::WeightInfo::remove_validator())]
         pub fn remove_validator(origin: OriginFor<T>, validator: T::ValidatorId) -> DispatchResult {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
 
             log::debug!(target: LOG_TARGET, "Validator removal initiated.");
 
@@ -154,9 +277,181 @@ pub mod pallet {
             }
             current.retain(|v| v != &validator);
             Validators::<T>::put(&current);
+
+            if OperatorProfiles::<T>::take(&validator).is_some() {
+                Self::deposit_event(Event::OperatorProfileCleared(validator.clone()));
+            }
+            Self::start_unbonding(&validator);
             Self::deposit_event(Event::ValidatorRemoved(validator));
             Ok(())
         }
+
+        /// Publish or replace the operator profile of the calling validator.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_operator_profile())]
+        pub fn set_operator_profile(
+            origin: OriginFor<T>,
+            profile: OperatorProfileOf<T>,
+        ) -> DispatchResult {
+            let validator = Self::ensure_validator(origin)?;
+            ensure!(profile.has_valid_bootnodes(), Error::<T>::InvalidBootnode);
+
+            OperatorProfiles::<T>::insert(&validator, profile);
+            Self::deposit_event(Event::OperatorProfileSet(validator));
+            Ok(())
+        }
+
+        /// Remove the operator profile of the calling validator.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::clear_operator_profile())]
+        pub fn clear_operator_profile(origin: OriginFor<T>) -> DispatchResult {
+            let validator = Self::ensure_validator(origin)?;
+
+            OperatorProfiles::<T>::take(&validator).ok_or(Error::<T>::ProfileNotFound)?;
+            Self::deposit_event(Event::OperatorProfileCleared(validator));
+            Ok(())
+        }
+
+        /// Slash up to `amount` of a validator bond, current or unbonding.
+        ///
+        /// The slashed funds are handed to `T::Slash`.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::slash_bond())]
+        pub fn slash_bond(
+            origin: OriginFor<T>,
+            validator: T::ValidatorId,
+            amount: BalanceOf<T>,
+            reason: BoundedVec<u8, T::MaxSlashReasonLength>,
+        ) -> DispatchResult {
+            T::SlashOrigin::ensure_origin(origin)?;
+
+            let account = Self::bond_account(&validator)?;
+            let bond_reason: T::RuntimeHoldReason = HoldReason::ValidatorBond.into();
+            ensure!(
+                !T::Currency::balance_on_hold(&bond_reason, &account).is_zero(),
+                Error::<T>::NoBond
+            );
+
+            let (credit, _) = T::Currency::slash(&bond_reason, &account, amount);
+            let amount = credit.peek();
+            T::Slash::on_unbalanced(credit);
+
+            log::debug!(target: LOG_TARGET, "Validator bond slashed.");
+
+            Self::deposit_event(Event::BondSlashed {
+                validator,
+                amount,
+                reason,
+            });
+            Ok(())
+        }
+
+        /// Release the bond of a removed validator once the unbonding delay elapsed.
+        ///
+        /// Callable by any signed origin.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_unbonded())]
+        pub fn withdraw_unbonded(
+            origin: OriginFor<T>,
+            validator: T::ValidatorId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let unlock_at = Unbonding::<T>::get(&validator).ok_or(Error::<T>::NotUnbonding)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= unlock_at,
+                Error::<T>::UnbondingNotElapsed
+            );
+
+            let account = Self::bond_account(&validator)?;
+            let amount = T::Currency::release_all(
+                &HoldReason::ValidatorBond.into(),
+                &account,
+                Precision::BestEffort,
+            )?;
+            Unbonding::<T>::remove(&validator);
+
+            Self::deposit_event(Event::BondReleased { validator, amount });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Ensure the origin is signed by an account mapping to a member of the validator set.
+        fn ensure_validator(origin: OriginFor<T>) -> Result<T::ValidatorId, DispatchError> {
+            let who = ensure_signed(origin)?;
+            let validator = <T as pallet_session::Config>::ValidatorIdOf::convert(who)
+                .ok_or(Error::<T>::NotAValidator)?;
+            ensure!(
+                Validators::<T>::get().contains(&validator),
+                Error::<T>::NotAValidator
+            );
+            Ok(validator)
+        }
+
+        fn bond_account(validator: &T::ValidatorId) -> Result<T::AccountId, DispatchError> {
+            T::ValidatorAccountOf::convert(validator.clone())
+                .ok_or_else(|| Error::<T>::NoBondAccount.into())
+        }
+
+        /// Top up the hold of a joining validator to `ValidatorBond`.
+        ///
+        /// A validator re-added while unbonding keeps its pending bond, only the missing part is
+        /// held.
+        pub(crate) fn hold_bond(validator: &T::ValidatorId) -> DispatchResult {
+            Unbonding::<T>::remove(validator);
+
+            let bond = T::ValidatorBond::get();
+            if bond.is_zero() {
+                return Ok(());
+            }
+
+            let account = Self::bond_account(validator)?;
+            let bond_reason: T::RuntimeHoldReason = HoldReason::ValidatorBond.into();
+            let missing = bond.saturating_sub(T::Currency::balance_on_hold(&bond_reason, &account));
+            if !missing.is_zero() {
+                T::Currency::hold(&bond_reason, &account, missing)?;
+                Self::deposit_event(Event::BondHeld {
+                    validator: validator.clone(),
+                    amount: missing,
+                });
+            }
+            Ok(())
+        }
+
+        /// Schedule the release of the bond of a leaving validator, if any.
+        fn start_unbonding(validator: &T::ValidatorId) {
+            let Some(account) = T::ValidatorAccountOf::convert(validator.clone()) else {
+                return;
+            };
+            if T::Currency::balance_on_hold(&HoldReason::ValidatorBond.into(), &account).is_zero() {
+                return;
+            }
+
+            let unlock_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingDelay::get());
+            Unbonding::<T>::insert(validator, unlock_at);
+            Self::deposit_event(Event::BondUnbonding {
+                validator: validator.clone(),
+                unlock_at,
+            });
+        }
+
+        /// Bootnodes advertised by the validators of the current session.
+        pub fn active_bootnodes() -> Vec<(T::ValidatorId, Vec<Vec<u8>>)> {
+            pallet_session::Pallet::<T>::validators()
+                .into_iter()
+                .filter_map(|validator| {
+                    let profile = OperatorProfiles::<T>::get(&validator)?;
+                    let bootnodes = profile
+                        .bootnodes
+                        .into_iter()
+                        .map(|addr| addr.into_inner())
+                        .collect::<Vec<_>>();
+                    (!bootnodes.is_empty()).then_some((validator, bootnodes))
+                })
+                .collect()
+        }
     }
 }
 
@@ -0,0 +1,81 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the validators pallet.
+
+/// Holds the `ValidatorBond` of the validators that joined the set before it existed.
+pub mod v1 {
+    use crate::*;
+    use alloc::vec::Vec;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{UncheckedOnRuntimeUpgrade, fungible::InspectHold},
+    };
+    use sp_runtime::traits::Convert;
+
+    /// Bonds every validator of the current set.
+    ///
+    /// Validators whose account cannot cover the bond are logged and stay unbonded, governance
+    /// can remove them or ask the operator to top up and be re-added.
+    pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let validators = Validators::<T>::get();
+            for validator in validators.iter() {
+                if let Err(e) = Pallet::<T>::hold_bond(validator) {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "could not hold the bond of validator {validator:?}: {e:?}",
+                    );
+                }
+            }
+
+            let count = validators.len() as u64;
+            T::DbWeight::get().reads_writes(
+                1u64.saturating_add(count.saturating_mul(2)),
+                count.saturating_mul(3),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let bond = T::ValidatorBond::get();
+            let unbonded = Validators::<T>::get()
+                .iter()
+                .filter_map(|validator| T::ValidatorAccountOf::convert(validator.clone()))
+                .filter(|account| {
+                    T::Currency::balance_on_hold(&HoldReason::ValidatorBond.into(), account) < bond
+                })
+                .count();
+            if unbonded > 0 {
+                log::warn!(target: LOG_TARGET, "{unbonded} validators are not fully bonded");
+            }
+            Ok(())
+        }
+    }
+
+    /// [`UncheckedMigrateToV1`] wrapped in a [`frame_support::migrations::VersionedMigration`].
+    pub type MigrateToV1<T> = frame_support::migrations::VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -16,7 +16,8 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{derive_impl, parameter_types, traits::tokens::imbalance::ResolveTo};
+use frame_system::EnsureRoot;
 use pallet_session::TestSessionHandler;
 use sp_runtime::{BuildStorage, testing::UintAuthorityId, traits::ConvertInto};
 
@@ -70,6 +71,12 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
     pub const MaxValidators: u32 = 5;
+    pub const MaxProfileFieldLength: u32 = 64;
+    pub const MaxBootnodes: u32 = 2;
+    pub const ValidatorBond: u64 = 100;
+    pub const UnbondingDelay: u64 = 10;
+    pub const MaxSlashReasonLength: u32 = 32;
+    pub const SlashDestination: u64 = 100;
     pub const Period: u64 = 3; // 3 blocks per session
     pub const Offset: u64 = 0;
 }
@@ -97,14 +104,34 @@ impl pallet_session::historical::Config for Test {
 
 impl pallet_validators::Config for Test {
     type MaxValidators = MaxValidators;
+    type MaxProfileFieldLength = MaxProfileFieldLength;
+    type MaxBootnodes = MaxBootnodes;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type ValidatorAccountOf = ConvertInto;
+    type ValidatorBond = ValidatorBond;
+    type UnbondingDelay = UnbondingDelay;
+    type AdminOrigin = EnsureRoot<u64>;
+    type SlashOrigin = EnsureRoot<u64>;
+    type Slash = ResolveTo<SlashDestination, Balances>;
+    type MaxSlashReasonLength = MaxSlashReasonLength;
     type WeightInfo = ();
 }
 
+pub const INITIAL_BALANCE: u64 = 1_000;
+
 pub fn new_test_ext() -> sp_io::TestExternalities {
     let mut storage = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
 
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=6).map(|who| (who, INITIAL_BALANCE)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
     pallet_validators::GenesisConfig::<Test> {
         initial_validators: vec![1, 2, 3],
     }
@@ -123,5 +150,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     .assimilate_storage(&mut storage)
     .unwrap();
 
-    sp_io::TestExternalities::new(storage)
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
 }
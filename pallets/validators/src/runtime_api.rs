@@ -0,0 +1,33 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the validators pallet.
+
+use alloc::vec::Vec;
+use parity_scale_codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Read access to the validator operator registry.
+    pub trait ValidatorsApi<ValidatorId>
+    where
+        ValidatorId: Codec,
+    {
+        /// The bootnode multiaddrs advertised by each validator of the active session.
+        fn active_bootnodes() -> Vec<(ValidatorId, Vec<Vec<u8>>)>;
+    }
+}
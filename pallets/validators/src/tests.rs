@@ -140,3 +140,256 @@ fn validator_set_changes_take_effect_in_next_session() {
         assert_eq!(Session::validators(), vec![2, 3, 4]);
     });
 }
+
+fn profile(bootnodes: &[&str]) -> crate::OperatorProfileOf<Test> {
+    crate::OperatorProfile {
+        organisation: b"Allfeat Labs".to_vec().try_into().unwrap(),
+        website: b"https://allfeat.com".to_vec().try_into().unwrap(),
+        bootnodes: bootnodes
+            .iter()
+            .map(|b| b.as_bytes().to_vec().try_into().unwrap())
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
+        telemetry_name: b"allfeat-validator-01".to_vec().try_into().unwrap(),
+        security_contact: b"security@allfeat.com".to_vec().try_into().unwrap(),
+    }
+}
+
+#[test]
+fn validator_can_set_and_clear_its_profile() {
+    new_test_ext().execute_with(|| {
+        let p = profile(&["/dns/boot.allfeat.com/tcp/30333/p2p/12D3KooW"]);
+        assert_ok!(Validators::set_operator_profile(
+            RuntimeOrigin::signed(1),
+            p.clone()
+        ));
+        assert_eq!(super::OperatorProfiles::<Test>::get(1), Some(p));
+
+        assert_ok!(Validators::clear_operator_profile(RuntimeOrigin::signed(1)));
+        assert!(super::OperatorProfiles::<Test>::get(1).is_none());
+
+        assert_noop!(
+            Validators::clear_operator_profile(RuntimeOrigin::signed(1)),
+            super::Error::<Test>::ProfileNotFound
+        );
+    });
+}
+
+#[test]
+fn non_validator_cannot_set_profile() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Validators::set_operator_profile(RuntimeOrigin::signed(4), profile(&[])),
+            super::Error::<Test>::NotAValidator
+        );
+    });
+}
+
+#[test]
+fn malformed_bootnode_is_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Validators::set_operator_profile(
+                RuntimeOrigin::signed(1),
+                profile(&["boot.allfeat.com:30333"])
+            ),
+            super::Error::<Test>::InvalidBootnode
+        );
+    });
+}
+
+#[test]
+fn removing_validator_removes_its_profile() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validators::set_operator_profile(
+            RuntimeOrigin::signed(2),
+            profile(&[])
+        ));
+        assert_ok!(Validators::remove_validator(RuntimeOrigin::root(), 2));
+        assert!(super::OperatorProfiles::<Test>::get(2).is_none());
+    });
+}
+
+#[test]
+fn active_bootnodes_only_lists_session_validators_with_bootnodes() {
+    new_test_ext().execute_with(|| {
+        for n in 0..3 {
+            Session::on_initialize(n);
+        }
+        let addr = "/ip4/10.0.0.1/tcp/30333/p2p/12D3KooW";
+        assert_ok!(Validators::set_operator_profile(
+            RuntimeOrigin::signed(1),
+            profile(&[addr])
+        ));
+        assert_ok!(Validators::set_operator_profile(
+            RuntimeOrigin::signed(2),
+            profile(&[])
+        ));
+
+        assert_eq!(
+            Validators::active_bootnodes(),
+            vec![(1, vec![addr.as_bytes().to_vec()])]
+        );
+    });
+}
+
+fn bond_held(who: u64) -> u64 {
+    use frame_support::traits::fungible::InspectHold;
+    Balances::balance_on_hold(&crate::HoldReason::ValidatorBond.into(), &who)
+}
+
+fn slash_reason() -> frame_support::BoundedVec<u8, MaxSlashReasonLength> {
+    b"equivocation".to_vec().try_into().unwrap()
+}
+
+#[test]
+fn adding_validator_holds_its_bond() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validators::add_validator(RuntimeOrigin::root(), 4));
+        assert_eq!(bond_held(4), ValidatorBond::get());
+        System::assert_has_event(
+            super::Event::<Test>::BondHeld {
+                validator: 4,
+                amount: ValidatorBond::get(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn adding_validator_without_funds_fails() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::Mutate;
+        Balances::set_balance(&5, ValidatorBond::get() / 2);
+        assert_noop!(
+            Validators::add_validator(RuntimeOrigin::root(), 5),
+            sp_runtime::TokenError::FundsUnavailable
+        );
+    });
+}
+
+#[test]
+fn bond_is_released_after_unbonding_delay() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validators::add_validator(RuntimeOrigin::root(), 4));
+        assert_ok!(Validators::remove_validator(RuntimeOrigin::root(), 4));
+        assert_eq!(
+            super::Unbonding::<Test>::get(4),
+            Some(1 + UnbondingDelay::get())
+        );
+
+        assert_noop!(
+            Validators::withdraw_unbonded(RuntimeOrigin::signed(5), 4),
+            super::Error::<Test>::UnbondingNotElapsed
+        );
+
+        System::set_block_number(1 + UnbondingDelay::get());
+        assert_ok!(Validators::withdraw_unbonded(RuntimeOrigin::signed(5), 4));
+        assert_eq!(bond_held(4), 0);
+        assert_eq!(Balances::free_balance(4), INITIAL_BALANCE);
+        assert!(super::Unbonding::<Test>::get(4).is_none());
+
+        assert_noop!(
+            Validators::withdraw_unbonded(RuntimeOrigin::signed(5), 4),
+            super::Error::<Test>::NotUnbonding
+        );
+    });
+}
+
+#[test]
+fn bond_of_active_validator_cannot_be_withdrawn() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validators::add_validator(RuntimeOrigin::root(), 4));
+        assert_noop!(
+            Validators::withdraw_unbonded(RuntimeOrigin::signed(4), 4),
+            super::Error::<Test>::NotUnbonding
+        );
+    });
+}
+
+#[test]
+fn readding_unbonding_validator_keeps_its_bond() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validators::add_validator(RuntimeOrigin::root(), 4));
+        assert_ok!(Validators::remove_validator(RuntimeOrigin::root(), 4));
+        assert_ok!(Validators::add_validator(RuntimeOrigin::root(), 4));
+
+        assert_eq!(bond_held(4), ValidatorBond::get());
+        assert!(super::Unbonding::<Test>::get(4).is_none());
+    });
+}
+
+#[test]
+fn governance_can_slash_bond_to_destination() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validators::add_validator(RuntimeOrigin::root(), 4));
+
+        assert_noop!(
+            Validators::slash_bond(RuntimeOrigin::signed(1), 4, 30, slash_reason()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Validators::slash_bond(
+            RuntimeOrigin::root(),
+            4,
+            30,
+            slash_reason()
+        ));
+        assert_eq!(bond_held(4), ValidatorBond::get() - 30);
+        assert_eq!(Balances::free_balance(SlashDestination::get()), 30);
+        System::assert_last_event(
+            super::Event::<Test>::BondSlashed {
+                validator: 4,
+                amount: 30,
+                reason: slash_reason(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn slash_is_capped_by_bond_and_applies_while_unbonding() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validators::add_validator(RuntimeOrigin::root(), 4));
+        assert_ok!(Validators::remove_validator(RuntimeOrigin::root(), 4));
+
+        assert_ok!(Validators::slash_bond(
+            RuntimeOrigin::root(),
+            4,
+            10 * ValidatorBond::get(),
+            slash_reason()
+        ));
+        assert_eq!(bond_held(4), 0);
+        assert_eq!(
+            Balances::free_balance(SlashDestination::get()),
+            ValidatorBond::get()
+        );
+
+        assert_noop!(
+            Validators::slash_bond(RuntimeOrigin::root(), 4, 1, slash_reason()),
+            super::Error::<Test>::NoBond
+        );
+    });
+}
+
+#[test]
+fn migration_bonds_existing_validators() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Validators>();
+        // Validator 3 cannot cover the bond and stays unbonded.
+        assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(3), 6, false));
+        assert_eq!(bond_held(1), 0);
+
+        crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(bond_held(1), ValidatorBond::get());
+        assert_eq!(bond_held(2), ValidatorBond::get());
+        assert_eq!(bond_held(3), 0);
+        assert_eq!(Validators::on_chain_storage_version(), 1);
+    });
+}
@@ -0,0 +1,74 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{
+    BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, traits::Get,
+};
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+use crate::Config;
+
+/// A bounded free-form text field of an operator profile.
+pub type ProfileField<S> = BoundedVec<u8, S>;
+
+pub type OperatorProfileOf<T> = OperatorProfile<
+    <T as Config>::MaxProfileFieldLength,
+    <T as Config>::MaxBootnodes,
+>;
+
+/// Public information published on-chain by a validator operator.
+///
+/// All fields are raw UTF-8 bytes, the pallet does not interpret them beyond basic sanity
+/// checks on the bootnode multiaddrs.
+#[derive(
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    RuntimeDebugNoBound,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[codec(mel_bound())]
+#[scale_info(skip_type_params(FieldLimit, BootnodeLimit))]
+pub struct OperatorProfile<FieldLimit: Get<u32>, BootnodeLimit: Get<u32>> {
+    /// Name of the organisation operating the validator.
+    pub organisation: ProfileField<FieldLimit>,
+    /// Public website of the organisation.
+    pub website: ProfileField<FieldLimit>,
+    /// Public bootnode multiaddrs, e.g. `/dns/boot.example.com/tcp/30333/p2p/12D3KooW...`.
+    pub bootnodes: BoundedVec<ProfileField<FieldLimit>, BootnodeLimit>,
+    /// Node name reported to telemetry, used to match the validator on dashboards.
+    pub telemetry_name: ProfileField<FieldLimit>,
+    /// Contact to reach in case of a security incident (email, Matrix handle...).
+    pub security_contact: ProfileField<FieldLimit>,
+}
+
+impl<FieldLimit: Get<u32>, BootnodeLimit: Get<u32>> OperatorProfile<FieldLimit, BootnodeLimit> {
+    /// Whether every advertised bootnode looks like a multiaddr.
+    ///
+    /// Full multiaddr parsing is left to the node, we only reject obviously malformed entries.
+    pub fn has_valid_bootnodes(&self) -> bool {
+        self.bootnodes
+            .iter()
+            .all(|addr| addr.first() == Some(&b'/') && core::str::from_utf8(addr).is_ok())
+    }
+}
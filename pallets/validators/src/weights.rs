@@ -23,29 +23,99 @@ use sp_runtime::Weight;
 pub trait WeightInfo {
     fn add_validator() -> Weight;
     fn remove_validator() -> Weight;
+    fn set_operator_profile() -> Weight;
+    fn clear_operator_profile() -> Weight;
+    fn slash_bond() -> Weight;
+    fn withdraw_unbonded() -> Weight;
 }
 
 impl WeightInfo for () {
     /// Storage: `Validators::Validators` (r:1 w:1)
     /// Proof: `Validators::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `Validators::Unbonding` (r:0 w:1)
+    /// Proof: `Validators::Unbonding` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
     fn add_validator() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `301`
+        //  Estimated: `3593`
+        // Minimum execution time: 41_300_000 picoseconds.
+        Weight::from_parts(43_100_000, 3593)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Validators::Validators` (r:1 w:1)
+    /// Proof: `Validators::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `Validators::OperatorProfiles` (r:0 w:1)
+    /// Proof: `Validators::OperatorProfiles` (`max_values`: None, `max_size`: Some(1370), added: 3845, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:0)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+    /// Storage: `Validators::Unbonding` (r:0 w:1)
+    /// Proof: `Validators::Unbonding` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn remove_validator() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `204`
+        //  Estimated: `3568`
+        // Minimum execution time: 24_600_000 picoseconds.
+        Weight::from_parts(26_050_000, 3568)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Validators::Validators` (r:1 w:0)
+    /// Proof: `Validators::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `Validators::OperatorProfiles` (r:0 w:1)
+    /// Proof: `Validators::OperatorProfiles` (`max_values`: None, `max_size`: Some(1370), added: 3845, mode: `MaxEncodedLen`)
+    fn set_operator_profile() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `198`
         //  Estimated: `1683`
-        // Minimum execution time: 18_000_000 picoseconds.
-        Weight::from_parts(19_400_000, 1683)
+        // Minimum execution time: 17_200_000 picoseconds.
+        Weight::from_parts(18_600_000, 1683)
             .saturating_add(ParityDbWeight::get().reads(1_u64))
             .saturating_add(ParityDbWeight::get().writes(1_u64))
     }
-    /// Storage: `Validators::Validators` (r:1 w:1)
+    /// Storage: `Validators::Validators` (r:1 w:0)
     /// Proof: `Validators::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-    fn remove_validator() -> Weight {
+    /// Storage: `Validators::OperatorProfiles` (r:1 w:1)
+    /// Proof: `Validators::OperatorProfiles` (`max_values`: None, `max_size`: Some(1370), added: 3845, mode: `MaxEncodedLen`)
+    fn clear_operator_profile() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `101`
-        //  Estimated: `1586`
-        // Minimum execution time: 16_800_000 picoseconds.
-        Weight::from_parts(19_120_000, 1586)
-            .saturating_add(ParityDbWeight::get().reads(1_u64))
+        //  Measured:  `842`
+        //  Estimated: `4835`
+        // Minimum execution time: 19_900_000 picoseconds.
+        Weight::from_parts(21_300_000, 4835)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
             .saturating_add(ParityDbWeight::get().writes(1_u64))
     }
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn slash_bond() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `317`
+        //  Estimated: `3593`
+        // Minimum execution time: 38_700_000 picoseconds.
+        Weight::from_parts(40_200_000, 3593)
+            .saturating_add(ParityDbWeight::get().reads(2_u64))
+            .saturating_add(ParityDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Validators::Unbonding` (r:1 w:1)
+    /// Proof: `Validators::Unbonding` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn withdraw_unbonded() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `389`
+        //  Estimated: `3593`
+        // Minimum execution time: 39_900_000 picoseconds.
+        Weight::from_parts(41_500_000, 3593)
+            .saturating_add(ParityDbWeight::get().reads(3_u64))
+            .saturating_add(ParityDbWeight::get().writes(3_u64))
+    }
 }
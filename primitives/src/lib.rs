@@ -20,21 +20,25 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 use frame_support::sp_runtime::{
-    MultiAddress, MultiSignature, OpaqueExtrinsic, generic,
+    MultiAddress, OpaqueExtrinsic, generic,
     traits::{BlakeTwo256, IdentifyAccount, Verify},
 };
 use sp_core::H256;
 
+pub mod meta_tx;
+mod signature;
+pub use signature::{
+    P256Public, Signature, Signer, WEBAUTHN_ORIGIN, WEBAUTHN_RP_ID, WebAuthnSignature,
+};
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
-/// Alias to 512-bit hash when used in the context of a transaction signature on the chain.
-pub type Signature = MultiSignature;
-
-/// Alias to 512-bit hash when used in the context of a transaction signature on the chain. pub type
-/// Signature = MultiSignature; Some way of identifying an account on the chain. We intentionally
-/// make it equivalent to the public key of our transaction signing scheme.
+/// Some way of identifying an account on the chain. We intentionally make it equivalent to the
+/// public key of our transaction signing scheme.
 pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
 
 /// The type for looking up accounts. We don't expect more than 4 billion of them, but you
@@ -0,0 +1,75 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API used by the node to relay meta-transactions.
+//!
+//! The runtime checks the meta-transaction and builds the transaction relaying it, the node only
+//! signs the payload returned by [`MetaTxRelayApi::prepare_relay`] with its relayer key.
+
+use alloc::vec::Vec;
+use frame_support::sp_runtime::{
+    RuntimeDebug, generic::Era, transaction_validity::TransactionValidityError,
+};
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+
+/// A meta-transaction checked against the chain state, with the transaction relaying it ready to
+/// be signed.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct MetaTxRelay<AccountId> {
+    /// Account which signed the meta-transaction.
+    pub signer: AccountId,
+    /// Name of the pallet of the relayed call.
+    pub pallet: Vec<u8>,
+    /// Name of the relayed call.
+    pub call: Vec<u8>,
+    /// Encoded `MetaTx::dispatch` call wrapping the meta-transaction.
+    pub dispatch: Vec<u8>,
+    /// Encoded transaction extension of the relaying transaction.
+    pub extension: Vec<u8>,
+    /// Payload of the relaying transaction to sign, hashed when longer than 256 bytes.
+    pub payload: Vec<u8>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Checks meta-transactions and builds the transactions relaying them.
+    pub trait MetaTxRelayApi<AccountId, Nonce, Signature>
+    where
+        AccountId: Codec,
+        Nonce: Codec,
+        Signature: Codec,
+    {
+        /// Check the signature, nonce and mortality of the encoded `meta_tx` as in a block
+        /// built on top of `block_hash`, and prepare a `MetaTx::dispatch` transaction relaying
+        /// it with `nonce`, mortal from `era`.
+        fn prepare_relay(
+            meta_tx: Vec<u8>,
+            nonce: Nonce,
+            era: Era,
+            block_hash: Block::Hash,
+        ) -> Result<MetaTxRelay<AccountId>, TransactionValidityError>;
+
+        /// The transaction relaying `relay`, signed by `relayer` with `signature` over its
+        /// payload.
+        fn relay_extrinsic(
+            relay: MetaTxRelay<AccountId>,
+            relayer: AccountId,
+            signature: Signature,
+        ) -> Result<Block::Extrinsic, TransactionValidityError>;
+    }
+}
@@ -0,0 +1,493 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction signatures: the [`MultiSignature`] schemes and WebAuthn passkeys.
+//!
+//! Passkeys are P-256 keys held by the authenticator of a device. They do not sign the message
+//! itself but a WebAuthn assertion whose challenge is the Blake2-256 hash of the message.
+
+use frame_support::{
+    BoundedVec,
+    pallet_prelude::ConstU32,
+    sp_runtime::{
+        AccountId32, MultiSignature, MultiSigner, RuntimeDebug,
+        traits::{IdentifyAccount, Lazy, Verify},
+    },
+};
+use p256::ecdsa::{VerifyingKey, signature::Verifier};
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{
+    ecdsa, ed25519,
+    hashing::{blake2_256, sha2_256},
+    sr25519,
+};
+
+/// Prefix of the hashed P-256 public keys giving their account, keeps them apart from the
+/// secp256k1 keys of the same length.
+const P256_ACCOUNT_PREFIX: &[u8] = b"allfeat/p256";
+/// Maximum length of the authenticator data of an assertion.
+pub const MAX_AUTHENTICATOR_DATA_LEN: u32 = 512;
+/// Maximum length of the client data of an assertion.
+pub const MAX_CLIENT_DATA_JSON_LEN: u32 = 1024;
+/// Relying party ID the passkeys are scoped to, the domain of the Allfeat app.
+pub const WEBAUTHN_RP_ID: &[u8] = b"app.allfeat.com";
+/// Origin of the pages allowed to request the assertions, the Allfeat app.
+pub const WEBAUTHN_ORIGIN: &[u8] = b"https://app.allfeat.com";
+/// `"origin":"<origin>"` entry of the client data of an assertion made by the Allfeat app.
+const ORIGIN_FIELD: [u8; WEBAUTHN_ORIGIN.len() + 11] = origin_field();
+/// Offset of the flags in the authenticator data, after the relying party ID hash.
+const AUTHENTICATOR_FLAGS_OFFSET: usize = 32;
+/// User presence flag of the authenticator data.
+const USER_PRESENT: u8 = 0x01;
+/// Alphabet of the base64url encoding of the challenge.
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Compressed SEC1 encoding of a P-256 public key.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    RuntimeDebug,
+)]
+pub struct P256Public(pub [u8; 33]);
+
+impl IdentifyAccount for P256Public {
+    type AccountId = AccountId32;
+
+    /// The Blake2-256 hash of the prefixed public key.
+    fn into_account(self) -> AccountId32 {
+        let mut data = [0u8; P256_ACCOUNT_PREFIX.len() + 33];
+        data[..P256_ACCOUNT_PREFIX.len()].copy_from_slice(P256_ACCOUNT_PREFIX);
+        data[P256_ACCOUNT_PREFIX.len()..].copy_from_slice(&self.0);
+        blake2_256(&data).into()
+    }
+}
+
+/// WebAuthn assertion of a passkey over a message.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    RuntimeDebug,
+)]
+pub struct WebAuthnSignature {
+    /// Public key of the passkey.
+    pub public: P256Public,
+    /// Authenticator data of the assertion.
+    pub authenticator_data: BoundedVec<u8, ConstU32<MAX_AUTHENTICATOR_DATA_LEN>>,
+    /// Client data of the assertion, its challenge is the Blake2-256 hash of the message.
+    pub client_data_json: BoundedVec<u8, ConstU32<MAX_CLIENT_DATA_JSON_LEN>>,
+    /// ECDSA signature (`r || s`) of the authenticator data followed by the SHA-256 hash of the
+    /// client data.
+    pub signature: [u8; 64],
+}
+
+impl WebAuthnSignature {
+    /// Whether this is an assertion of the user over `msg`, made for [`WEBAUTHN_RP_ID`] by a
+    /// top-level page of [`WEBAUTHN_ORIGIN`].
+    pub fn verify(&self, msg: &[u8]) -> bool {
+        let rp_id_hash = self.authenticator_data.get(..AUTHENTICATOR_FLAGS_OFFSET);
+        let user_present = self
+            .authenticator_data
+            .get(AUTHENTICATOR_FLAGS_OFFSET)
+            .is_some_and(|flags| flags & USER_PRESENT != 0);
+        if rp_id_hash != Some(&sha2_256(WEBAUTHN_RP_ID)[..])
+            || !user_present
+            || !contains(&self.client_data_json, b"\"type\":\"webauthn.get\"")
+            || !contains(&self.client_data_json, &challenge_field(msg))
+            || !contains(&self.client_data_json, &ORIGIN_FIELD)
+            || contains(&self.client_data_json, b"\"crossOrigin\":true")
+        {
+            return false;
+        }
+
+        let (Ok(key), Ok(signature)) = (
+            VerifyingKey::from_sec1_bytes(&self.public.0),
+            p256::ecdsa::Signature::from_slice(&self.signature),
+        ) else {
+            return false;
+        };
+        let signed = [
+            &self.authenticator_data[..],
+            &sha2_256(&self.client_data_json),
+        ]
+        .concat();
+        key.verify(&signed, &signature).is_ok()
+    }
+
+    /// An assertion over `msg` of the passkey of secret key `secret`, as an authenticator makes
+    /// it for the Allfeat app. Used by tests and benchmarks.
+    ///
+    /// Panics if `secret` is not a valid P-256 secret key.
+    #[cfg(any(feature = "std", feature = "runtime-benchmarks"))]
+    pub fn sign(secret: &[u8; 32], msg: &[u8]) -> Self {
+        use p256::ecdsa::{SigningKey, signature::Signer as _};
+
+        let key = SigningKey::from_slice(secret).expect("valid P-256 secret key");
+        let mut public = [0u8; 33];
+        public.copy_from_slice(key.verifying_key().to_encoded_point(true).as_bytes());
+        // Relying party ID hash, user present and verified flags, zero signature counter.
+        let authenticator_data = [&sha2_256(WEBAUTHN_RP_ID)[..], &[0x05u8, 0, 0, 0, 0]].concat();
+        let client_data_json = [
+            &b"{\"type\":\"webauthn.get\","[..],
+            &challenge_field(msg),
+            b",",
+            &ORIGIN_FIELD,
+            b",\"crossOrigin\":false}",
+        ]
+        .concat();
+
+        let signed = [&authenticator_data[..], &sha2_256(&client_data_json)].concat();
+        let signature: p256::ecdsa::Signature = key.sign(&signed);
+        let signature = signature.normalize_s().unwrap_or(signature);
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(&signature.to_bytes());
+
+        Self {
+            public: P256Public(public),
+            authenticator_data: authenticator_data
+                .try_into()
+                .expect("37 bytes are below the bound; qed"),
+            client_data_json: client_data_json
+                .try_into()
+                .expect("the client data is below the bound; qed"),
+            signature: bytes,
+        }
+    }
+}
+
+/// `"challenge":"<challenge>"` entry of the client data of an assertion over `msg`.
+///
+/// JSON escapes the quotes of string values, the entry cannot be matched inside another value.
+fn challenge_field(msg: &[u8]) -> [u8; 57] {
+    let challenge = blake2_256(msg);
+    let mut field = [0u8; 57];
+    field[..13].copy_from_slice(b"\"challenge\":\"");
+    let mut i = 13;
+    for chunk in challenge.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (j, byte)| n | ((*byte as u32) << (16 - 8 * j)));
+        for k in 0..=chunk.len() {
+            field[i] = BASE64URL[((n >> (18 - 6 * k)) & 0x3f) as usize];
+            i += 1;
+        }
+    }
+    field[i] = b'"';
+    field
+}
+
+/// Builds [`ORIGIN_FIELD`].
+///
+/// The closing quote keeps origins merely starting with [`WEBAUTHN_ORIGIN`] out.
+const fn origin_field() -> [u8; WEBAUTHN_ORIGIN.len() + 11] {
+    let mut field = [0u8; WEBAUTHN_ORIGIN.len() + 11];
+    let prefix = b"\"origin\":\"";
+    let mut i = 0;
+    while i < prefix.len() {
+        field[i] = prefix[i];
+        i += 1;
+    }
+    while i < prefix.len() + WEBAUTHN_ORIGIN.len() {
+        field[i] = WEBAUTHN_ORIGIN[i - prefix.len()];
+        i += 1;
+    }
+    field[i] = b'"';
+    field
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+/// Signature of a transaction.
+///
+/// Encodes as [`MultiSignature`] for the schemes they share.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo, RuntimeDebug)]
+pub enum Signature {
+    /// An Ed25519 signature.
+    Ed25519(ed25519::Signature),
+    /// An Sr25519 signature.
+    Sr25519(sr25519::Signature),
+    /// An ECDSA/SECP256k1 signature.
+    Ecdsa(ecdsa::Signature),
+    /// A WebAuthn assertion of a P-256 passkey.
+    WebAuthn(WebAuthnSignature),
+}
+
+impl Verify for Signature {
+    type Signer = Signer;
+
+    fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId32) -> bool {
+        let signature = match self {
+            Signature::Ed25519(signature) => MultiSignature::Ed25519(*signature),
+            Signature::Sr25519(signature) => MultiSignature::Sr25519(*signature),
+            Signature::Ecdsa(signature) => MultiSignature::Ecdsa(*signature),
+            Signature::WebAuthn(signature) => {
+                return signature.public.into_account() == *signer && signature.verify(msg.get());
+            }
+        };
+        signature.verify(msg, signer)
+    }
+}
+
+impl From<MultiSignature> for Signature {
+    fn from(signature: MultiSignature) -> Self {
+        match signature {
+            MultiSignature::Ed25519(signature) => Signature::Ed25519(signature),
+            MultiSignature::Sr25519(signature) => Signature::Sr25519(signature),
+            MultiSignature::Ecdsa(signature) => Signature::Ecdsa(signature),
+        }
+    }
+}
+
+impl From<ed25519::Signature> for Signature {
+    fn from(signature: ed25519::Signature) -> Self {
+        Signature::Ed25519(signature)
+    }
+}
+
+impl From<sr25519::Signature> for Signature {
+    fn from(signature: sr25519::Signature) -> Self {
+        Signature::Sr25519(signature)
+    }
+}
+
+impl From<ecdsa::Signature> for Signature {
+    fn from(signature: ecdsa::Signature) -> Self {
+        Signature::Ecdsa(signature)
+    }
+}
+
+impl From<WebAuthnSignature> for Signature {
+    fn from(signature: WebAuthnSignature) -> Self {
+        Signature::WebAuthn(signature)
+    }
+}
+
+/// Public key of a [`Signature`].
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    RuntimeDebug,
+)]
+pub enum Signer {
+    /// An Ed25519 public key.
+    Ed25519(ed25519::Public),
+    /// An Sr25519 public key.
+    Sr25519(sr25519::Public),
+    /// An SECP256k1 public key.
+    Ecdsa(ecdsa::Public),
+    /// A P-256 passkey public key.
+    P256(P256Public),
+}
+
+impl IdentifyAccount for Signer {
+    type AccountId = AccountId32;
+
+    fn into_account(self) -> AccountId32 {
+        match self {
+            Signer::Ed25519(public) => MultiSigner::Ed25519(public).into_account(),
+            Signer::Sr25519(public) => MultiSigner::Sr25519(public).into_account(),
+            Signer::Ecdsa(public) => MultiSigner::Ecdsa(public).into_account(),
+            Signer::P256(public) => public.into_account(),
+        }
+    }
+}
+
+impl From<ed25519::Public> for Signer {
+    fn from(public: ed25519::Public) -> Self {
+        Signer::Ed25519(public)
+    }
+}
+
+impl From<sr25519::Public> for Signer {
+    fn from(public: sr25519::Public) -> Self {
+        Signer::Sr25519(public)
+    }
+}
+
+impl From<ecdsa::Public> for Signer {
+    fn from(public: ecdsa::Public) -> Self {
+        Signer::Ecdsa(public)
+    }
+}
+
+impl From<P256Public> for Signer {
+    fn from(public: P256Public) -> Self {
+        Signer::P256(public)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::Pair;
+
+    const PUBLIC: &str = "03aac85470dcfbeb9b77672aa11516efd7cfb7d1f563db7480e59ff8c45ad21fa9";
+    const ACCOUNT: &str = "2baf83706cb34a58c34bf7b6160ba158fb625abac182a6cebad54000fdcbe8e7";
+    const MESSAGE: &[u8] = b"allfeat passkey test payload";
+    const AUTHENTICATOR_DATA: &str =
+        "1066410ec70347a8645aabd6d431dcaab9a583faa32cb888c228e708330794cc0500000000";
+    const CLIENT_DATA_JSON: &str = r#"{"type":"webauthn.get","challenge":"wBrp7rTgJrgcv0Ur7E1l39nh--HM0v3kwrEcA-ALoxQ","origin":"https://app.allfeat.com","crossOrigin":false}"#;
+    const SIGNATURE: &str = "783c870e053b6695a5537a1bd5a8c839fd171bf802403ee2511f8be9b01cd615466b708d9d496eaa646f830044474e4695ed03676c9d2dcf131782502fcc6913";
+
+    fn account() -> AccountId32 {
+        array_bytes::hex2array_unchecked::<_, 32>(ACCOUNT).into()
+    }
+
+    fn assertion(authenticator_data: &str, client_data_json: &str, signature: &str) -> Signature {
+        Signature::WebAuthn(WebAuthnSignature {
+            public: P256Public(array_bytes::hex2array_unchecked(PUBLIC)),
+            authenticator_data: array_bytes::hex2bytes_unchecked(authenticator_data)
+                .try_into()
+                .unwrap(),
+            client_data_json: client_data_json.as_bytes().to_vec().try_into().unwrap(),
+            signature: array_bytes::hex2array_unchecked(signature),
+        })
+    }
+
+    #[test]
+    fn p256_account_is_derived_from_the_public_key() {
+        let public = P256Public(array_bytes::hex2array_unchecked(PUBLIC));
+        assert_eq!(public.into_account(), account());
+        assert_eq!(Signer::P256(public).into_account(), account());
+    }
+
+    #[test]
+    fn webauthn_assertion_verifies() {
+        let signature = assertion(AUTHENTICATOR_DATA, CLIENT_DATA_JSON, SIGNATURE);
+        assert!(signature.verify(MESSAGE, &account()));
+
+        assert!(!signature.verify(&b"another payload"[..], &account()));
+        assert!(!signature.verify(MESSAGE, &AccountId32::new([0; 32])));
+    }
+
+    #[test]
+    fn tampered_webauthn_assertion_is_rejected() {
+        let mut signature = array_bytes::hex2array_unchecked::<_, 64>(SIGNATURE);
+        signature[63] ^= 1;
+        let signature = assertion(
+            AUTHENTICATOR_DATA,
+            CLIENT_DATA_JSON,
+            &array_bytes::bytes2hex("", signature),
+        );
+        assert!(!signature.verify(MESSAGE, &account()));
+
+        let client_data_json = CLIENT_DATA_JSON.replace("app.allfeat.com", "app.allfeat.org");
+        let signature = assertion(AUTHENTICATOR_DATA, &client_data_json, SIGNATURE);
+        assert!(!signature.verify(MESSAGE, &account()));
+    }
+
+    #[test]
+    fn webauthn_assertion_for_another_relying_party_is_rejected() {
+        let signature = assertion(
+            "9c180de0cd699ee78897c47cfdb3e7ee1d75906e31b7746a4747dea5369098370500000000",
+            CLIENT_DATA_JSON,
+            "8a339e57af7bae8d8b37f796a5966ac894ea1d00c36277d390a6217a935f9d5a2be9bc554e91522a72f3d47648e4fc51301eeeb7ca62e66147625a3604bf821c",
+        );
+        assert!(!signature.verify(MESSAGE, &account()));
+    }
+
+    #[test]
+    fn cross_origin_webauthn_assertion_is_rejected() {
+        let signature = assertion(
+            AUTHENTICATOR_DATA,
+            &CLIENT_DATA_JSON.replace("\"crossOrigin\":false", "\"crossOrigin\":true"),
+            "ac8caf9bf54421ff779d8b81141d4f0f972d472261f7eaa77b4b68ab5ee900e61168e55172dcbf2b90409a89525b6ab35d9ad153c6966f868289b76ceb5195a7",
+        );
+        assert!(!signature.verify(MESSAGE, &account()));
+    }
+
+    #[test]
+    fn webauthn_assertion_from_another_origin_is_rejected() {
+        let signature = assertion(
+            AUTHENTICATOR_DATA,
+            &CLIENT_DATA_JSON.replace("https://app.allfeat.com", "https://evil.example"),
+            "bb1f5cf6c957529d48193df6ee926bcf77949a70993be55a63fbe210e7a1bf80339a5e8b6e6cd779e35c34ea401c17941e9bd2a178fa77733ae873cf06acb08e",
+        );
+        assert!(!signature.verify(MESSAGE, &account()));
+    }
+
+    #[test]
+    fn signed_webauthn_assertion_verifies() {
+        let secret = sha2_256(b"allfeat passkey test key");
+        let signature = WebAuthnSignature::sign(&secret, MESSAGE);
+
+        assert_eq!(signature.public.0, array_bytes::hex2array_unchecked(PUBLIC));
+        assert!(Signature::from(signature).verify(MESSAGE, &account()));
+    }
+
+    #[test]
+    fn webauthn_registration_is_rejected() {
+        let signature = assertion(
+            AUTHENTICATOR_DATA,
+            r#"{"type":"webauthn.create","challenge":"wBrp7rTgJrgcv0Ur7E1l39nh--HM0v3kwrEcA-ALoxQ","origin":"https://app.allfeat.com","crossOrigin":false}"#,
+            "5e903b6ec3c3b4167c5b4206f1c091f7aa332b97398bd9f42802e7585f9458d00eb4b21c9dfe5dade60029ba4b55a5c6d0b7fbcb0a8a669b34298e693dc5ed55",
+        );
+        assert!(!signature.verify(MESSAGE, &account()));
+    }
+
+    #[test]
+    fn webauthn_assertion_without_user_presence_is_rejected() {
+        let signature = assertion(
+            "1066410ec70347a8645aabd6d431dcaab9a583faa32cb888c228e708330794cc0000000000",
+            CLIENT_DATA_JSON,
+            "afc61b2af4e714219cc1902b63803c936a935d73a0d58877dd01b7cc7f946e0e191c7ef595bdebbaeb545b22d0042ec4b456f8d201c5a9d07f3287d354023741",
+        );
+        assert!(!signature.verify(MESSAGE, &account()));
+    }
+
+    #[test]
+    fn multi_signature_schemes_are_unchanged() {
+        let pair = sr25519::Pair::from_seed(&[7; 32]);
+        let signature = pair.sign(MESSAGE);
+        let account = Signer::from(pair.public()).into_account();
+
+        assert_eq!(account, MultiSigner::from(pair.public()).into_account());
+        assert!(Signature::from(signature).verify(MESSAGE, &account));
+        assert_eq!(
+            Signature::from(signature).encode(),
+            MultiSignature::from(signature).encode()
+        );
+    }
+}
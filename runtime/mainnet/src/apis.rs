@@ -16,7 +16,9 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::pallet_prelude::{TransactionSource, TransactionValidity};
+use frame_support::pallet_prelude::{
+    TransactionSource, TransactionValidity, TransactionValidityError,
+};
 use sp_api::impl_runtime_apis;
 use sp_inherents::InherentData;
 use sp_runtime::traits::Block as BlockT;
@@ -188,6 +190,80 @@ impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
         }
     }
 
+    impl pallet_validators::runtime_api::ValidatorsApi<Block, AccountId> for Runtime {
+        fn active_bootnodes() -> Vec<(AccountId, Vec<Vec<u8>>)> {
+            Validators::active_bootnodes()
+        }
+    }
+
+    impl pallet_token_allocation::runtime_api::TokenAllocationApi<Block, AccountId, Balance, BlockNumber>
+        for Runtime
+    {
+        fn allocations_of(
+            who: AccountId,
+        ) -> Vec<(
+            pallet_token_allocation::AllocationId,
+            pallet_token_allocation::Allocation<AccountId, Balance, BlockNumber>,
+        )> {
+            TokenAllocation::allocations_of(&who)
+        }
+
+        fn claimable_at(
+            id: pallet_token_allocation::AllocationId,
+            at: BlockNumber,
+        ) -> Option<Balance> {
+            TokenAllocation::claimable_at(id, at)
+        }
+
+        fn unlock_schedule(from: BlockNumber, months: u32) -> Vec<(BlockNumber, Balance)> {
+            TokenAllocation::unlock_schedule(from, MONTHS, months)
+        }
+
+        fn envelope_totals() -> Vec<(
+            pallet_token_allocation::EnvelopeId,
+            pallet_token_allocation::EnvelopeTotals<Balance>,
+        )> {
+            TokenAllocation::envelope_totals()
+        }
+
+        fn circulating_supply() -> Balance {
+            TokenAllocation::circulating_supply()
+        }
+
+        fn failed_releases() -> Vec<pallet_token_allocation::FailedRelease<Balance>> {
+            TokenAllocation::failed_releases()
+        }
+    }
+
+    impl pallet_sponsorship::runtime_api::SponsorshipApi<Block, AccountId, Balance> for Runtime {
+        fn remaining_budget(sponsor: AccountId) -> Option<Balance> {
+            Sponsorship::remaining_budget(&sponsor)
+        }
+
+        fn is_beneficiary(sponsor: AccountId, beneficiary: AccountId) -> bool {
+            Sponsorship::is_beneficiary(&sponsor, &beneficiary)
+        }
+    }
+
+    impl allfeat_primitives::meta_tx::MetaTxRelayApi<Block, AccountId, Nonce, Signature> for Runtime {
+        fn prepare_relay(
+            meta_tx: Vec<u8>,
+            nonce: Nonce,
+            era: sp_runtime::generic::Era,
+            block_hash: <Block as BlockT>::Hash,
+        ) -> Result<allfeat_primitives::meta_tx::MetaTxRelay<AccountId>, TransactionValidityError> {
+            pallets::prepare_meta_tx_relay(meta_tx, nonce, era, block_hash)
+        }
+
+        fn relay_extrinsic(
+            relay: allfeat_primitives::meta_tx::MetaTxRelay<AccountId>,
+            relayer: AccountId,
+            signature: Signature,
+        ) -> Result<<Block as BlockT>::Extrinsic, TransactionValidityError> {
+            pallets::meta_tx_relay_extrinsic(relay, relayer, signature)
+        }
+    }
+
     impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
         fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
             use frame_support::genesis_builder_helper::build_state;
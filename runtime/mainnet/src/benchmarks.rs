@@ -25,15 +25,26 @@ frame_benchmarking::define_benchmarks!(
     [pallet_preimage, Preimage]
     [pallet_proxy, Proxy]
     [pallet_scheduler, Scheduler]
-    [pallet_sudo, Sudo]
     [frame_system, SystemBench::<Runtime>]
     [pallet_timestamp, Timestamp]
     [pallet_utility, Utility]
     [pallet_validators, Validators]
+    [pallet_validator_rewards, ValidatorRewards]
     [pallet_token_allocation, TokenAllocation]
     [pallet_transaction_payment, TransactionPayment]
     [pallet_treasury, Treasury]
+    [pallet_bounties, Bounties]
+    [pallet_child_bounties, ChildBounties]
+    [pallet_asset_rate, AssetRate]
+    [pallet_safe_mode, SafeMode]
+    [pallet_tx_pause, TxPause]
+    [pallet_collective, Council]
+    [pallet_membership, CouncilMembership]
+    [pallet_referenda, Referenda]
+    [pallet_conviction_voting, ConvictionVoting]
+    [pallet_whitelist, Whitelist]
     [pallet_meta_tx, MetaTx]
+    [pallet_sponsorship, Sponsorship]
     [pallet_verify_signature, VerifySignature]
     [pallet_ats, Ats]
 );
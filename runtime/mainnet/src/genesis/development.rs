@@ -35,6 +35,6 @@ pub fn development_config_genesis() -> serde_json::Value {
             Sr25519Keyring::Eve.to_account_id(),
             Sr25519Keyring::Ferdie.to_account_id(),
         ],
-        Sr25519Keyring::Alice.to_account_id(),
+        vec![Sr25519Keyring::Alice.to_account_id()],
     )
 }
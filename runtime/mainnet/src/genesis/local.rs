@@ -36,6 +36,6 @@ pub fn local_config_genesis() -> serde_json::Value {
             ),
         ],
         vec![Sr25519Keyring::Bob.to_account_id()],
-        Sr25519Keyring::Alice.to_account_id(),
+        vec![Sr25519Keyring::Alice.to_account_id()],
     )
 }
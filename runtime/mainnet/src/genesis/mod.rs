@@ -29,10 +29,11 @@ use shared_runtime::currency::AFT;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_genesis_builder::PresetId;
+use sp_runtime::BoundedVec;
 use staging::staging_config_genesis;
 use token::{VALIDATOR_ENDOWMENT, tokenomics};
 
-use crate::{RuntimeGenesisConfig, SessionKeys};
+use crate::{CouncilMembershipConfig, RuntimeGenesisConfig, SessionKeys};
 
 mod development;
 mod local;
@@ -63,9 +64,9 @@ pub fn genesis(
         AuraId,
     )>,
     dev_accounts: Vec<AccountId>,
-    root_key: AccountId,
+    council: Vec<AccountId>,
 ) -> serde_json::Value {
-    let mut token_genesis = tokenomics(root_key.clone(), initial_authorities.len() as u128);
+    let mut token_genesis = tokenomics(&council, initial_authorities.len() as u128);
 
     // Give each validator an initial endowment (taken from R&D envelope)
     for (account, _, _) in &initial_authorities {
@@ -105,8 +106,8 @@ pub fn genesis(
                 .collect::<Vec<_>>(),
             non_authority_keys: Default::default(),
         },
-        sudo: pallet_sudo::GenesisConfig {
-            key: Some(root_key)
+        council_membership: CouncilMembershipConfig {
+            members: BoundedVec::truncate_from(council),
         },
     })
 }
@@ -58,6 +58,8 @@ pub fn staging_config_genesis() -> serde_json::Value {
             ),
         ],
         vec![],
-        AccountId::from_ss58check("qSysBTZC3yQRKNroife4djUTQwnfVxHQ19PpxgHKcRFJszHRA").unwrap(),
+        vec![
+            AccountId::from_ss58check("qSysBTZC3yQRKNroife4djUTQwnfVxHQ19PpxgHKcRFJszHRA").unwrap(),
+        ],
     )
 }
@@ -6,7 +6,7 @@ use sp_runtime::Percent;
 
 use crate::{MONTHS, Runtime, Treasury};
 
-pub const SUDO_AMOUNT: Balance = 1_000 * AFT;
+pub const COUNCIL_ENDOWMENT: Balance = 1_000 * AFT;
 pub const VALIDATOR_ENDOWMENT: Balance = 10 * AFT;
 
 pub struct TokenGenesis {
@@ -14,10 +14,13 @@ pub struct TokenGenesis {
     pub allocations: pallet_token_allocation::GenesisConfig<Runtime>,
 }
 
-pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
+pub fn tokenomics(council: &[AccountId], num_validators: u128) -> TokenGenesis {
     TokenGenesis {
         balances: pallet_balances::GenesisConfig {
-            balances: vec![(sudo_key, SUDO_AMOUNT)],
+            balances: council
+                .iter()
+                .map(|member| (member.clone(), COUNCIL_ENDOWMENT))
+                .collect(),
             dev_accounts: None,
         },
         allocations: pallet_token_allocation::GenesisConfig {
@@ -34,6 +37,9 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                         cliff: 12 * MONTHS,
                         vesting_duration: 36 * MONTHS,
                         unique_beneficiary: None,
+                        revocable: true,
+                        transfer_approval: true,
+                        curve: None,
                     },
                 ),
                 // Envelope name: KoL
@@ -48,6 +54,9 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                         cliff: 9 * MONTHS,
                         vesting_duration: 9 * MONTHS,
                         unique_beneficiary: None,
+                        revocable: true,
+                        transfer_approval: true,
+                        curve: None,
                     },
                 ),
                 // Envelope name: Private1
@@ -62,6 +71,9 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                         cliff: 8 * MONTHS,
                         vesting_duration: 38 * MONTHS,
                         unique_beneficiary: None,
+                        revocable: false,
+                        transfer_approval: true,
+                        curve: None,
                     },
                 ),
                 // Envelope name: Private2
@@ -76,6 +88,9 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                         cliff: 3 * MONTHS,
                         vesting_duration: 36 * MONTHS,
                         unique_beneficiary: None,
+                        revocable: false,
+                        transfer_approval: true,
+                        curve: None,
                     },
                 ),
                 // Envelope name: Public1
@@ -90,6 +105,9 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                         cliff: 0u32,
                         vesting_duration: 6 * MONTHS,
                         unique_beneficiary: None,
+                        revocable: false,
+                        transfer_approval: false,
+                        curve: None,
                     },
                 ),
                 // Envelope name: Public3
@@ -104,6 +122,9 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                         cliff: 0u32,
                         vesting_duration: 6 * MONTHS,
                         unique_beneficiary: None,
+                        revocable: false,
+                        transfer_approval: false,
+                        curve: None,
                     },
                 ),
                 // Envelope name: Public2
@@ -118,6 +139,9 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                         cliff: 18 * MONTHS,
                         vesting_duration: 12 * MONTHS,
                         unique_beneficiary: None,
+                        revocable: false,
+                        transfer_approval: false,
+                        curve: None,
                     },
                 ),
                 // Envelope name: Public4
@@ -132,6 +156,9 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                         cliff: 12 * MONTHS,
                         vesting_duration: 12 * MONTHS,
                         unique_beneficiary: None,
+                        revocable: false,
+                        transfer_approval: false,
+                        curve: None,
                     },
                 ),
                 // Envelope name: Airdrop
@@ -146,6 +173,9 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                         cliff: 0u32,
                         vesting_duration: 0u32,
                         unique_beneficiary: None,
+                        revocable: false,
+                        transfer_approval: false,
+                        curve: None,
                     },
                 ),
                 // Envelope name: Community Rewards
@@ -161,6 +191,9 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                         cliff: 5 * MONTHS,
                         vesting_duration: 46 * MONTHS,
                         unique_beneficiary: Some(Treasury::account_id()),
+                        revocable: false,
+                        transfer_approval: false,
+                        curve: None,
                     },
                 ),
                 // Envelope name: Listing
@@ -176,6 +209,9 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                         cliff: 4 * MONTHS,
                         vesting_duration: 12 * MONTHS,
                         unique_beneficiary: Some(Treasury::account_id()),
+                        revocable: false,
+                        transfer_approval: false,
+                        curve: None,
                     },
                 ),
                 // Envelope name: Research & Development
@@ -184,17 +220,20 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                 // Vesting on 26 months
                 // Beneficiary: Treasury
                 //
-                // Note: Available sudo key amount balance is taken from this envelope.
+                // Note: Council members and validators endowments are taken from this envelope.
                 (
                     EnvelopeId::ResearchDevelopment,
                     EnvelopeConfig {
                         total_cap: (125_000_000 * AFT)
-                            .saturating_sub(SUDO_AMOUNT)
+                            .saturating_sub(council.len() as u128 * COUNCIL_ENDOWMENT)
                             .saturating_sub(num_validators * VALIDATOR_ENDOWMENT),
                         upfront_rate: Percent::from_percent(20),
                         cliff: 0u32,
                         vesting_duration: 26 * MONTHS,
                         unique_beneficiary: Some(Treasury::account_id()),
+                        revocable: false,
+                        transfer_approval: false,
+                        curve: None,
                     },
                 ),
                 // Envelope name: Reserve
@@ -210,6 +249,9 @@ pub fn tokenomics(sudo_key: AccountId, num_validators: u128) -> TokenGenesis {
                         cliff: 0u32,
                         vesting_duration: 0u32,
                         unique_beneficiary: Some(Treasury::account_id()),
+                        revocable: false,
+                        transfer_approval: false,
+                        curve: None,
                     },
                 ),
             ],
@@ -71,10 +71,12 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: alloc::borrow::Cow::Borrowed("allfeat"),
     impl_name: alloc::borrow::Cow::Borrowed("allfeat-allfeat"),
     authoring_version: 1,
-    spec_version: 201,
+    // Bumped by every change to runtime storage, calls or logic.
+    spec_version: 223,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    // Bumped whenever the encoding of an existing call or extension changes.
+    transaction_version: 5,
     system_version: 1,
 };
 
@@ -101,6 +103,7 @@ pub type TxBareExtension = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
+    pallet_sponsorship::CheckSponsorship<Runtime>,
     pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
     frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 );
@@ -194,9 +197,6 @@ mod runtime {
     #[runtime::pallet_index(9)]
     pub type Grandpa = pallet_grandpa;
 
-    #[runtime::pallet_index(10)]
-    pub type Sudo = pallet_sudo;
-
     #[runtime::pallet_index(13)]
     pub type Historical = pallet_session::historical;
 
@@ -224,6 +224,54 @@ mod runtime {
     #[runtime::pallet_index(21)]
     pub type VerifySignature = pallet_verify_signature;
 
+    #[runtime::pallet_index(22)]
+    pub type ValidatorRewards = pallet_validator_rewards;
+
+    #[runtime::pallet_index(23)]
+    pub type Council = pallet_collective<Instance1>;
+
+    #[runtime::pallet_index(24)]
+    pub type CouncilMembership = pallet_membership<Instance1>;
+
+    #[runtime::pallet_index(25)]
+    pub type Referenda = pallet_referenda;
+
+    #[runtime::pallet_index(26)]
+    pub type ConvictionVoting = pallet_conviction_voting;
+
+    #[runtime::pallet_index(27)]
+    pub type Origins = pallet_custom_origins;
+
+    #[runtime::pallet_index(28)]
+    pub type Whitelist = pallet_whitelist;
+
+    #[runtime::pallet_index(29)]
+    pub type Assets = pallet_assets;
+
+    #[runtime::pallet_index(30)]
+    pub type AssetRate = pallet_asset_rate;
+
+    #[runtime::pallet_index(31)]
+    pub type Bounties = pallet_bounties;
+
+    #[runtime::pallet_index(32)]
+    pub type ChildBounties = pallet_child_bounties;
+
+    #[runtime::pallet_index(33)]
+    pub type SafeMode = pallet_safe_mode;
+
+    #[runtime::pallet_index(34)]
+    pub type TxPause = pallet_tx_pause;
+
+    #[runtime::pallet_index(35)]
+    pub type Parameters = pallet_parameters;
+
+    #[runtime::pallet_index(36)]
+    pub type Sponsorship = pallet_sponsorship;
+
+    #[runtime::pallet_index(37)]
+    pub type FeeDistribution = pallet_fee_distribution;
+
     #[runtime::pallet_index(105)]
     pub type Ats = pallet_ats;
 }
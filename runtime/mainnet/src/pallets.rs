@@ -16,6 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+mod assets;
 mod ats;
 mod multisig;
 mod proxy;
@@ -24,22 +25,34 @@ mod scheduler;
 mod aura;
 mod authorship;
 mod balances;
+mod council;
+mod governance;
 mod grandpa;
 mod meta_tx;
+mod parameters;
 mod preimage;
+mod safe_mode;
 mod session;
-mod sudo;
+mod sponsorship;
 mod system;
 mod timestamp;
 mod token_allocation;
 mod transaction_payment;
 mod treasury;
 mod utility;
+mod validator_rewards;
 mod validators;
 
 // External required imports
+pub use assets::*;
 pub use aura::*;
 pub use balances::*;
+pub use council::*;
+pub use governance::*;
+pub use meta_tx::{meta_tx_relay_extrinsic, prepare_meta_tx_relay};
+pub use parameters::*;
+pub use proxy::ProxyType;
 pub use session::*;
+pub use sponsorship::SponsoredCalls;
 pub use system::*;
 pub use transaction_payment::*;
@@ -0,0 +1,76 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU32},
+};
+use frame_system::EnsureSigned;
+use parity_scale_codec::Compact;
+use shared_runtime::currency::{AFT, deposit};
+
+/// Identifier of a local asset, e.g. a registered stable coin.
+pub type AssetId = u32;
+
+parameter_types! {
+    pub const AssetDeposit: Balance = 100 * AFT;
+    pub const AssetAccountDeposit: Balance = deposit(1, 16);
+    pub const ApprovalDeposit: Balance = AFT;
+    pub const AssetsStringLimit: u32 = 50;
+    pub const MetadataDepositBase: Balance = deposit(1, 68);
+    pub const MetadataDepositPerByte: Balance = deposit(0, 1);
+}
+
+impl pallet_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = AssetId;
+    type AssetIdParameter = Compact<AssetId>;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type ForceOrigin = RootOrCouncilTwoThirds;
+    type AssetDeposit = AssetDeposit;
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type MetadataDepositBase = MetadataDepositBase;
+    type MetadataDepositPerByte = MetadataDepositPerByte;
+    type ApprovalDeposit = ApprovalDeposit;
+    type StringLimit = AssetsStringLimit;
+    type Holder = ();
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type ReserveData = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+/// Governance sets the AFT value of the assets the treasury is allowed to pay out in.
+impl pallet_asset_rate::Config for Runtime {
+    type WeightInfo = weights::asset_rate::AllfeatWeight<Runtime>;
+    type RuntimeEvent = RuntimeEvent;
+    type CreateOrigin = RootOrCouncilTwoThirds;
+    type RemoveOrigin = RootOrCouncilTwoThirds;
+    type UpdateOrigin = RootOrCouncilTwoThirds;
+    type Currency = Balances;
+    type AssetKind = AssetId;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
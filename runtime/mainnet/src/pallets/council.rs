@@ -0,0 +1,127 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    parameter_types,
+    traits::{EitherOf, Get, OnRuntimeUpgrade},
+    weights::Weight,
+};
+use frame_system::EnsureRoot;
+use pallet_collective::EnsureProportionAtLeast;
+use sp_runtime::{Perbill, traits::StaticLookup};
+
+/// The collective instance backing the governance council.
+pub type CouncilCollective = pallet_collective::Instance1;
+/// The membership instance managing the seats of [`CouncilCollective`].
+pub type CouncilMembershipInstance = pallet_membership::Instance1;
+
+/// At least half of the council approved the motion.
+pub type CouncilHalf = EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
+/// At least three fifths of the council approved the motion.
+pub type CouncilThreeFifths = EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 5>;
+/// At least two thirds of the council approved the motion.
+pub type CouncilTwoThirds = EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+
+/// Root or two thirds of the council.
+pub type RootOrCouncilTwoThirds = EitherOf<EnsureRoot<AccountId>, CouncilTwoThirds>;
+
+parameter_types! {
+    pub const CouncilMotionDuration: BlockNumber = 5 * DAYS;
+    pub const CouncilMaxProposals: u32 = 100;
+    pub const CouncilMaxMembers: u32 = 13;
+    pub MaxCouncilProposalWeight: Weight = Perbill::from_percent(50) *
+        RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type MotionDuration = CouncilMotionDuration;
+    type MaxProposals = CouncilMaxProposals;
+    type MaxMembers = CouncilMaxMembers;
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type WeightInfo = weights::collective::AllfeatWeight<Runtime>;
+    /// Seats are managed through `CouncilMembership`, this is only a fallback.
+    type SetMembersOrigin = EnsureRoot<AccountId>;
+    type MaxProposalWeight = MaxCouncilProposalWeight;
+    type DisapproveOrigin = RootOrCouncilTwoThirds;
+    type KillOrigin = RootOrCouncilTwoThirds;
+    type Consideration = ();
+}
+
+impl pallet_membership::Config<CouncilMembershipInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AddOrigin = RootOrCouncilTwoThirds;
+    type RemoveOrigin = RootOrCouncilTwoThirds;
+    type SwapOrigin = RootOrCouncilTwoThirds;
+    type ResetOrigin = EnsureRoot<AccountId>;
+    type PrimeOrigin = RootOrCouncilTwoThirds;
+    type MembershipInitialized = Council;
+    type MembershipChanged = Council;
+    type MaxMembers = CouncilMaxMembers;
+    type WeightInfo = weights::membership::AllfeatWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const SudoPalletName: &'static str = "Sudo";
+}
+
+/// The key of the removed `pallet_sudo`.
+#[frame_support::storage_alias]
+type SudoKey = StorageValue<Sudo, AccountId>;
+
+/// Hands the powers of the former sudo key over to the council.
+///
+/// If the council has no member yet, the sudo key is seated as its sole member and prime so that
+/// governance keeps working right after the upgrade. The key itself is removed, the rest of the
+/// pallet storage is cleared by [`RemoveSudo`].
+pub struct SudoToCouncil;
+impl OnRuntimeUpgrade for SudoToCouncil {
+    fn on_runtime_upgrade() -> Weight {
+        use pallet_membership::WeightInfo as _;
+        type MembershipWeight =
+            <Runtime as pallet_membership::Config<CouncilMembershipInstance>>::WeightInfo;
+
+        let db = <Runtime as frame_system::Config>::DbWeight::get();
+        let Some(key) = SudoKey::take() else {
+            return db.reads(1);
+        };
+        let mut weight = db.reads_writes(2, 1);
+
+        if pallet_membership::Members::<Runtime, CouncilMembershipInstance>::get().is_empty() {
+            let who = <Runtime as frame_system::Config>::Lookup::unlookup(key);
+            let _ = CouncilMembership::add_member(RuntimeOrigin::root(), who.clone())
+                .and_then(|_| CouncilMembership::set_prime(RuntimeOrigin::root(), who))
+                .inspect_err(|e| {
+                    log::error!(target: "runtime::council", "Failed to seat the sudo key: {e:?}")
+                });
+            weight.saturating_accrue(MembershipWeight::add_member(1));
+            weight.saturating_accrue(MembershipWeight::set_prime(1));
+        }
+
+        weight
+    }
+}
+
+/// Clears whatever is left of the removed `pallet_sudo` storage.
+pub type RemoveSudo = frame_support::migrations::RemovePallet<
+    SudoPalletName,
+    <Runtime as frame_system::Config>::DbWeight,
+>;
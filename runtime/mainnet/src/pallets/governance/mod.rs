@@ -0,0 +1,132 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Token holder governance: referenda with conviction voting on dedicated tracks, plus the
+//! whitelist used by the council to fast-track root calls.
+
+use super::council::{CouncilHalf, CouncilThreeFifths, CouncilTwoThirds};
+use crate::*;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, EitherOf, EnsureWithSuccess, tokens::currency::ActiveIssuanceOf},
+};
+use frame_system::{EnsureRoot, EnsureRootWithSuccess, EnsureSigned};
+use shared_runtime::currency::AFT;
+
+mod origins;
+pub use origins::{
+    AllocationAdmin, MediumSpender, ReferendumCanceller, ReferendumKiller, SmallSpender, Treasurer,
+    ValidatorAdmin, WhitelistedCaller, pallet_custom_origins,
+};
+mod tracks;
+pub use tracks::TracksInfo;
+
+/// Root, a referendum on the `validator_admin` track or two thirds of the council.
+pub type ValidatorAdminOrigin =
+    EitherOf<EnsureRoot<AccountId>, EitherOf<ValidatorAdmin, CouncilTwoThirds>>;
+
+/// Root, a referendum on the `allocation_admin` track or two thirds of the council.
+pub type AllocationAdminOrigin =
+    EitherOf<EnsureRoot<AccountId>, EitherOf<AllocationAdmin, CouncilTwoThirds>>;
+
+/// Root, a referendum on the `treasurer` track or half of the council.
+pub type TreasuryRejectOrigin = EitherOf<EnsureRoot<AccountId>, EitherOf<Treasurer, CouncilHalf>>;
+
+parameter_types! {
+    /// Largest amount a single `small_spender` referendum can spend.
+    pub const SmallSpendLimit: Balance = 10_000 * AFT;
+    /// Largest amount a single `medium_spender` referendum can spend.
+    pub const MediumSpendLimit: Balance = 250_000 * AFT;
+    /// Largest amount a single `treasurer` referendum can spend.
+    pub const TreasurerSpendLimit: Balance = 10_000_000 * AFT;
+    /// Largest amount a single council motion can spend.
+    pub const CouncilSpendLimit: Balance = 100_000 * AFT;
+}
+
+/// Root spends without limit, each spender track up to its own limit and three fifths of the
+/// council up to [`CouncilSpendLimit`].
+///
+/// Spends in other assets are converted to AFT before being checked against these limits.
+pub type TreasurySpendOrigin = EitherOf<
+    EnsureRootWithSuccess<AccountId, super::treasury::MaxBalance>,
+    EitherOf<
+        EitherOf<
+            EnsureWithSuccess<SmallSpender, AccountId, SmallSpendLimit>,
+            EnsureWithSuccess<MediumSpender, AccountId, MediumSpendLimit>,
+        >,
+        EitherOf<
+            EnsureWithSuccess<Treasurer, AccountId, TreasurerSpendLimit>,
+            EnsureWithSuccess<CouncilThreeFifths, AccountId, CouncilSpendLimit>,
+        >,
+    >,
+>;
+
+impl pallet_custom_origins::Config for Runtime {}
+
+parameter_types! {
+    pub const VoteLockingPeriod: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_conviction_voting::Config for Runtime {
+    type WeightInfo = weights::conviction_voting::AllfeatWeight<Runtime>;
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type VoteLockingPeriod = VoteLockingPeriod;
+    type MaxVotes = ConstU32<512>;
+    type MaxTurnout = ActiveIssuanceOf<Balances, Self::AccountId>;
+    type Polls = Referenda;
+    type BlockNumberProvider = System;
+    type VotingHooks = ();
+}
+
+parameter_types! {
+    pub const AlarmInterval: BlockNumber = 1;
+    pub const SubmissionDeposit: Balance = 100 * AFT;
+    pub const UndecidingTimeout: BlockNumber = 14 * DAYS;
+}
+
+impl pallet_referenda::Config for Runtime {
+    type WeightInfo = weights::referenda::AllfeatWeight<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Scheduler = Scheduler;
+    type Currency = Balances;
+    type SubmitOrigin = EnsureSigned<AccountId>;
+    type CancelOrigin = EitherOf<EnsureRoot<AccountId>, ReferendumCanceller>;
+    type KillOrigin = EitherOf<EnsureRoot<AccountId>, ReferendumKiller>;
+    /// Slashed decision deposits are sent to the treasury.
+    type Slash = Treasury;
+    type Votes = pallet_conviction_voting::VotesOf<Runtime>;
+    type Tally = pallet_conviction_voting::TallyOf<Runtime>;
+    type SubmissionDeposit = SubmissionDeposit;
+    type MaxQueued = ConstU32<100>;
+    type UndecidingTimeout = UndecidingTimeout;
+    type AlarmInterval = AlarmInterval;
+    type Tracks = TracksInfo;
+    type Preimages = Preimage;
+    type BlockNumberProvider = System;
+}
+
+impl pallet_whitelist::Config for Runtime {
+    type WeightInfo = weights::whitelist::AllfeatWeight<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type WhitelistOrigin = EitherOf<EnsureRoot<AccountId>, CouncilTwoThirds>;
+    type DispatchWhitelistedOrigin = EitherOf<EnsureRoot<AccountId>, WhitelistedCaller>;
+    type Preimages = Preimage;
+}
@@ -0,0 +1,100 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Custom origins for the governance tracks.
+
+pub use pallet_custom_origins::*;
+
+#[frame_support::pallet]
+pub mod pallet_custom_origins {
+    use frame_support::{pallet_prelude::*, traits::OriginTrait};
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {}
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Origins a referendum can dispatch with, one per governance track.
+    #[derive(
+        PartialEq,
+        Eq,
+        Clone,
+        MaxEncodedLen,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        TypeInfo,
+        RuntimeDebug,
+    )]
+    #[pallet::origin]
+    pub enum Origin {
+        /// Origin able to cancel referenda.
+        ReferendumCanceller,
+        /// Origin able to kill referenda and slash their deposits.
+        ReferendumKiller,
+        /// Origin able to dispatch calls whitelisted by the council.
+        WhitelistedCaller,
+        /// Origin able to manage the validator set and its rewards.
+        ValidatorAdmin,
+        /// Origin able to spend and reject treasury funds.
+        Treasurer,
+        /// Origin able to spend small amounts of treasury funds, e.g. artist grants.
+        SmallSpender,
+        /// Origin able to spend medium amounts of treasury funds.
+        MediumSpender,
+        /// Origin able to administrate the token allocation envelopes.
+        AllocationAdmin,
+    }
+
+    macro_rules! decl_unit_ensures {
+        ( $name:ident ) => {
+            pub struct $name;
+            impl<O: OriginTrait + From<Origin>> EnsureOrigin<O> for $name
+            where
+                for<'a> &'a O::PalletsOrigin: TryInto<&'a Origin>,
+            {
+                type Success = ();
+                fn try_origin(o: O) -> Result<Self::Success, O> {
+                    if let Ok(Origin::$name) = o.caller().try_into() {
+                        return Ok(());
+                    }
+                    Err(o)
+                }
+                #[cfg(feature = "runtime-benchmarks")]
+                fn try_successful_origin() -> Result<O, ()> {
+                    Ok(O::from(Origin::$name))
+                }
+            }
+        };
+        ( $name:ident, $( $rest:ident ),+ ) => {
+            decl_unit_ensures! { $name }
+            decl_unit_ensures! { $( $rest ),+ }
+        };
+    }
+    decl_unit_ensures!(
+        ReferendumCanceller,
+        ReferendumKiller,
+        WhitelistedCaller,
+        ValidatorAdmin,
+        Treasurer,
+        SmallSpender,
+        MediumSpender,
+        AllocationAdmin
+    );
+}
@@ -0,0 +1,217 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Referenda tracks of the mainnet governance.
+
+use super::origins;
+use crate::*;
+use alloc::borrow::Cow;
+use pallet_referenda::{Curve, Track, TrackInfo, str_array as s};
+use shared_runtime::currency::AFT;
+use sp_runtime::FixedI64;
+
+const fn percent(x: i32) -> FixedI64 {
+    FixedI64::from_rational(x as u128, 100)
+}
+
+const APP_ROOT: Curve = Curve::make_reciprocal(4, 28, percent(80), percent(50), percent(100));
+const SUP_ROOT: Curve = Curve::make_linear(28, 28, percent(0), percent(50));
+const APP_WHITELISTED_CALLER: Curve =
+    Curve::make_reciprocal(16, 28 * 24, percent(96), percent(50), percent(100));
+const SUP_WHITELISTED_CALLER: Curve =
+    Curve::make_reciprocal(1, 28, percent(20), percent(5), percent(50));
+const APP_REFERENDUM_CANCELLER: Curve = Curve::make_linear(17, 28, percent(50), percent(100));
+const SUP_REFERENDUM_CANCELLER: Curve =
+    Curve::make_reciprocal(12, 28, percent(1), percent(0), percent(50));
+const APP_REFERENDUM_KILLER: Curve = Curve::make_linear(17, 28, percent(50), percent(100));
+const SUP_REFERENDUM_KILLER: Curve =
+    Curve::make_reciprocal(12, 28, percent(1), percent(0), percent(50));
+const APP_VALIDATOR_ADMIN: Curve = Curve::make_linear(17, 28, percent(50), percent(100));
+const SUP_VALIDATOR_ADMIN: Curve =
+    Curve::make_reciprocal(12, 28, percent(1), percent(0), percent(50));
+const APP_TREASURER: Curve = Curve::make_reciprocal(4, 28, percent(80), percent(50), percent(100));
+const SUP_TREASURER: Curve = Curve::make_linear(28, 28, percent(0), percent(50));
+const APP_SMALL_SPENDER: Curve = Curve::make_linear(8, 28, percent(50), percent(100));
+const SUP_SMALL_SPENDER: Curve = Curve::make_reciprocal(2, 28, percent(1), percent(0), percent(50));
+const APP_MEDIUM_SPENDER: Curve = Curve::make_linear(23, 28, percent(50), percent(100));
+const SUP_MEDIUM_SPENDER: Curve =
+    Curve::make_reciprocal(4, 28, percent(1), percent(0), percent(50));
+const APP_ALLOCATION_ADMIN: Curve = Curve::make_linear(17, 28, percent(50), percent(100));
+const SUP_ALLOCATION_ADMIN: Curve =
+    Curve::make_reciprocal(12, 28, percent(1), percent(0), percent(50));
+
+const TRACKS_DATA: [Track<u16, Balance, BlockNumber>; 9] = [
+    Track {
+        id: 0,
+        info: TrackInfo {
+            name: s("root"),
+            max_deciding: 1,
+            decision_deposit: 100_000 * AFT,
+            prepare_period: 2 * HOURS,
+            decision_period: 14 * DAYS,
+            confirm_period: DAYS,
+            min_enactment_period: DAYS,
+            min_approval: APP_ROOT,
+            min_support: SUP_ROOT,
+        },
+    },
+    Track {
+        id: 1,
+        info: TrackInfo {
+            name: s("whitelisted_caller"),
+            max_deciding: 10,
+            decision_deposit: 10_000 * AFT,
+            prepare_period: 30 * MINUTES,
+            decision_period: 14 * DAYS,
+            confirm_period: 10 * MINUTES,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_WHITELISTED_CALLER,
+            min_support: SUP_WHITELISTED_CALLER,
+        },
+    },
+    Track {
+        id: 2,
+        info: TrackInfo {
+            name: s("referendum_canceller"),
+            max_deciding: 20,
+            decision_deposit: 10_000 * AFT,
+            prepare_period: 2 * HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_REFERENDUM_CANCELLER,
+            min_support: SUP_REFERENDUM_CANCELLER,
+        },
+    },
+    Track {
+        id: 3,
+        info: TrackInfo {
+            name: s("referendum_killer"),
+            max_deciding: 20,
+            decision_deposit: 50_000 * AFT,
+            prepare_period: 2 * HOURS,
+            decision_period: 14 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_REFERENDUM_KILLER,
+            min_support: SUP_REFERENDUM_KILLER,
+        },
+    },
+    Track {
+        id: 10,
+        info: TrackInfo {
+            name: s("validator_admin"),
+            max_deciding: 10,
+            decision_deposit: 5_000 * AFT,
+            prepare_period: 2 * HOURS,
+            decision_period: 14 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_VALIDATOR_ADMIN,
+            min_support: SUP_VALIDATOR_ADMIN,
+        },
+    },
+    Track {
+        id: 11,
+        info: TrackInfo {
+            name: s("treasurer"),
+            max_deciding: 10,
+            decision_deposit: 10_000 * AFT,
+            prepare_period: 2 * HOURS,
+            decision_period: 14 * DAYS,
+            confirm_period: DAYS,
+            min_enactment_period: DAYS,
+            min_approval: APP_TREASURER,
+            min_support: SUP_TREASURER,
+        },
+    },
+    Track {
+        id: 12,
+        info: TrackInfo {
+            name: s("allocation_admin"),
+            max_deciding: 10,
+            decision_deposit: 20_000 * AFT,
+            prepare_period: 2 * HOURS,
+            decision_period: 14 * DAYS,
+            confirm_period: DAYS,
+            min_enactment_period: DAYS,
+            min_approval: APP_ALLOCATION_ADMIN,
+            min_support: SUP_ALLOCATION_ADMIN,
+        },
+    },
+    Track {
+        id: 20,
+        info: TrackInfo {
+            name: s("small_spender"),
+            max_deciding: 50,
+            decision_deposit: 500 * AFT,
+            prepare_period: 2 * HOURS,
+            decision_period: 14 * DAYS,
+            confirm_period: 12 * HOURS,
+            min_enactment_period: DAYS,
+            min_approval: APP_SMALL_SPENDER,
+            min_support: SUP_SMALL_SPENDER,
+        },
+    },
+    Track {
+        id: 21,
+        info: TrackInfo {
+            name: s("medium_spender"),
+            max_deciding: 20,
+            decision_deposit: 2_500 * AFT,
+            prepare_period: 2 * HOURS,
+            decision_period: 14 * DAYS,
+            confirm_period: DAYS,
+            min_enactment_period: DAYS,
+            min_approval: APP_MEDIUM_SPENDER,
+            min_support: SUP_MEDIUM_SPENDER,
+        },
+    },
+];
+
+pub struct TracksInfo;
+impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
+    type Id = u16;
+    type RuntimeOrigin = <RuntimeOrigin as frame_support::traits::OriginTrait>::PalletsOrigin;
+
+    fn tracks() -> impl Iterator<Item = Cow<'static, Track<Self::Id, Balance, BlockNumber>>> {
+        TRACKS_DATA.iter().map(Cow::Borrowed)
+    }
+
+    fn track_for(id: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+        if let Ok(system_origin) = frame_system::RawOrigin::try_from(id.clone()) {
+            match system_origin {
+                frame_system::RawOrigin::Root => Ok(0),
+                _ => Err(()),
+            }
+        } else if let Ok(custom_origin) = origins::Origin::try_from(id.clone()) {
+            match custom_origin {
+                origins::Origin::WhitelistedCaller => Ok(1),
+                origins::Origin::ReferendumCanceller => Ok(2),
+                origins::Origin::ReferendumKiller => Ok(3),
+                origins::Origin::ValidatorAdmin => Ok(10),
+                origins::Origin::Treasurer => Ok(11),
+                origins::Origin::AllocationAdmin => Ok(12),
+                origins::Origin::SmallSpender => Ok(20),
+                origins::Origin::MediumSpender => Ok(21),
+            }
+        } else {
+            Err(())
+        }
+    }
+}
@@ -16,7 +16,18 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use sp_runtime::{MultiSignature, traits::Verify};
+use allfeat_primitives::{Hash, meta_tx::MetaTxRelay};
+use alloc::{boxed::Box, vec::Vec};
+use frame_support::{
+    dispatch::GetDispatchInfo,
+    pallet_prelude::{Decode, Encode},
+    traits::GetCallMetadata,
+};
+use sp_runtime::{
+    generic::{Era, ExtensionVersion, SignedPayload},
+    traits::{AsSystemOriginSigner, TransactionExtension, Verify},
+    transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+};
 
 use crate::*;
 
@@ -27,9 +38,106 @@ impl pallet_meta_tx::Config for Runtime {
 }
 
 impl pallet_verify_signature::Config for Runtime {
-    type Signature = MultiSignature;
+    type Signature = Signature;
     type AccountIdentifier = <Signature as Verify>::Signer;
     type WeightInfo = weights::verify_signature::AllfeatWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
-    type BenchmarkHelper = ();
+    type BenchmarkHelper = VerifySignatureBenchmarkHelper;
+}
+
+/// Benchmark signatures: passkey assertions, the most expensive scheme to verify.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct VerifySignatureBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_verify_signature::BenchmarkHelper<Signature, AccountId>
+    for VerifySignatureBenchmarkHelper
+{
+    fn create_signature(entropy: &[u8], msg: &[u8]) -> (Signature, AccountId) {
+        use sp_runtime::traits::IdentifyAccount;
+
+        let signature =
+            allfeat_primitives::WebAuthnSignature::sign(&sp_io::hashing::blake2_256(entropy), msg);
+        let account = signature.public.into_account();
+        (signature.into(), account)
+    }
+}
+
+/// Checks the encoded `meta_tx` as `MetaTx::dispatch` would and prepares the transaction relaying
+/// it with `nonce`, mortal from `era`, for [`meta_tx_relay_extrinsic`].
+///
+/// The checks run as in a block built on top of `block_hash`, like the transaction pool does.
+pub fn prepare_meta_tx_relay(
+    meta_tx: Vec<u8>,
+    nonce: Nonce,
+    era: Era,
+    block_hash: Hash,
+) -> Result<MetaTxRelay<AccountId>, TransactionValidityError> {
+    System::initialize(
+        &(System::block_number() + 1),
+        &block_hash,
+        &Default::default(),
+    );
+
+    let (call, version, extension): (RuntimeCall, ExtensionVersion, MetaTxExtension) =
+        Decode::decode(&mut &meta_tx[..]).map_err(|_| InvalidTransaction::Call)?;
+    let info = call.get_dispatch_info();
+    let (_, _, origin) = extension.validate_only(
+        RuntimeOrigin::none(),
+        &call,
+        &info,
+        meta_tx.len(),
+        TransactionSource::External,
+        version,
+    )?;
+    let signer = origin
+        .as_system_origin_signer()
+        .cloned()
+        .ok_or(InvalidTransaction::BadSigner)?;
+    let metadata = call.get_call_metadata();
+
+    let relay_extension: TxExtension = (
+        frame_system::CheckNonZeroSender::new(),
+        frame_system::CheckSpecVersion::new(),
+        frame_system::CheckTxVersion::new(),
+        frame_system::CheckGenesis::new(),
+        frame_system::CheckEra::from(era),
+        frame_system::CheckNonce::from(nonce),
+        frame_system::CheckWeight::new(),
+        pallet_sponsorship::CheckSponsorship::new(),
+        pallet_transaction_payment::ChargeTransactionPayment::from(0),
+        frame_metadata_hash_extension::CheckMetadataHash::new(false),
+    );
+    let dispatch = RuntimeCall::MetaTx(pallet_meta_tx::Call::dispatch {
+        meta_tx: Box::new(pallet_meta_tx::MetaTx::new(call, version, extension)),
+    });
+    let payload = SignedPayload::new(dispatch.clone(), relay_extension.clone())?;
+
+    Ok(MetaTxRelay {
+        signer,
+        pallet: metadata.pallet_name.as_bytes().to_vec(),
+        call: metadata.function_name.as_bytes().to_vec(),
+        dispatch: dispatch.encode(),
+        extension: relay_extension.encode(),
+        payload: payload.using_encoded(|payload| payload.to_vec()),
+    })
+}
+
+/// The transaction relaying a meta-transaction prepared by [`prepare_meta_tx_relay`], signed by
+/// `relayer` with `signature` over its payload.
+pub fn meta_tx_relay_extrinsic(
+    relay: MetaTxRelay<AccountId>,
+    relayer: AccountId,
+    signature: Signature,
+) -> Result<UncheckedExtrinsic, TransactionValidityError> {
+    let call =
+        RuntimeCall::decode(&mut &relay.dispatch[..]).map_err(|_| InvalidTransaction::Call)?;
+    let extension =
+        TxExtension::decode(&mut &relay.extension[..]).map_err(|_| InvalidTransaction::Call)?;
+    Ok(UncheckedExtrinsic::new_signed(
+        call,
+        relayer.into(),
+        signature,
+        extension,
+    ))
 }
@@ -0,0 +1,100 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    dynamic_params::{dynamic_pallet_params, dynamic_params},
+    traits::{EnsureOrigin, EnsureOriginWithArg},
+};
+use shared_runtime::currency::{MICROAFT, MILLIAFT};
+use sp_runtime::Perbill;
+
+/// Runtime values that governance can change without a runtime upgrade.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+    use super::*;
+
+    #[dynamic_pallet_params]
+    #[codec(index = 0)]
+    pub mod fees {
+        /// Share of the fees and tips sent to the treasury, the block author gets the rest.
+        #[codec(index = 0)]
+        pub static TreasuryFeeShare: Perbill = Perbill::from_percent(20);
+
+        /// Fee charged per byte of an encoded extrinsic.
+        #[codec(index = 1)]
+        pub static TransactionByteFee: Balance = 10 * MICROAFT;
+
+        /// Fee charged for the weight of a base extrinsic.
+        #[codec(index = 2)]
+        pub static WeightFeeFactor: Balance = 10 * MILLIAFT;
+
+        /// Share of the fees and tips burned before paying the treasury and the block author.
+        ///
+        /// The treasury share is capped to what this share leaves, the burn always applies in
+        /// full.
+        #[codec(index = 3)]
+        pub static BurnFeeShare: Perbill = Perbill::zero();
+    }
+
+    #[dynamic_pallet_params]
+    #[codec(index = 1)]
+    pub mod token_allocation {
+        /// Number of blocks between two vesting payouts.
+        #[codec(index = 0)]
+        pub static EpochDuration: BlockNumber = DAYS;
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+    fn default() -> Self {
+        RuntimeParameters::Fees(dynamic_params::fees::Parameters::TransactionByteFee(
+            dynamic_params::fees::TransactionByteFee,
+            Some(10 * MICROAFT),
+        ))
+    }
+}
+
+/// Each group of parameters is managed by the origin administrating the matching domain.
+pub struct ParametersAdminOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for ParametersAdminOrigin {
+    type Success = ();
+
+    fn try_origin(
+        origin: RuntimeOrigin,
+        key: &RuntimeParametersKey,
+    ) -> Result<Self::Success, RuntimeOrigin> {
+        match key {
+            RuntimeParametersKey::Fees(_) => RootOrCouncilTwoThirds::try_origin(origin),
+            RuntimeParametersKey::TokenAllocation(_) => AllocationAdminOrigin::try_origin(origin),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(_key: &RuntimeParametersKey) -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::root())
+    }
+}
+
+impl pallet_parameters::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeParameters = RuntimeParameters;
+    type AdminOrigin = ParametersAdminOrigin;
+    type WeightInfo = pallet_parameters::weights::SubstrateWeight<Runtime>;
+}
@@ -36,6 +36,8 @@ parameter_types! {
 }
 
 /// The type used to represent the kinds of proxying allowed.
+///
+/// The indices are shared with the melodie runtime, which only has some of the variants.
 #[derive(
     Copy,
     Clone,
@@ -51,19 +53,105 @@ parameter_types! {
     scale_info::TypeInfo,
 )]
 pub enum ProxyType {
+    /// All calls can be proxied.
+    #[codec(index = 0)]
     Any,
+    /// Everything but the calls moving funds or dispatching from another account, where this
+    /// filter would not apply anymore.
+    #[codec(index = 1)]
     NonTransfer,
+    /// Registering and updating ATS works.
+    #[codec(index = 2)]
+    Registrar,
+    /// Taking part in the council and in referenda.
+    #[codec(index = 3)]
+    Governance,
+    /// Managing the session keys and the operator profile of a validator.
+    #[codec(index = 4)]
+    Validator,
+    /// Claiming token allocations and airdrops.
+    #[codec(index = 5)]
+    Allocation,
+    /// Rejecting the announcements of a time-delayed proxy.
+    #[codec(index = 6)]
+    CancelProxy,
 }
 impl Default for ProxyType {
     fn default() -> Self {
         Self::Any
     }
 }
+
+/// Batches are the only `Utility` calls the narrow proxy types can make, each batched call is
+/// still checked against the proxy filter. Dispatching as a derivative account or with another
+/// origin would escape it.
+fn is_batch(c: &RuntimeCall) -> bool {
+    matches!(
+        c,
+        RuntimeCall::Utility(
+            pallet_utility::Call::batch { .. }
+                | pallet_utility::Call::batch_all { .. }
+                | pallet_utility::Call::force_batch { .. }
+        )
+    )
+}
+
 impl InstanceFilter<RuntimeCall> for ProxyType {
     fn filter(&self, c: &RuntimeCall) -> bool {
         match self {
             ProxyType::Any => true,
-            ProxyType::NonTransfer => !matches!(c, RuntimeCall::Balances(..)),
+            ProxyType::NonTransfer => !matches!(
+                c,
+                RuntimeCall::Balances(..)
+                    | RuntimeCall::Assets(..)
+                    | RuntimeCall::Multisig(..)
+                    | RuntimeCall::Proxy(
+                        pallet_proxy::Call::proxy { .. }
+                            | pallet_proxy::Call::proxy_announced { .. }
+                    )
+                    | RuntimeCall::TokenAllocation(
+                        pallet_token_allocation::Call::transfer_allocation { .. }
+                    )
+                    // Sponsorship budgets and deposits are paid by the sponsor.
+                    | RuntimeCall::Sponsorship(..)
+            ),
+            ProxyType::Registrar => matches!(c, RuntimeCall::Ats(..)) || is_batch(c),
+            ProxyType::Governance => {
+                matches!(
+                    c,
+                    RuntimeCall::Council(..)
+                        | RuntimeCall::Referenda(..)
+                        | RuntimeCall::ConvictionVoting(..)
+                ) || is_batch(c)
+            }
+            ProxyType::Validator => {
+                matches!(
+                    c,
+                    RuntimeCall::Session(
+                        pallet_session::Call::set_keys { .. }
+                            | pallet_session::Call::purge_keys { .. }
+                    ) | RuntimeCall::Validators(
+                        pallet_validators::Call::set_operator_profile { .. }
+                            | pallet_validators::Call::clear_operator_profile { .. }
+                    )
+                ) || is_batch(c)
+            }
+            ProxyType::Allocation => {
+                matches!(
+                    c,
+                    RuntimeCall::TokenAllocation(
+                        pallet_token_allocation::Call::claim { .. }
+                            | pallet_token_allocation::Call::claim_for { .. }
+                            | pallet_token_allocation::Call::claim_airdrop { .. }
+                    )
+                ) || is_batch(c)
+            }
+            ProxyType::CancelProxy => {
+                matches!(
+                    c,
+                    RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. })
+                ) || is_batch(c)
+            }
         }
     }
     fn is_superset(&self, o: &Self) -> bool {
@@ -72,6 +160,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             (ProxyType::Any, _) => true,
             (_, ProxyType::Any) => false,
             (ProxyType::NonTransfer, _) => true,
+            _ => false,
         }
     }
 }
@@ -0,0 +1,106 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{
+    parameter_types,
+    traits::{Contains, EitherOf, EnsureWithSuccess, GetCallMetadata},
+};
+use frame_system::{EnsureRoot, EnsureRootWithSuccess};
+use pallet_tx_pause::RuntimeCallNameOf;
+use sp_core::ConstU32;
+
+use crate::*;
+
+/// Pallets that stay usable during an emergency so that governance can react and blocks keep
+/// being finalized.
+const EMERGENCY_PALLETS: &[&str] = &[
+    "System",
+    "Timestamp",
+    "Grandpa",
+    "SafeMode",
+    "TxPause",
+    "Preimage",
+    "Council",
+    "CouncilMembership",
+    "Referenda",
+    "ConvictionVoting",
+    "Whitelist",
+];
+
+/// Calls that can bypass the safe-mode pallet.
+pub struct SafeModeWhitelistedCalls;
+impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        EMERGENCY_PALLETS.contains(&call.get_call_metadata().pallet_name)
+    }
+}
+
+/// Calls that can never be paused.
+pub struct TxPauseWhitelistedCalls;
+impl Contains<RuntimeCallNameOf<Runtime>> for TxPauseWhitelistedCalls {
+    fn contains((pallet, _call): &RuntimeCallNameOf<Runtime>) -> bool {
+        EMERGENCY_PALLETS
+            .iter()
+            .any(|name| name.as_bytes() == pallet.as_slice())
+    }
+}
+
+/// Root or half of the council, so that an emergency can be handled quickly.
+pub type EmergencyOrigin = EitherOf<EnsureRoot<AccountId>, CouncilHalf>;
+
+parameter_types! {
+    pub const EnterDuration: BlockNumber = 4 * HOURS;
+    pub const EnterDepositAmount: Option<Balance> = None;
+    pub const ExtendDuration: BlockNumber = 2 * HOURS;
+    pub const ExtendDepositAmount: Option<Balance> = None;
+    pub const ReleaseDelay: u32 = 2 * DAYS;
+}
+
+impl pallet_safe_mode::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type WhitelistedCalls = SafeModeWhitelistedCalls;
+    type EnterDuration = EnterDuration;
+    type ExtendDuration = ExtendDuration;
+    type EnterDepositAmount = EnterDepositAmount;
+    type ExtendDepositAmount = ExtendDepositAmount;
+    type ForceEnterOrigin = EitherOf<
+        EnsureRootWithSuccess<AccountId, EnterDuration>,
+        EnsureWithSuccess<CouncilHalf, AccountId, EnterDuration>,
+    >;
+    type ForceExtendOrigin = EitherOf<
+        EnsureRootWithSuccess<AccountId, ExtendDuration>,
+        EnsureWithSuccess<CouncilHalf, AccountId, ExtendDuration>,
+    >;
+    type ForceExitOrigin = EmergencyOrigin;
+    type ForceDepositOrigin = RootOrCouncilTwoThirds;
+    type Notify = ();
+    type ReleaseDelay = ReleaseDelay;
+    type WeightInfo = weights::safe_mode::AllfeatWeight<Runtime>;
+}
+
+impl pallet_tx_pause::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type PauseOrigin = EmergencyOrigin;
+    type UnpauseOrigin = EmergencyOrigin;
+    type WhitelistedCalls = TxPauseWhitelistedCalls;
+    type MaxNameLen = ConstU32<256>;
+    type WeightInfo = weights::tx_pause::AllfeatWeight<Runtime>;
+}
@@ -0,0 +1,162 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    pallet_prelude::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen, RuntimeDebug},
+    parameter_types,
+    traits::InstanceFilter,
+};
+use pallet_sponsorship::{ComputeFee, InspectRelayedCall};
+use shared_runtime::currency::deposit;
+use sp_runtime::generic::ExtensionVersion;
+
+parameter_types! {
+    pub const MaxSponsoredBeneficiaries: u32 = 100;
+    // One storage item; key size 32, value size 53.
+    pub const SponsorshipDeposit: Balance = deposit(1, 85);
+    // One storage item; key size 64, value size 16.
+    pub const BeneficiaryDeposit: Balance = deposit(1, 80);
+}
+
+/// The kinds of relayed calls a sponsor pays for.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    RuntimeDebug,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+)]
+pub enum SponsoredCalls {
+    /// Any call, for beneficiaries the sponsor fully trusts.
+    Any,
+    /// ATS registrations and updates.
+    #[default]
+    Ats,
+}
+
+impl InstanceFilter<RuntimeCall> for SponsoredCalls {
+    fn filter(&self, c: &RuntimeCall) -> bool {
+        match self {
+            SponsoredCalls::Any => true,
+            SponsoredCalls::Ats => matches!(c, RuntimeCall::Ats(..)),
+        }
+    }
+
+    fn is_superset(&self, o: &Self) -> bool {
+        self == o || *self == SponsoredCalls::Any
+    }
+}
+
+/// Reads the signer and the call of `MetaTx::dispatch`.
+pub struct RelayedMetaTx;
+impl InspectRelayedCall<RuntimeCall, AccountId> for RelayedMetaTx {
+    #[cfg(not(feature = "runtime-benchmarks"))]
+    fn inspect(call: &RuntimeCall) -> Option<(AccountId, RuntimeCall)> {
+        let RuntimeCall::MetaTx(pallet_meta_tx::Call::dispatch { meta_tx }) = call else {
+            return None;
+        };
+        // `MetaTx` keeps its fields private, read them back from its encoding.
+        let (relayed, _, (signature, _)): (RuntimeCall, ExtensionVersion, MetaTxExtension) =
+            Decode::decode(&mut &meta_tx.encode()[..]).ok()?;
+        match signature {
+            pallet_verify_signature::VerifySignature::Signed { account, .. } => {
+                Some((account, relayed))
+            }
+            pallet_verify_signature::VerifySignature::Disabled => None,
+        }
+    }
+
+    // Meta transactions carry no signature in benchmarks, their calls are read as signed by the
+    // beneficiary of `SponsorshipBenchmarkHelper`.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn inspect(call: &RuntimeCall) -> Option<(AccountId, RuntimeCall)> {
+        let RuntimeCall::MetaTx(pallet_meta_tx::Call::dispatch { meta_tx }) = call else {
+            return None;
+        };
+        let (relayed, _, _): (RuntimeCall, ExtensionVersion, MetaTxExtension) =
+            Decode::decode(&mut &meta_tx.encode()[..]).ok()?;
+        Some((SponsorshipBenchmarkHelper::beneficiary(), relayed))
+    }
+}
+
+/// Relays a remark through `MetaTx::dispatch` in the sponsorship benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SponsorshipBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl SponsorshipBenchmarkHelper {
+    fn beneficiary() -> AccountId {
+        frame_benchmarking::account("beneficiary", 0, 0)
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_sponsorship::BenchmarkHelper<SponsoredCalls, RuntimeCall, AccountId>
+    for SponsorshipBenchmarkHelper
+{
+    fn relayed_call() -> (AccountId, RuntimeCall, SponsoredCalls) {
+        let remark = RuntimeCall::System(frame_system::Call::remark { remark: Vec::new() });
+        // The benchmark extension is weightless and encodes to nothing.
+        let extension = MetaTxExtension::decode(&mut &[][..]).expect("empty encoding; qed");
+        let call = RuntimeCall::MetaTx(pallet_meta_tx::Call::dispatch {
+            meta_tx: alloc::boxed::Box::new(pallet_meta_tx::MetaTx::new(
+                remark,
+                META_EXTENSION_VERSION,
+                extension,
+            )),
+        });
+        (Self::beneficiary(), call, SponsoredCalls::Any)
+    }
+}
+
+/// Fees as charged by `pallet_transaction_payment`, tips excluded.
+pub struct TransactionFees;
+impl ComputeFee<RuntimeCall, Balance> for TransactionFees {
+    fn compute_fee(len: u32, info: &DispatchInfo) -> Balance {
+        TransactionPayment::compute_fee(len, info, 0)
+    }
+
+    fn compute_actual_fee(len: u32, info: &DispatchInfo, post_info: &PostDispatchInfo) -> Balance {
+        TransactionPayment::compute_actual_fee(len, info, post_info, 0)
+    }
+}
+
+impl pallet_sponsorship::Config for Runtime {
+    type Balance = Balance;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type SponsorshipDeposit = SponsorshipDeposit;
+    type BeneficiaryDeposit = BeneficiaryDeposit;
+    type Fees = TransactionFees;
+    type CallFilter = SponsoredCalls;
+    type RelayedCall = RelayedMetaTx;
+    type MaxBeneficiaries = MaxSponsoredBeneficiaries;
+    type WeightInfo = weights::sponsorship::AllfeatWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = SponsorshipBenchmarkHelper;
+}
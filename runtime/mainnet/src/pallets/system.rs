@@ -19,7 +19,7 @@
 use crate::*;
 use frame_support::{
     derive_impl,
-    traits::{ConstU16, ConstU32},
+    traits::{ConstU16, ConstU32, InsideBoth},
     weights::{
         Weight,
         constants::{ParityDbWeight, WEIGHT_REF_TIME_PER_SECOND},
@@ -32,7 +32,22 @@ use shared_runtime::{NORMAL_DISPATCH_RATIO, RuntimeBlockLength};
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type SingleBlockMigrations = ();
+type SingleBlockMigrations = (
+    pallet_token_allocation::migrations::v1::MigrateToV1<
+        Runtime,
+        super::token_allocation::RevocableEnvelopes,
+    >,
+    pallet_token_allocation::migrations::v2::MigrateToV2<
+        Runtime,
+        super::token_allocation::TransferApprovalEnvelopes,
+    >,
+    pallet_token_allocation::migrations::v3::MigrateToV3<Runtime>,
+    pallet_token_allocation::migrations::v4::MigrateToV4<Runtime>,
+    super::council::SudoToCouncil,
+    super::council::RemoveSudo,
+    pallet_validators::migrations::v1::MigrateToV1<Runtime>,
+    super::treasury::SpendsToNativeOrWithId,
+);
 
 frame_support::parameter_types! {
     pub const Version: sp_version::RuntimeVersion = VERSION;
@@ -59,4 +74,6 @@ impl frame_system::Config for Runtime {
     type SS58Prefix = ConstU16<440>;
     type MaxConsumers = ConstU32<16>;
     type SingleBlockMigrations = SingleBlockMigrations;
+    /// Calls are filtered out while safe mode is on or when they are paused.
+    type BaseCallFilter = InsideBoth<SafeMode, TxPause>;
 }
@@ -16,23 +16,54 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::{PalletId, parameter_types};
-use frame_system::EnsureRoot;
+use frame_support::{PalletId, parameter_types, traits::Contains};
+use pallet_token_allocation::EnvelopeId;
+use sp_runtime::{traits::AccountIdConversion, transaction_validity::TransactionPriority};
+
+use super::treasury::TreasuryAccount;
 
 use crate::*;
 
 parameter_types! {
     pub const TokenAllocPalletId: PalletId = PalletId(*b"m/tknalc");
-    pub const EpochDuration: BlockNumber = DAYS;
-    pub const MaxPayoutsPerBlock: u32 = 256;
+    pub const MaxAllocationsPerAccount: u32 = 32;
+    pub const MaxFailedReleases: u32 = 64;
+    pub const AirdropUnsignedPriority: TransactionPriority = TransactionPriority::MAX / 2;
+    /// Funds new envelopes, it only receives treasury spends within the spender caps.
+    pub AllocationFundingAccount: AccountId =
+        PalletId(*b"m/tknfnd").into_account_truncating();
+}
+
+/// Envelopes whose allocations can be clawed back, used to migrate the existing envelopes.
+pub struct RevocableEnvelopes;
+impl Contains<EnvelopeId> for RevocableEnvelopes {
+    fn contains(id: &EnvelopeId) -> bool {
+        matches!(id, EnvelopeId::Teams | EnvelopeId::KoL)
+    }
+}
+
+/// Envelopes whose allocations can only be transferred with the approval of governance, used to
+/// migrate the existing envelopes.
+pub struct TransferApprovalEnvelopes;
+impl Contains<EnvelopeId> for TransferApprovalEnvelopes {
+    fn contains(id: &EnvelopeId) -> bool {
+        matches!(
+            id,
+            EnvelopeId::Teams | EnvelopeId::KoL | EnvelopeId::Private1 | EnvelopeId::Private2
+        )
+    }
 }
 
 impl pallet_token_allocation::Config for Runtime {
     type Currency = Balances;
-    type AdminOrigin = EnsureRoot<Self::AccountId>;
+    type AdminOrigin = AllocationAdminOrigin;
     type PalletId = TokenAllocPalletId;
-    type EpochDuration = EpochDuration;
-    type MaxPayoutsPerBlock = MaxPayoutsPerBlock;
+    type EpochDuration = dynamic_params::token_allocation::EpochDuration;
+    type MaxAllocationsPerAccount = MaxAllocationsPerAccount;
+    type MaxFailedReleases = MaxFailedReleases;
+    type SweepDestination = TreasuryAccount;
+    type FundingAccount = AllocationFundingAccount;
+    type AirdropUnsignedPriority = AirdropUnsignedPriority;
     type RuntimeHoldReason = RuntimeHoldReason;
     type WeightInfo = weights::token_allocation::AllfeatWeight<Runtime>;
 }
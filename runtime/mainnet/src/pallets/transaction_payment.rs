@@ -21,46 +21,27 @@ use frame_support::{
     dispatch::DispatchClass,
     parameter_types,
     sp_runtime::Perbill,
-    traits::{
-        Imbalance, OnUnbalanced,
-        fungible::{Balanced, Credit},
-    },
+    traits::Get,
     weights::{
         ConstantMultiplier, WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial,
     },
 };
-use shared_runtime::{
-    SlowAdjustingFeeUpdate,
-    currency::{MICROAFT, MILLIAFT},
-};
-
-pub struct DealWithFees;
-impl OnUnbalanced<Credit<AccountId, Balances>> for DealWithFees {
-    fn on_unbalanceds(mut fees_then_tips: impl Iterator<Item = Credit<AccountId, Balances>>) {
-        if let Some(mut amount) = fees_then_tips.next() {
-            if let Some(tips) = fees_then_tips.next() {
-                tips.merge_into(&mut amount);
-            }
+use shared_runtime::SlowAdjustingFeeUpdate;
 
-            // Split: 80% to block author, 20% to treasury
-            let treasury_amount = Perbill::from_percent(20) * amount.peek();
-            let (treasury_part, author_part) = amount.split(treasury_amount);
-
-            // Send 20% to treasury
-            let _ = Balances::resolve(&Treasury::account_id(), treasury_part);
+/// Splits fees and tips between a burn, the treasury and the block author.
+///
+/// Both the burn and treasury shares are runtime parameters, see [`pallet_fee_distribution`].
+pub type DealWithFees = FeeDistribution;
 
-            // Send 80% to block author
-            if let Some(author) = Authorship::author() {
-                let _ = Balances::resolve(&author, author_part);
-            }
-        }
-    }
+impl pallet_fee_distribution::Config for Runtime {
+    type Currency = Balances;
+    type BurnShare = dynamic_params::fees::BurnFeeShare;
+    type TreasuryShare = dynamic_params::fees::TreasuryFeeShare;
+    type TreasuryAccount = super::treasury::TreasuryAccount;
 }
 
 parameter_types! {
-    pub const TransactionByteFee: Balance = 10 * MICROAFT;
     pub const OperationalFeeMultiplier: u8 = 5;
-    pub const WeightFeeFactor: Balance = 10 * MILLIAFT;
 }
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
@@ -77,7 +58,7 @@ pub struct WeightToFee;
 impl WeightToFeePolynomial for WeightToFee {
     type Balance = Balance;
     fn polynomial() -> WeightToFeeCoefficients<Self::Balance> {
-        let p = WeightFeeFactor::get();
+        let p = dynamic_params::fees::WeightFeeFactor::get();
         let q = Balance::from(
             RuntimeBlockWeights::get()
                 .get(DispatchClass::Normal)
@@ -99,7 +80,7 @@ impl pallet_transaction_payment::Config for Runtime {
     type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, DealWithFees>;
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
     type WeightToFee = WeightToFee;
-    type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+    type LengthToFee = ConstantMultiplier<Balance, dynamic_params::fees::TransactionByteFee>;
     type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
     type WeightInfo = weights::transaction_payment::AllfeatWeight<Runtime>;
 }
@@ -1,15 +1,41 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
 #[cfg(feature = "runtime-benchmarks")]
 use core::marker::PhantomData;
 
 use crate::weights;
 use allfeat_primitives::{AccountId, Balance};
 use frame_support::{
-    PalletId, parameter_types,
-    traits::tokens::{PayFromAccount, UnityAssetBalanceConversion},
+    PalletId, Twox64Concat, parameter_types,
+    traits::{
+        Get, OnRuntimeUpgrade,
+        tokens::{
+            ConversionFromAssetBalance, PayAssetFromAccount,
+            fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
+        },
+    },
+    weights::Weight,
 };
-use frame_system::{EnsureRoot, EnsureRootWithSuccess};
+use parity_scale_codec::{Decode, DecodeAll, Encode};
+use shared_runtime::currency::{AFT, deposit};
 use sp_core::ConstU32;
-use sp_runtime::traits::IdentityLookup;
+use sp_runtime::{Permill, traits::IdentityLookup};
 
 #[cfg(feature = "runtime-benchmarks")]
 use frame_support::traits::fungible::{Inspect, Mutate};
@@ -18,7 +44,10 @@ use pallet_treasury::ArgumentsFactory;
 #[cfg(feature = "runtime-benchmarks")]
 use sp_core::crypto::FromEntropy;
 
-use crate::{Balances, BlockNumber, DAYS, Runtime, RuntimeEvent, System, Treasury};
+use crate::{
+    AssetId, AssetRate, Assets, Balances, BlockNumber, Bounties, ChildBounties, DAYS, Runtime,
+    RuntimeEvent, System, Treasury, TreasuryRejectOrigin, TreasurySpendOrigin,
+};
 
 parameter_types! {
     pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
@@ -29,15 +58,44 @@ parameter_types! {
     pub TreasuryAccount: AccountId = Treasury::account_id();
 }
 
+/// AFT and the local assets seen as a single multi-asset `fungibles` implementation.
+pub type NativeAndAssets =
+    UnionOf<Balances, Assets, NativeFromLeft, NativeOrWithId<AssetId>, AccountId>;
+
+/// Converts a treasury spend to its AFT value.
+///
+/// AFT is taken as is, local assets use the rate registered in `AssetRate`.
+pub struct NativeOrAssetRate;
+impl ConversionFromAssetBalance<Balance, NativeOrWithId<AssetId>, Balance> for NativeOrAssetRate {
+    type Error = pallet_asset_rate::Error<Runtime>;
+
+    fn from_asset_balance(
+        balance: Balance,
+        asset_kind: NativeOrWithId<AssetId>,
+    ) -> Result<Balance, Self::Error> {
+        match asset_kind {
+            NativeOrWithId::Native => Ok(balance),
+            NativeOrWithId::WithId(id) => AssetRate::from_asset_balance(balance, id),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn ensure_successful(asset_kind: NativeOrWithId<AssetId>) {
+        if let NativeOrWithId::WithId(id) = asset_kind {
+            AssetRate::ensure_successful(id)
+        }
+    }
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub struct PalletTreasuryArguments<T>(PhantomData<T>);
 #[cfg(feature = "runtime-benchmarks")]
-impl<T> ArgumentsFactory<(), AccountId> for PalletTreasuryArguments<T>
+impl<T> ArgumentsFactory<NativeOrWithId<AssetId>, AccountId> for PalletTreasuryArguments<T>
 where
     T: Mutate<AccountId> + Inspect<AccountId>,
 {
-    fn create_asset_kind(_seed: u32) -> () {
-        ()
+    fn create_asset_kind(_seed: u32) -> NativeOrWithId<AssetId> {
+        NativeOrWithId::Native
     }
     fn create_beneficiary(seed: [u8; 32]) -> AccountId {
         let account = AccountId::from_entropy(&mut seed.as_slice()).unwrap();
@@ -49,22 +107,156 @@ where
 impl pallet_treasury::Config for Runtime {
     type PalletId = TreasuryPalletId;
     type Currency = Balances;
-    type RejectOrigin = EnsureRoot<Self::AccountId>;
+    type RejectOrigin = TreasuryRejectOrigin;
     type RuntimeEvent = RuntimeEvent;
     type SpendPeriod = SpendPeriod;
     type Burn = ();
     type BurnDestination = ();
     type MaxApprovals = ConstU32<100>;
     type WeightInfo = weights::treasury::AllfeatWeight<Runtime>;
-    type SpendFunds = ();
-    type SpendOrigin = EnsureRootWithSuccess<Self::AccountId, MaxBalance>;
-    type AssetKind = ();
+    /// Approved bounties are funded at each spend period.
+    type SpendFunds = Bounties;
+    type SpendOrigin = TreasurySpendOrigin;
+    type AssetKind = NativeOrWithId<AssetId>;
     type Beneficiary = Self::AccountId;
     type BeneficiaryLookup = IdentityLookup<Self::Beneficiary>;
-    type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
-    type BalanceConverter = UnityAssetBalanceConversion;
+    type Paymaster = PayAssetFromAccount<NativeAndAssets, TreasuryAccount>;
+    type BalanceConverter = NativeOrAssetRate;
     type PayoutPeriod = PayoutSpendPeriod;
     type BlockNumberProvider = System;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = PalletTreasuryArguments<Balances>;
 }
+
+parameter_types! {
+    pub const BountyDepositBase: Balance = 10 * AFT;
+    pub const BountyDepositPayoutDelay: BlockNumber = 4 * DAYS;
+    pub const BountyUpdatePeriod: BlockNumber = 90 * DAYS;
+    pub const CuratorDepositMultiplier: Permill = Permill::from_percent(50);
+    pub const CuratorDepositMin: Balance = 10 * AFT;
+    pub const CuratorDepositMax: Balance = 200 * AFT;
+    pub const BountyValueMinimum: Balance = 100 * AFT;
+    pub const DataDepositPerByte: Balance = deposit(0, 1);
+    pub const MaximumReasonLength: u32 = 16384;
+    pub const MaxActiveChildBountyCount: u32 = 100;
+    pub const ChildBountyValueMinimum: Balance = BountyValueMinimum::get() / 10;
+}
+
+impl pallet_bounties::Config for Runtime {
+    type BountyDepositBase = BountyDepositBase;
+    type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
+    type BountyUpdatePeriod = BountyUpdatePeriod;
+    type CuratorDepositMultiplier = CuratorDepositMultiplier;
+    type CuratorDepositMin = CuratorDepositMin;
+    type CuratorDepositMax = CuratorDepositMax;
+    type BountyValueMinimum = BountyValueMinimum;
+    type DataDepositPerByte = DataDepositPerByte;
+    type RuntimeEvent = RuntimeEvent;
+    type MaximumReasonLength = MaximumReasonLength;
+    type WeightInfo = weights::bounties::AllfeatWeight<Runtime>;
+    type ChildBountyManager = ChildBounties;
+    /// Slashed curator deposits are sent to the treasury.
+    type OnSlash = Treasury;
+}
+
+impl pallet_child_bounties::Config for Runtime {
+    type MaxActiveChildBountyCount = MaxActiveChildBountyCount;
+    type ChildBountyValueMinimum = ChildBountyValueMinimum;
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = weights::child_bounties::AllfeatWeight<Runtime>;
+}
+
+/// Layout of a `pallet_treasury` spend, generic over the asset kind it is paid in.
+///
+/// Both the former `PayFromAccount` and the current `PayAssetFromAccount` paymasters use `()` as
+/// payment identifier.
+#[derive(Encode, Decode)]
+struct SpendStatus<AssetKind> {
+    asset_kind: AssetKind,
+    amount: Balance,
+    beneficiary: AccountId,
+    valid_from: BlockNumber,
+    expire_at: BlockNumber,
+    status: PaymentState,
+}
+
+#[derive(Encode, Decode)]
+enum PaymentState {
+    Pending,
+    Attempted { id: () },
+    Failed,
+}
+
+/// Spends recorded while the treasury only paid out in AFT.
+#[frame_support::storage_alias]
+type NativeSpends = StorageMap<Treasury, Twox64Concat, u32, SpendStatus<()>>;
+
+/// Spends as recorded since the treasury pays out in AFT or local assets.
+#[frame_support::storage_alias]
+type AssetSpends = StorageMap<Treasury, Twox64Concat, u32, SpendStatus<NativeOrWithId<AssetId>>>;
+
+/// Re-encodes the pending treasury spends created while `AssetKind` was `()` as AFT spends.
+///
+/// An AFT-only spend is one byte shorter than any multi-asset spend, so entries already in the
+/// new layout are left untouched and the migration is safe to run more than once.
+pub struct SpendsToNativeOrWithId;
+impl OnRuntimeUpgrade for SpendsToNativeOrWithId {
+    fn on_runtime_upgrade() -> Weight {
+        let db = <Runtime as frame_system::Config>::DbWeight::get();
+        let native_len = SpendStatus::<()> {
+            asset_kind: (),
+            amount: 0,
+            beneficiary: AccountId::new([0; 32]),
+            valid_from: 0,
+            expire_at: 0,
+            status: PaymentState::Pending,
+        }
+        .encoded_size();
+
+        let mut reads = 0u64;
+        let mut writes = 0u64;
+        for index in NativeSpends::iter_keys().collect::<alloc::vec::Vec<_>>() {
+            reads += 1;
+            let Some(raw) =
+                frame_support::storage::unhashed::get_raw(&NativeSpends::hashed_key_for(index))
+            else {
+                continue;
+            };
+            if raw.len() != native_len {
+                continue;
+            }
+            match SpendStatus::<()>::decode_all(&mut &raw[..]) {
+                Ok(old) => {
+                    AssetSpends::insert(
+                        index,
+                        SpendStatus {
+                            asset_kind: NativeOrWithId::Native,
+                            amount: old.amount,
+                            beneficiary: old.beneficiary,
+                            valid_from: old.valid_from,
+                            expire_at: old.expire_at,
+                            status: old.status,
+                        },
+                    );
+                    writes += 1;
+                }
+                Err(e) => {
+                    log::error!(target: "runtime::treasury", "Failed to decode spend {index}: {e:?}")
+                }
+            }
+        }
+
+        db.reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        for index in AssetSpends::iter_keys() {
+            frame_support::ensure!(
+                pallet_treasury::Spends::<Runtime>::try_get(index).is_ok(),
+                "treasury spend not decodable after migration"
+            );
+        }
+        Ok(())
+    }
+}
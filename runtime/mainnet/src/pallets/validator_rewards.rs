@@ -16,10 +16,18 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use super::treasury::TreasuryAccount;
 use crate::*;
+use frame_support::parameter_types;
 
-impl pallet_sudo::Config for Runtime {
-    type RuntimeEvent = RuntimeEvent;
-    type RuntimeCall = RuntimeCall;
-    type WeightInfo = weights::sudo::AllfeatWeight<Runtime>;
+parameter_types! {
+    pub const MaxAuthors: u32 = 32;
+}
+
+impl pallet_validator_rewards::Config for Runtime {
+    type Currency = Balances;
+    type AdminOrigin = ValidatorAdminOrigin;
+    type RewardSource = TreasuryAccount;
+    type MaxAuthors = MaxAuthors;
+    type WeightInfo = weights::validator_rewards::AllfeatWeight<Runtime>;
 }
@@ -16,14 +16,34 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use super::treasury::TreasuryAccount;
 use crate::*;
-use frame_support::parameter_types;
+use frame_support::{parameter_types, traits::tokens::imbalance::ResolveTo};
+use shared_runtime::currency::AFT;
+use sp_runtime::traits::ConvertInto;
 
 parameter_types! {
     pub const MaxValidators: u32 = 5;
+    pub const MaxProfileFieldLength: u32 = 128;
+    pub const MaxBootnodes: u32 = 4;
+    pub const ValidatorBond: Balance = 10_000 * AFT;
+    pub const UnbondingDelay: BlockNumber = 28 * DAYS;
+    pub const MaxSlashReasonLength: u32 = 256;
 }
 
 impl pallet_validators::Config for Runtime {
     type MaxValidators = MaxValidators;
+    type MaxProfileFieldLength = MaxProfileFieldLength;
+    type MaxBootnodes = MaxBootnodes;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type ValidatorAccountOf = ConvertInto;
+    type ValidatorBond = ValidatorBond;
+    type UnbondingDelay = UnbondingDelay;
+    type AdminOrigin = ValidatorAdminOrigin;
+    type SlashOrigin = ValidatorAdminOrigin;
+    /// Slashed bonds are sent to the treasury.
+    type Slash = ResolveTo<TreasuryAccount, Balances>;
+    type MaxSlashReasonLength = MaxSlashReasonLength;
     type WeightInfo = weights::validators::AllfeatWeight<Runtime>;
 }
@@ -1,4 +1,5 @@
-use crate::{Runtime, RuntimeBlockWeights, TransactionByteFee, WeightToFee, weights};
+use crate::{Runtime, RuntimeBlockWeights, WeightToFee, dynamic_params, weights};
+use frame_support::traits::Get;
 use frame_support::weights::{Weight, WeightToFee as WeightToFeeTrait};
 use frame_system::limits::BlockWeights;
 use shared_runtime::fee_estimator::{
@@ -36,23 +37,50 @@ fn print_fee_report() {
 
         let config = FeeReportConfig {
             base_weight: base_extrinsic_weight(),
-            byte_fee: TransactionByteFee::get(),
+            byte_fee: dynamic_params::fees::TransactionByteFee::get(),
             weight_to_fee_fn: weight_to_fee,
             min_multiplier_num: min_num,
             min_multiplier_den: min_den,
             max_multiplier_num: 10,
             max_multiplier_den: 1,
             aft_price_usd: DEFAULT_AFT_PRICE_USD,
+            parameters: vec![
+                (
+                    "TreasuryFeeShare",
+                    format!("{:?}", dynamic_params::fees::TreasuryFeeShare::get()),
+                ),
+                (
+                    "BurnFeeShare",
+                    format!("{:?}", dynamic_params::fees::BurnFeeShare::get()),
+                ),
+                (
+                    "TransactionByteFee",
+                    format_balance(dynamic_params::fees::TransactionByteFee::get()),
+                ),
+                (
+                    "WeightFeeFactor",
+                    format_balance(dynamic_params::fees::WeightFeeFactor::get()),
+                ),
+                (
+                    "EpochDuration",
+                    format!(
+                        "{} blocks",
+                        dynamic_params::token_allocation::EpochDuration::get()
+                    ),
+                ),
+            ],
         };
 
         use frame_system::WeightInfo as _;
         use pallet_ats::WeightInfo as _;
         use pallet_balances::WeightInfo as _;
+        use pallet_collective::WeightInfo as _;
+        use pallet_conviction_voting::WeightInfo as _;
         use pallet_multisig::WeightInfo as _;
         use pallet_preimage::WeightInfo as _;
         use pallet_proxy::WeightInfo as _;
+        use pallet_referenda::WeightInfo as _;
         use pallet_scheduler::WeightInfo as _;
-        use pallet_sudo::WeightInfo as _;
         use pallet_timestamp::WeightInfo as _;
         use pallet_token_allocation::WeightInfo as _;
         use pallet_treasury::WeightInfo as _;
@@ -69,7 +97,9 @@ fn print_fee_report() {
         type MultisigW = weights::multisig::AllfeatWeight<Runtime>;
         type TokenAllocationW = weights::token_allocation::AllfeatWeight<Runtime>;
         type TreasuryW = weights::treasury::AllfeatWeight<Runtime>;
-        type SudoW = weights::sudo::AllfeatWeight<Runtime>;
+        type CouncilW = pallet_collective::weights::SubstrateWeight<Runtime>;
+        type ReferendaW = pallet_referenda::weights::SubstrateWeight<Runtime>;
+        type ConvictionVotingW = pallet_conviction_voting::weights::SubstrateWeight<Runtime>;
         type ValidatorsW = weights::validators::AllfeatWeight<Runtime>;
         type AtsW = weights::ats::AllfeatWeight<Runtime>;
 
@@ -206,6 +236,13 @@ fn print_fee_report() {
                 encoded_len: 200,
                 deposit: 0,
             },
+            ExtrinsicFeeInfo {
+                pallet: "TokenAllocation",
+                extrinsic: "claim",
+                weight: TokenAllocationW::claim(),
+                encoded_len: 110,
+                deposit: 0,
+            },
             // Treasury
             ExtrinsicFeeInfo {
                 pallet: "Treasury",
@@ -214,12 +251,33 @@ fn print_fee_report() {
                 encoded_len: 150,
                 deposit: 0,
             },
-            // Sudo
+            // Governance
             ExtrinsicFeeInfo {
-                pallet: "Sudo",
-                extrinsic: "sudo",
-                weight: SudoW::sudo(),
-                encoded_len: 200,
+                pallet: "Council",
+                extrinsic: "propose",
+                weight: CouncilW::propose_proposed(200, 13, 100),
+                encoded_len: 250,
+                deposit: 0,
+            },
+            ExtrinsicFeeInfo {
+                pallet: "Council",
+                extrinsic: "vote",
+                weight: CouncilW::vote(13),
+                encoded_len: 100,
+                deposit: 0,
+            },
+            ExtrinsicFeeInfo {
+                pallet: "Referenda",
+                extrinsic: "submit",
+                weight: ReferendaW::submit(),
+                encoded_len: 150,
+                deposit: crate::SubmissionDeposit::get(),
+            },
+            ExtrinsicFeeInfo {
+                pallet: "ConvictionVoting",
+                extrinsic: "vote",
+                weight: ConvictionVotingW::vote_new(),
+                encoded_len: 100,
                 deposit: 0,
             },
             // Validators
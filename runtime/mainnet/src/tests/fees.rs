@@ -0,0 +1,74 @@
+use crate::{tests::new_test_ext, *};
+use frame_support::{assert_ok, pallet_prelude::Encode};
+use shared_runtime::currency::AFT;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{
+    Perbill,
+    generic::{Era, SignedPayload},
+};
+
+fn tx_extension() -> TxExtension {
+    (
+        frame_system::CheckNonZeroSender::new(),
+        frame_system::CheckSpecVersion::new(),
+        frame_system::CheckTxVersion::new(),
+        frame_system::CheckGenesis::new(),
+        frame_system::CheckEra::from(Era::Immortal),
+        frame_system::CheckNonce::from(0),
+        frame_system::CheckWeight::new(),
+        pallet_sponsorship::CheckSponsorship::new(),
+        pallet_transaction_payment::ChargeTransactionPayment::from(0),
+        frame_metadata_hash_extension::CheckMetadataHash::new(false),
+    )
+}
+
+#[test]
+fn charged_fees_burn_their_share_of_the_issuance() {
+    new_test_ext().execute_with(|| {
+        System::initialize(&1, &Default::default(), &Default::default());
+        assert_ok!(Parameters::set_parameter(
+            RuntimeOrigin::root(),
+            RuntimeParameters::Fees(dynamic_params::fees::Parameters::BurnFeeShare(
+                dynamic_params::fees::BurnFeeShare,
+                Some(Perbill::from_percent(10)),
+            ))
+        ));
+        let alice = Sr25519Keyring::Alice;
+        assert_ok!(Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            alice.to_account_id().into(),
+            100 * AFT
+        ));
+
+        let call = RuntimeCall::System(frame_system::Call::remark {
+            remark: vec![0; 64],
+        });
+        let payload = SignedPayload::new(call.clone(), tx_extension()).unwrap();
+        let signature = payload.using_encoded(|payload| alice.sign(payload));
+        let xt = UncheckedExtrinsic::new_signed(
+            call,
+            alice.to_account_id().into(),
+            signature.into(),
+            tx_extension(),
+        );
+        let issuance_before = Balances::total_issuance();
+
+        assert_eq!(RuntimeExecutive::apply_extrinsic(xt), Ok(Ok(())));
+
+        let actual_fee = System::events()
+            .into_iter()
+            .find_map(|record| match record.event {
+                RuntimeEvent::TransactionPayment(
+                    pallet_transaction_payment::Event::TransactionFeePaid { actual_fee, .. },
+                ) => Some(actual_fee),
+                _ => None,
+            })
+            .expect("the extrinsic is charged");
+        let burned = Perbill::from_percent(10) * actual_fee;
+        assert!(burned > 0);
+        assert_eq!(Balances::total_issuance(), issuance_before - burned);
+        System::assert_has_event(
+            pallet_fee_distribution::Event::<Runtime>::FeesBurned { amount: burned }.into(),
+        );
+    });
+}
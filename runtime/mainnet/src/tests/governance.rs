@@ -0,0 +1,146 @@
+use crate::{tests::new_test_ext, *};
+use frame_support::{
+    assert_ok,
+    storage::{storage_prefix, unhashed},
+    traits::{EnsureOrigin, OnRuntimeUpgrade, OriginTrait},
+};
+use pallet_referenda::TracksInfo as _;
+use sp_keyring::Sr25519Keyring;
+
+fn council(ayes: u32, total: u32) -> RuntimeOrigin {
+    pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(ayes, total).into()
+}
+
+fn track(origin: impl Into<RuntimeOrigin>) -> Result<u16, ()> {
+    TracksInfo::track_for(origin.into().caller())
+}
+
+fn sudo_key_location() -> [u8; 32] {
+    storage_prefix(b"Sudo", b"Key")
+}
+
+#[test]
+fn sudo_key_is_handed_over_to_the_council() {
+    new_test_ext().execute_with(|| {
+        let key = Sr25519Keyring::Alice.to_account_id();
+        unhashed::put(&sudo_key_location(), &key);
+
+        SudoToCouncil::on_runtime_upgrade();
+        RemoveSudo::on_runtime_upgrade();
+
+        assert_eq!(
+            pallet_membership::Members::<Runtime, CouncilMembershipInstance>::get().into_inner(),
+            vec![key.clone()]
+        );
+        assert_eq!(
+            pallet_collective::Members::<Runtime, CouncilCollective>::get(),
+            vec![key.clone()]
+        );
+        assert_eq!(
+            pallet_collective::Prime::<Runtime, CouncilCollective>::get(),
+            Some(key)
+        );
+        assert!(!unhashed::exists(&sudo_key_location()));
+    });
+}
+
+#[test]
+fn sudo_key_is_dropped_when_the_council_is_seated() {
+    new_test_ext().execute_with(|| {
+        let member = Sr25519Keyring::Bob.to_account_id();
+        assert_ok!(CouncilMembership::add_member(
+            RuntimeOrigin::root(),
+            member.clone().into()
+        ));
+        unhashed::put(&sudo_key_location(), &Sr25519Keyring::Alice.to_account_id());
+
+        SudoToCouncil::on_runtime_upgrade();
+
+        assert_eq!(
+            pallet_collective::Members::<Runtime, CouncilCollective>::get(),
+            vec![member]
+        );
+        assert!(!unhashed::exists(&sudo_key_location()));
+    });
+}
+
+#[test]
+fn privileged_origins_accept_root_their_track_and_the_council() {
+    new_test_ext().execute_with(|| {
+        let alice = Sr25519Keyring::Alice.to_account_id();
+
+        assert_ok!(ValidatorAdminOrigin::try_origin(RuntimeOrigin::root()));
+        assert_ok!(ValidatorAdminOrigin::try_origin(
+            pallet_custom_origins::Origin::ValidatorAdmin.into()
+        ));
+        assert_ok!(ValidatorAdminOrigin::try_origin(council(2, 3)));
+        assert!(ValidatorAdminOrigin::try_origin(council(1, 2)).is_err());
+        assert!(
+            ValidatorAdminOrigin::try_origin(pallet_custom_origins::Origin::Treasurer.into())
+                .is_err()
+        );
+        assert!(ValidatorAdminOrigin::try_origin(RuntimeOrigin::signed(alice)).is_err());
+
+        assert_ok!(AllocationAdminOrigin::try_origin(
+            pallet_custom_origins::Origin::AllocationAdmin.into()
+        ));
+        assert!(
+            AllocationAdminOrigin::try_origin(pallet_custom_origins::Origin::ValidatorAdmin.into())
+                .is_err()
+        );
+
+        assert_ok!(TreasuryRejectOrigin::try_origin(council(1, 2)));
+    });
+}
+
+#[test]
+fn treasury_spends_are_capped_per_origin() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            TreasurySpendOrigin::try_origin(RuntimeOrigin::root()).ok(),
+            Some(Balance::MAX)
+        );
+        assert_eq!(
+            TreasurySpendOrigin::try_origin(pallet_custom_origins::Origin::SmallSpender.into())
+                .ok(),
+            Some(SmallSpendLimit::get())
+        );
+        assert_eq!(
+            TreasurySpendOrigin::try_origin(pallet_custom_origins::Origin::MediumSpender.into())
+                .ok(),
+            Some(MediumSpendLimit::get())
+        );
+        assert_eq!(
+            TreasurySpendOrigin::try_origin(pallet_custom_origins::Origin::Treasurer.into()).ok(),
+            Some(TreasurerSpendLimit::get())
+        );
+        assert_eq!(
+            TreasurySpendOrigin::try_origin(council(3, 5)).ok(),
+            Some(CouncilSpendLimit::get())
+        );
+        assert!(TreasurySpendOrigin::try_origin(council(1, 2)).is_err());
+    });
+}
+
+#[test]
+fn every_governance_origin_has_a_track() {
+    use pallet_custom_origins::Origin;
+
+    assert_eq!(track(RuntimeOrigin::root()), Ok(0));
+    assert_eq!(track(Origin::WhitelistedCaller), Ok(1));
+    assert_eq!(track(Origin::ReferendumCanceller), Ok(2));
+    assert_eq!(track(Origin::ReferendumKiller), Ok(3));
+    assert_eq!(track(Origin::ValidatorAdmin), Ok(10));
+    assert_eq!(track(Origin::Treasurer), Ok(11));
+    assert_eq!(track(Origin::AllocationAdmin), Ok(12));
+    assert_eq!(track(Origin::SmallSpender), Ok(20));
+    assert_eq!(track(Origin::MediumSpender), Ok(21));
+    assert_eq!(track(council(1, 1)), Err(()));
+    assert_eq!(
+        track(RuntimeOrigin::signed(Sr25519Keyring::Alice.to_account_id())),
+        Err(())
+    );
+
+    let ids = TracksInfo::tracks().map(|t| t.id).collect::<Vec<_>>();
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+}
@@ -3,11 +3,19 @@ use sp_keyring::Sr25519Keyring;
 use sp_runtime::BuildStorage;
 
 pub mod fee_report;
+pub mod fees;
+pub mod governance;
+pub mod parameters;
+pub mod passkey;
+pub mod proxy;
+pub mod safe_mode;
+pub mod sponsorship;
 pub mod token;
+pub mod treasury;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    let sudo = Sr25519Keyring::Charlie.to_account_id();
-    let token_genesis = tokenomics(sudo, 0);
+    let council = Sr25519Keyring::Charlie.to_account_id();
+    let token_genesis = tokenomics(&[council], 0);
 
     let mut t = frame_system::GenesisConfig::<Runtime>::default()
         .build_storage()
@@ -0,0 +1,90 @@
+use crate::{tests::new_test_ext, *};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use shared_runtime::currency::MICROAFT;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{DispatchError, Perbill};
+
+fn council(ayes: u32, total: u32) -> RuntimeOrigin {
+    pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(ayes, total).into()
+}
+
+fn treasury_fee_share(share: Perbill) -> RuntimeParameters {
+    RuntimeParameters::Fees(dynamic_params::fees::Parameters::TreasuryFeeShare(
+        dynamic_params::fees::TreasuryFeeShare,
+        Some(share),
+    ))
+}
+
+fn epoch_duration(blocks: BlockNumber) -> RuntimeParameters {
+    RuntimeParameters::TokenAllocation(dynamic_params::token_allocation::Parameters::EpochDuration(
+        dynamic_params::token_allocation::EpochDuration,
+        Some(blocks),
+    ))
+}
+
+#[test]
+fn parameters_default_to_previous_constants() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            dynamic_params::fees::TreasuryFeeShare::get(),
+            Perbill::from_percent(20)
+        );
+        assert_eq!(
+            dynamic_params::fees::TransactionByteFee::get(),
+            10 * MICROAFT
+        );
+        assert_eq!(dynamic_params::token_allocation::EpochDuration::get(), DAYS);
+    });
+}
+
+#[test]
+fn council_supermajority_sets_fee_parameters() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Parameters::set_parameter(council(1, 2), treasury_fee_share(Perbill::from_percent(50))),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Parameters::set_parameter(
+                RuntimeOrigin::signed(Sr25519Keyring::Charlie.to_account_id()),
+                treasury_fee_share(Perbill::from_percent(50))
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Parameters::set_parameter(
+            council(2, 3),
+            treasury_fee_share(Perbill::from_percent(50))
+        ));
+        assert_eq!(
+            dynamic_params::fees::TreasuryFeeShare::get(),
+            Perbill::from_percent(50)
+        );
+    });
+}
+
+#[test]
+fn allocation_admin_sets_epoch_duration() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Parameters::set_parameter(council(1, 2), epoch_duration(HOURS)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Parameters::set_parameter(
+                pallet_custom_origins::Origin::ValidatorAdmin.into(),
+                epoch_duration(HOURS)
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Parameters::set_parameter(
+            pallet_custom_origins::Origin::AllocationAdmin.into(),
+            epoch_duration(HOURS)
+        ));
+        assert_eq!(
+            dynamic_params::token_allocation::EpochDuration::get(),
+            HOURS
+        );
+    });
+}
@@ -0,0 +1,158 @@
+use crate::{tests::new_test_ext, *};
+use allfeat_primitives::WebAuthnSignature;
+use frame_support::{assert_ok, pallet_prelude::Encode};
+use shared_runtime::currency::AFT;
+use sp_core::hashing::{blake2_256, sha2_256};
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{
+    generic::{Era, SignedPayload},
+    traits::{IdentifyAccount, TransactionExtension},
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+fn secret() -> [u8; 32] {
+    sha2_256(b"allfeat passkey test key")
+}
+
+fn passkey_account() -> AccountId {
+    WebAuthnSignature::sign(&secret(), &[])
+        .public
+        .into_account()
+}
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark_with_event {
+        remark: b"signed with a passkey".to_vec(),
+    })
+}
+
+fn tx_extension() -> TxExtension {
+    (
+        frame_system::CheckNonZeroSender::new(),
+        frame_system::CheckSpecVersion::new(),
+        frame_system::CheckTxVersion::new(),
+        frame_system::CheckGenesis::new(),
+        frame_system::CheckEra::from(Era::Immortal),
+        frame_system::CheckNonce::from(0),
+        frame_system::CheckWeight::new(),
+        pallet_sponsorship::CheckSponsorship::new(),
+        pallet_transaction_payment::ChargeTransactionPayment::from(0),
+        frame_metadata_hash_extension::CheckMetadataHash::new(false),
+    )
+}
+
+/// A new block with the passkey account funded.
+fn setup() {
+    System::initialize(&1, &Default::default(), &Default::default());
+    assert_ok!(Balances::force_set_balance(
+        RuntimeOrigin::root(),
+        passkey_account().into(),
+        100 * AFT
+    ));
+}
+
+fn remarked_by(sender: &AccountId) -> bool {
+    System::events().iter().any(|record| {
+        matches!(
+            &record.event,
+            RuntimeEvent::System(frame_system::Event::Remarked { sender: s, .. }) if s == sender
+        )
+    })
+}
+
+#[test]
+fn passkey_signed_extrinsic_is_applied() {
+    new_test_ext().execute_with(|| {
+        setup();
+        let payload = SignedPayload::new(remark(), tx_extension()).unwrap();
+        let signature =
+            payload.using_encoded(|payload| WebAuthnSignature::sign(&secret(), payload));
+        let xt = UncheckedExtrinsic::new_signed(
+            remark(),
+            passkey_account().into(),
+            signature.into(),
+            tx_extension(),
+        );
+
+        assert_eq!(RuntimeExecutive::apply_extrinsic(xt), Ok(Ok(())));
+        assert!(remarked_by(&passkey_account()));
+        assert_eq!(System::account_nonce(passkey_account()), 1);
+    });
+}
+
+#[test]
+fn extrinsic_signed_by_another_passkey_is_rejected() {
+    new_test_ext().execute_with(|| {
+        setup();
+        let payload = SignedPayload::new(remark(), tx_extension()).unwrap();
+        let signature = payload
+            .using_encoded(|payload| WebAuthnSignature::sign(&blake2_256(b"other"), payload));
+        let xt = UncheckedExtrinsic::new_signed(
+            remark(),
+            passkey_account().into(),
+            signature.into(),
+            tx_extension(),
+        );
+
+        assert_eq!(
+            RuntimeExecutive::apply_extrinsic(xt),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::BadProof
+            ))
+        );
+    });
+}
+
+#[test]
+fn passkey_signed_meta_tx_is_relayed() {
+    new_test_ext().execute_with(|| {
+        setup();
+        let bare: MetaTxBareExtension = (
+            MetaTxMarker::new(),
+            frame_system::CheckNonZeroSender::new(),
+            frame_system::CheckSpecVersion::new(),
+            frame_system::CheckTxVersion::new(),
+            frame_system::CheckGenesis::new(),
+            frame_system::CheckMortality::from(Era::Immortal),
+            frame_system::CheckNonce::from(0),
+        );
+        // `VerifySignature` checks the hash of the extension version, the call and the rest of
+        // the extension with its implicit data.
+        let msg = blake2_256(
+            &(
+                META_EXTENSION_VERSION,
+                remark(),
+                &bare,
+                bare.implicit().unwrap(),
+            )
+                .encode(),
+        );
+        let signature = WebAuthnSignature::sign(&secret(), &msg);
+        let extension: MetaTxExtension = (
+            pallet_verify_signature::VerifySignature::new_with_signature(
+                signature.into(),
+                passkey_account(),
+            ),
+            bare,
+        );
+        let meta_tx = pallet_meta_tx::MetaTx::new(remark(), META_EXTENSION_VERSION, extension);
+
+        let relayer = Sr25519Keyring::Charlie;
+        let relay = prepare_meta_tx_relay(
+            meta_tx.encode(),
+            System::account_nonce(relayer.to_account_id()),
+            Era::Immortal,
+            Default::default(),
+        )
+        .unwrap();
+        assert_eq!(relay.signer, passkey_account());
+
+        let signature = Signature::Sr25519(relayer.sign(&relay.payload));
+        let xt = meta_tx_relay_extrinsic(relay, relayer.to_account_id(), signature).unwrap();
+
+        assert_eq!(RuntimeExecutive::apply_extrinsic(xt), Ok(Ok(())));
+        assert!(remarked_by(&passkey_account()));
+        assert_eq!(System::account_nonce(passkey_account()), 1);
+        assert_eq!(System::account_nonce(relayer.to_account_id()), 1);
+    });
+}
@@ -0,0 +1,245 @@
+use crate::*;
+use frame_support::traits::InstanceFilter;
+use sp_core::H256;
+use sp_keyring::Sr25519Keyring;
+
+const ALL: [ProxyType; 7] = [
+    ProxyType::Any,
+    ProxyType::NonTransfer,
+    ProxyType::Registrar,
+    ProxyType::Governance,
+    ProxyType::Validator,
+    ProxyType::Allocation,
+    ProxyType::CancelProxy,
+];
+
+fn bob() -> AccountId {
+    Sr25519Keyring::Bob.to_account_id()
+}
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn batch(calls: Vec<RuntimeCall>) -> RuntimeCall {
+    RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+}
+
+fn as_derivative(call: RuntimeCall) -> RuntimeCall {
+    RuntimeCall::Utility(pallet_utility::Call::as_derivative {
+        index: 0,
+        call: Box::new(call),
+    })
+}
+
+fn transfer() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: bob().into(),
+        value: 1,
+    })
+}
+
+fn asset_transfer() -> RuntimeCall {
+    RuntimeCall::Assets(pallet_assets::Call::transfer {
+        id: 1u32.into(),
+        target: bob().into(),
+        amount: 1,
+    })
+}
+
+fn multisig() -> RuntimeCall {
+    RuntimeCall::Multisig(pallet_multisig::Call::as_multi_threshold_1 {
+        other_signatories: vec![bob()],
+        call: Box::new(remark()),
+    })
+}
+
+fn proxy() -> RuntimeCall {
+    RuntimeCall::Proxy(pallet_proxy::Call::proxy {
+        real: bob().into(),
+        force_proxy_type: None,
+        call: Box::new(remark()),
+    })
+}
+
+fn reject_announcement() -> RuntimeCall {
+    RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement {
+        delegate: bob().into(),
+        call_hash: H256::zero(),
+    })
+}
+
+fn council_vote() -> RuntimeCall {
+    RuntimeCall::Council(pallet_collective::Call::vote {
+        proposal: H256::zero(),
+        index: 0,
+        approve: true,
+    })
+}
+
+fn referendum_vote() -> RuntimeCall {
+    RuntimeCall::ConvictionVoting(pallet_conviction_voting::Call::remove_vote {
+        class: None,
+        index: 0,
+    })
+}
+
+fn purge_keys() -> RuntimeCall {
+    RuntimeCall::Session(pallet_session::Call::purge_keys {})
+}
+
+fn clear_operator_profile() -> RuntimeCall {
+    RuntimeCall::Validators(pallet_validators::Call::clear_operator_profile {})
+}
+
+fn add_validator() -> RuntimeCall {
+    RuntimeCall::Validators(pallet_validators::Call::add_validator { validator: bob() })
+}
+
+fn claim() -> RuntimeCall {
+    RuntimeCall::TokenAllocation(pallet_token_allocation::Call::claim { id: 0 })
+}
+
+fn add_beneficiary() -> RuntimeCall {
+    RuntimeCall::Sponsorship(pallet_sponsorship::Call::add_beneficiary { beneficiary: bob() })
+}
+
+fn remove_sponsorship() -> RuntimeCall {
+    RuntimeCall::Sponsorship(pallet_sponsorship::Call::remove_sponsorship {})
+}
+
+fn transfer_allocation() -> RuntimeCall {
+    RuntimeCall::TokenAllocation(pallet_token_allocation::Call::transfer_allocation {
+        id: 0,
+        to: bob(),
+    })
+}
+
+#[test]
+fn non_transfer_proxy_cannot_move_funds() {
+    let p = ProxyType::NonTransfer;
+
+    for call in [
+        transfer(),
+        asset_transfer(),
+        multisig(),
+        proxy(),
+        transfer_allocation(),
+        add_beneficiary(),
+        remove_sponsorship(),
+    ] {
+        assert!(!p.filter(&call), "{call:?} should be filtered");
+    }
+    for call in [
+        remark(),
+        council_vote(),
+        claim(),
+        purge_keys(),
+        reject_announcement(),
+    ] {
+        assert!(p.filter(&call), "{call:?} should pass");
+    }
+}
+
+#[test]
+fn governance_proxy_only_votes() {
+    let p = ProxyType::Governance;
+
+    assert!(p.filter(&council_vote()));
+    assert!(p.filter(&referendum_vote()));
+    assert!(p.filter(&batch(vec![council_vote()])));
+    assert!(!p.filter(&transfer()));
+    assert!(!p.filter(&remark()));
+    assert!(!p.filter(&claim()));
+}
+
+#[test]
+fn validator_proxy_only_manages_keys_and_profile() {
+    let p = ProxyType::Validator;
+
+    assert!(p.filter(&purge_keys()));
+    assert!(p.filter(&clear_operator_profile()));
+    assert!(!p.filter(&add_validator()));
+    assert!(!p.filter(&transfer()));
+    assert!(!p.filter(&council_vote()));
+}
+
+#[test]
+fn allocation_proxy_only_claims() {
+    let p = ProxyType::Allocation;
+
+    assert!(p.filter(&claim()));
+    assert!(!p.filter(&transfer_allocation()));
+    assert!(!p.filter(&transfer()));
+    assert!(!p.filter(&referendum_vote()));
+}
+
+#[test]
+fn registrar_proxy_cannot_touch_anything_else() {
+    let p = ProxyType::Registrar;
+
+    for call in [
+        transfer(),
+        remark(),
+        council_vote(),
+        claim(),
+        purge_keys(),
+        proxy(),
+    ] {
+        assert!(!p.filter(&call), "{call:?} should be filtered");
+    }
+}
+
+#[test]
+fn narrow_proxies_cannot_dispatch_as_derivative() {
+    assert!(!ProxyType::Registrar.filter(&as_derivative(remark())));
+    assert!(!ProxyType::Allocation.filter(&as_derivative(claim())));
+    for p in &ALL[2..] {
+        assert!(p.filter(&batch(vec![])), "{p:?} should batch");
+        assert!(
+            !p.filter(&as_derivative(remark())),
+            "{p:?} should not derive"
+        );
+    }
+}
+
+#[test]
+fn cancel_proxy_only_rejects_announcements() {
+    let p = ProxyType::CancelProxy;
+
+    assert!(p.filter(&reject_announcement()));
+    assert!(!p.filter(&proxy()));
+    assert!(!p.filter(&transfer()));
+}
+
+#[test]
+fn superset_follows_the_filters() {
+    for p in ALL {
+        assert!(ProxyType::Any.is_superset(&p));
+        assert!(p.is_superset(&p));
+        assert!(ProxyType::NonTransfer.is_superset(&p) || p == ProxyType::Any);
+    }
+    for p in &ALL[2..] {
+        assert!(!p.is_superset(&ProxyType::Any));
+        assert!(!p.is_superset(&ProxyType::NonTransfer));
+        for o in &ALL[2..] {
+            assert_eq!(p.is_superset(o), p == o);
+        }
+    }
+
+    // Every call a narrow proxy can make is also allowed to a `NonTransfer` proxy.
+    let calls = [
+        council_vote(),
+        referendum_vote(),
+        purge_keys(),
+        clear_operator_profile(),
+        claim(),
+        reject_announcement(),
+        batch(vec![]),
+    ];
+    for p in &ALL[2..] {
+        for call in calls.iter().filter(|c| p.filter(c)) {
+            assert!(ProxyType::NonTransfer.filter(call), "{p:?} allows {call:?}");
+        }
+    }
+}
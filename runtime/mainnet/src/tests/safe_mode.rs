@@ -0,0 +1,107 @@
+use crate::{tests::new_test_ext, *};
+use frame_support::assert_ok;
+use shared_runtime::currency::AFT;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{DispatchError, traits::Dispatchable};
+
+fn charlie() -> RuntimeOrigin {
+    RuntimeOrigin::signed(Sr25519Keyring::Charlie.to_account_id())
+}
+
+fn council(ayes: u32, total: u32) -> RuntimeOrigin {
+    pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(ayes, total).into()
+}
+
+fn transfer_keep_alive() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: Sr25519Keyring::Bob.to_account_id().into(),
+        value: AFT,
+    })
+}
+
+fn transfer_allow_death() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+        dest: Sr25519Keyring::Bob.to_account_id().into(),
+        value: AFT,
+    })
+}
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![1] })
+}
+
+fn dispatch(call: RuntimeCall) -> Result<(), DispatchError> {
+    call.dispatch(charlie()).map(|_| ()).map_err(|e| e.error)
+}
+
+fn call_name(pallet: &[u8], call: &[u8]) -> pallet_tx_pause::RuntimeCallNameOf<Runtime> {
+    (
+        pallet.to_vec().try_into().unwrap(),
+        call.to_vec().try_into().unwrap(),
+    )
+}
+
+#[test]
+fn balances_transfers_are_blocked_in_safe_mode() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(dispatch(transfer_keep_alive()));
+
+        assert_ok!(SafeMode::force_enter(RuntimeOrigin::root()));
+
+        let filtered = frame_system::Error::<Runtime>::CallFiltered.into();
+        assert_eq!(dispatch(transfer_keep_alive()), Err(filtered));
+        assert_eq!(dispatch(transfer_allow_death()), Err(filtered));
+        // The whitelisted pallets stay usable.
+        assert_ok!(dispatch(remark()));
+
+        assert_ok!(SafeMode::force_exit(council(1, 2)));
+        assert_ok!(dispatch(transfer_keep_alive()));
+    });
+}
+
+#[test]
+fn half_of_the_council_can_enter_safe_mode() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_eq!(
+            SafeMode::force_enter(charlie()),
+            Err(DispatchError::BadOrigin)
+        );
+        assert!(SafeMode::force_enter(council(1, 3)).is_err());
+        assert_ok!(SafeMode::force_enter(council(1, 2)));
+
+        assert_eq!(
+            pallet_safe_mode::EnteredUntil::<Runtime>::get(),
+            Some(1 + 4 * HOURS)
+        );
+    });
+}
+
+#[test]
+fn paused_calls_are_blocked() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(TxPause::pause(
+            RuntimeOrigin::root(),
+            call_name(b"Balances", b"transfer_keep_alive")
+        ));
+
+        assert_eq!(
+            dispatch(transfer_keep_alive()),
+            Err(frame_system::Error::<Runtime>::CallFiltered.into())
+        );
+        assert_ok!(dispatch(transfer_allow_death()));
+
+        assert_eq!(
+            TxPause::pause(RuntimeOrigin::root(), call_name(b"System", b"remark")),
+            Err(pallet_tx_pause::Error::<Runtime>::Unpausable.into())
+        );
+        assert_eq!(
+            TxPause::pause(RuntimeOrigin::root(), call_name(b"Referenda", b"submit")),
+            Err(pallet_tx_pause::Error::<Runtime>::Unpausable.into())
+        );
+    });
+}
@@ -0,0 +1,196 @@
+use crate::{tests::new_test_ext, *};
+use frame_support::{
+    assert_ok,
+    dispatch::GetDispatchInfo,
+    pallet_prelude::{Decode, Encode},
+    traits::InstanceFilter,
+};
+use pallet_sponsorship::{CheckSponsorship, InspectRelayedCall, SponsorshipError};
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{
+    generic::Era,
+    traits::TransactionExtension,
+    transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+};
+
+fn sponsor() -> AccountId {
+    Sr25519Keyring::Charlie.to_account_id()
+}
+
+fn beneficiary() -> AccountId {
+    Sr25519Keyring::Dave.to_account_id()
+}
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn transfer() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: Sr25519Keyring::Bob.to_account_id().into(),
+        value: 1,
+    })
+}
+
+fn meta_tx_extension(signer: AccountId) -> MetaTxExtension {
+    (
+        pallet_verify_signature::VerifySignature::new_with_signature(
+            Signature::Sr25519(sp_core::sr25519::Signature::from_raw([0; 64])),
+            signer,
+        ),
+        (
+            MetaTxMarker::new(),
+            frame_system::CheckNonZeroSender::new(),
+            frame_system::CheckSpecVersion::new(),
+            frame_system::CheckTxVersion::new(),
+            frame_system::CheckGenesis::new(),
+            frame_system::CheckMortality::from(Era::Immortal),
+            frame_system::CheckNonce::from(0),
+        ),
+    )
+}
+
+fn meta_tx(signer: AccountId, call: RuntimeCall) -> RuntimeCall {
+    RuntimeCall::MetaTx(pallet_meta_tx::Call::dispatch {
+        meta_tx: Box::new(pallet_meta_tx::MetaTx::new(
+            call,
+            META_EXTENSION_VERSION,
+            meta_tx_extension(signer),
+        )),
+    })
+}
+
+fn validate(relayer: AccountId, call: RuntimeCall) -> Result<(), InvalidTransaction> {
+    let info = call.get_dispatch_info();
+    CheckSponsorship::<Runtime>::new()
+        .validate_only(
+            RuntimeOrigin::signed(relayer),
+            &call,
+            &info,
+            100,
+            TransactionSource::External,
+            0,
+        )
+        .map(|_| ())
+        .map_err(|e| match e {
+            TransactionValidityError::Invalid(e) => e,
+            e => panic!("unexpected error: {e:?}"),
+        })
+}
+
+fn sponsorship_relayed(call: RuntimeCall) -> Option<(AccountId, RuntimeCall)> {
+    <Runtime as pallet_sponsorship::Config>::RelayedCall::inspect(&call)
+}
+
+#[test]
+fn sponsored_calls_filter() {
+    assert!(!SponsoredCalls::Ats.filter(&remark()));
+    assert!(!SponsoredCalls::Ats.filter(&transfer()));
+    assert!(SponsoredCalls::Any.filter(&remark()));
+    assert!(SponsoredCalls::Any.is_superset(&SponsoredCalls::Ats));
+    assert!(!SponsoredCalls::Ats.is_superset(&SponsoredCalls::Any));
+}
+
+/// `RelayedMetaTx` and `prepare_meta_tx_relay` rely on `MetaTx` being encoded as its call,
+/// extension version and extension, in this order.
+#[test]
+fn meta_tx_is_encoded_as_call_version_and_extension() {
+    let fields = (
+        remark(),
+        META_EXTENSION_VERSION,
+        meta_tx_extension(beneficiary()),
+    );
+    let meta_tx = pallet_meta_tx::MetaTx::new(
+        remark(),
+        META_EXTENSION_VERSION,
+        meta_tx_extension(beneficiary()),
+    );
+    assert_eq!(meta_tx.encode(), fields.encode());
+
+    let decoded =
+        pallet_meta_tx::MetaTx::<RuntimeCall, MetaTxExtension>::decode(&mut &fields.encode()[..])
+            .unwrap();
+    assert_eq!(decoded.encode(), fields.encode());
+}
+
+#[test]
+fn meta_tx_signer_and_call_are_read() {
+    let relayed = sponsorship_relayed(meta_tx(beneficiary(), remark()));
+    assert_eq!(relayed, Some((beneficiary(), remark())));
+    assert_eq!(sponsorship_relayed(remark()), None);
+}
+
+#[test]
+fn invalid_meta_tx_are_not_relayed() {
+    new_test_ext().execute_with(|| {
+        let RuntimeCall::MetaTx(pallet_meta_tx::Call::dispatch { meta_tx }) =
+            meta_tx(beneficiary(), remark())
+        else {
+            unreachable!()
+        };
+        let relay =
+            |meta_tx: Vec<u8>| prepare_meta_tx_relay(meta_tx, 0, Era::Immortal, Default::default());
+
+        assert_eq!(
+            relay(meta_tx.encode()),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::BadProof
+            ))
+        );
+        assert_eq!(
+            relay(vec![1, 2, 3]),
+            Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+        );
+    });
+}
+
+#[test]
+fn sponsor_relays_are_checked() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Sponsorship::set_sponsorship(
+            RuntimeOrigin::signed(sponsor()),
+            SponsoredCalls::Ats,
+            u128::MAX
+        ));
+        assert_ok!(Sponsorship::add_beneficiary(
+            RuntimeOrigin::signed(sponsor()),
+            beneficiary()
+        ));
+
+        assert_eq!(
+            validate(sponsor(), meta_tx(beneficiary(), remark())),
+            Err(SponsorshipError::CallNotSponsored.into())
+        );
+
+        assert_ok!(Sponsorship::set_sponsorship(
+            RuntimeOrigin::signed(sponsor()),
+            SponsoredCalls::Any,
+            u128::MAX
+        ));
+        assert_ok!(validate(sponsor(), meta_tx(beneficiary(), remark())));
+        assert_eq!(
+            validate(
+                sponsor(),
+                meta_tx(Sr25519Keyring::Eve.to_account_id(), remark())
+            ),
+            Err(SponsorshipError::NotBeneficiary.into())
+        );
+
+        // Relays of other accounts keep working as before.
+        assert_ok!(validate(
+            Sr25519Keyring::Eve.to_account_id(),
+            meta_tx(Sr25519Keyring::Ferdie.to_account_id(), transfer())
+        ));
+
+        assert_ok!(Sponsorship::set_sponsorship(
+            RuntimeOrigin::signed(sponsor()),
+            SponsoredCalls::Any,
+            0
+        ));
+        assert_eq!(
+            validate(sponsor(), meta_tx(beneficiary(), remark())),
+            Err(SponsorshipError::BudgetExhausted.into())
+        );
+        assert_eq!(Sponsorship::remaining_budget(&sponsor()), Some(0));
+    });
+}
@@ -118,9 +118,10 @@ fn e2e_vesting_schedule_private2() {
         let check_block = 3 * MONTHS + 15 * MONTHS;
         jump_to(check_block);
 
-        // Force manual payout trigger
-        pallet_token_allocation::NextPayoutAt::<Runtime>::put(check_block);
-        pallet_token_allocation::Pallet::<Runtime>::on_initialize(check_block);
+        assert_ok!(pallet_token_allocation::Pallet::<Runtime>::claim(
+            RuntimeOrigin::signed(alice.clone()),
+            alloc_id,
+        ));
 
         // Reload allocation
         let alloc_updated = pallet_token_allocation::Allocations::<Runtime>::get(alloc_id).unwrap();
@@ -146,8 +147,11 @@ fn e2e_vesting_schedule_private2() {
         // --- Check Completion ---
         let end_block = 3 * MONTHS + 36 * MONTHS + MONTHS;
 
-        pallet_token_allocation::NextPayoutAt::<Runtime>::put(end_block);
-        pallet_token_allocation::Pallet::<Runtime>::on_initialize(end_block);
+        jump_to(end_block);
+        assert_ok!(pallet_token_allocation::Pallet::<Runtime>::claim(
+            RuntimeOrigin::signed(alice.clone()),
+            alloc_id,
+        ));
 
         assert!(
             pallet_token_allocation::Allocations::<Runtime>::get(alloc_id).is_none(),
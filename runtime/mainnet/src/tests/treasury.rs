@@ -0,0 +1,115 @@
+use crate::{tests::new_test_ext, *};
+use frame_support::{assert_noop, assert_ok, traits::tokens::fungible::NativeOrWithId};
+use shared_runtime::currency::AFT;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::FixedU128;
+
+const USD: AssetId = 1;
+
+fn register_stable_asset(rate: u32) {
+    let owner = Sr25519Keyring::Charlie.to_account_id();
+    assert_ok!(Assets::force_create(
+        RuntimeOrigin::root(),
+        USD.into(),
+        owner.clone().into(),
+        true,
+        1
+    ));
+    assert_ok!(Assets::mint(
+        RuntimeOrigin::signed(owner),
+        USD.into(),
+        Treasury::account_id().into(),
+        1_000_000 * AFT
+    ));
+    assert_ok!(AssetRate::create(
+        RuntimeOrigin::root(),
+        Box::new(USD),
+        FixedU128::from_u32(rate)
+    ));
+}
+
+#[test]
+fn grants_are_paid_in_a_registered_asset() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        register_stable_asset(1);
+        let artist = Sr25519Keyring::Dave.to_account_id();
+
+        assert_ok!(Treasury::spend(
+            pallet_custom_origins::Origin::SmallSpender.into(),
+            Box::new(NativeOrWithId::WithId(USD)),
+            5_000 * AFT,
+            Box::new(artist.clone()),
+            None
+        ));
+        assert_ok!(Treasury::payout(RuntimeOrigin::signed(artist.clone()), 0));
+
+        assert_eq!(Assets::balance(USD, &artist), 5_000 * AFT);
+    });
+}
+
+#[test]
+fn asset_spends_are_capped_by_their_aft_value() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        // One unit of the asset is worth two AFT.
+        register_stable_asset(2);
+        let artist = Sr25519Keyring::Dave.to_account_id();
+
+        assert_noop!(
+            Treasury::spend(
+                pallet_custom_origins::Origin::SmallSpender.into(),
+                Box::new(NativeOrWithId::WithId(USD)),
+                SmallSpendLimit::get() / 2 + 1,
+                Box::new(artist.clone()),
+                None
+            ),
+            pallet_treasury::Error::<Runtime>::InsufficientPermission
+        );
+        assert_ok!(Treasury::spend(
+            pallet_custom_origins::Origin::MediumSpender.into(),
+            Box::new(NativeOrWithId::WithId(USD)),
+            SmallSpendLimit::get() / 2 + 1,
+            Box::new(artist.clone()),
+            None
+        ));
+
+        // Assets without a rate cannot be spent.
+        assert_noop!(
+            Treasury::spend(
+                RuntimeOrigin::root(),
+                Box::new(NativeOrWithId::WithId(USD + 1)),
+                AFT,
+                Box::new(artist),
+                None
+            ),
+            pallet_treasury::Error::<Runtime>::FailedToConvertBalance
+        );
+    });
+}
+
+#[test]
+fn spender_tracks_can_approve_bounties() {
+    new_test_ext().execute_with(|| {
+        let proposer = Sr25519Keyring::Charlie.to_account_id();
+
+        assert_ok!(Bounties::propose_bounty(
+            RuntimeOrigin::signed(proposer),
+            100 * AFT,
+            b"Mastering toolkit for independent artists".to_vec()
+        ));
+        assert_noop!(
+            Bounties::approve_bounty(RuntimeOrigin::signed(Treasury::account_id()), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Bounties::approve_bounty(
+            pallet_custom_origins::Origin::SmallSpender.into(),
+            0
+        ));
+
+        assert_eq!(
+            pallet_bounties::BountyApprovals::<Runtime>::get().into_inner(),
+            vec![0]
+        );
+    });
+}
@@ -0,0 +1,114 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_bounties`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN ESTIMATES, NOT BENCHMARK RESULTS. They are derived from the
+//! storage accessed by each call and must be replaced with the output of the pallet benchmarks
+//! before the next runtime release:
+//!
+//! frame-omni-bencher v1 benchmark pallet \
+//!     --runtime target/release/wbuild/<runtime>/<runtime>.compact.compressed.wasm \
+//!     --genesis-builder-preset=staging --pallet=pallet_bounties --extrinsic=* \
+//!     --steps=50 --repeat=20 --wasm-execution=compiled --heap-pages=4096 \
+//!     --header=HEADER --template=.maintain/runtimes-weight-template.hbs \
+//!     --output=runtime/mainnet/src/weights/bounties.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_bounties::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	fn propose_bounty(d: u32, ) -> Weight {
+		Weight::from_parts(32_000_000, 3593)
+			.saturating_add(Weight::from_parts(800, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn approve_bounty() -> Weight {
+		Weight::from_parts(17_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn propose_curator() -> Weight {
+		Weight::from_parts(15_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn unassign_curator() -> Weight {
+		Weight::from_parts(50_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn accept_curator() -> Weight {
+		Weight::from_parts(40_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn award_bounty() -> Weight {
+		Weight::from_parts(25_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn claim_bounty() -> Weight {
+		Weight::from_parts(115_000_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	fn close_bounty_proposed() -> Weight {
+		Weight::from_parts(50_000_000, 3827)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn close_bounty_active() -> Weight {
+		Weight::from_parts(85_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn extend_bounty_expiry() -> Weight {
+		Weight::from_parts(18_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn spend_funds(b: u32, ) -> Weight {
+		Weight::from_parts(4_000_000, 1887)
+			.saturating_add(Weight::from_parts(42_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 7774).saturating_mul(b.into()))
+	}
+	fn poke_deposit() -> Weight {
+		Weight::from_parts(35_000_000, 3807)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn approve_bounty_with_curator() -> Weight {
+		Weight::from_parts(20_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
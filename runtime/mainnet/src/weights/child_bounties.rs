@@ -0,0 +1,85 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_child_bounties`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN ESTIMATES, NOT BENCHMARK RESULTS. They are derived from the
+//! storage accessed by each call and must be replaced with the output of the pallet benchmarks
+//! before the next runtime release:
+//!
+//! frame-omni-bencher v1 benchmark pallet \
+//!     --runtime target/release/wbuild/<runtime>/<runtime>.compact.compressed.wasm \
+//!     --genesis-builder-preset=staging --pallet=pallet_child_bounties --extrinsic=* \
+//!     --steps=50 --repeat=20 --wasm-execution=compiled --heap-pages=4096 \
+//!     --header=HEADER --template=.maintain/runtimes-weight-template.hbs \
+//!     --output=runtime/mainnet/src/weights/child_bounties.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_child_bounties::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	fn add_child_bounty(d: u32, ) -> Weight {
+		Weight::from_parts(75_000_000, 6196)
+			.saturating_add(Weight::from_parts(800, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn propose_curator() -> Weight {
+		Weight::from_parts(22_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn accept_curator() -> Weight {
+		Weight::from_parts(42_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn unassign_curator() -> Weight {
+		Weight::from_parts(55_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn award_child_bounty() -> Weight {
+		Weight::from_parts(28_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn claim_child_bounty() -> Weight {
+		Weight::from_parts(115_000_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn close_child_bounty_added() -> Weight {
+		Weight::from_parts(95_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn close_child_bounty_active() -> Weight {
+		Weight::from_parts(115_000_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+}
@@ -0,0 +1,149 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_collective`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN ESTIMATES, NOT BENCHMARK RESULTS. They are derived from the
+//! storage accessed by each call and must be replaced with the output of the pallet benchmarks
+//! before the next runtime release:
+//!
+//! frame-omni-bencher v1 benchmark pallet \
+//!     --runtime target/release/wbuild/<runtime>/<runtime>.compact.compressed.wasm \
+//!     --genesis-builder-preset=staging --pallet=pallet_collective --extrinsic=* \
+//!     --steps=50 --repeat=20 --wasm-execution=compiled --heap-pages=4096 \
+//!     --header=HEADER --template=.maintain/runtimes-weight-template.hbs \
+//!     --output=runtime/mainnet/src/weights/collective.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_collective::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	fn set_members(m: u32, n: u32, p: u32, ) -> Weight {
+		Weight::from_parts(16_000_000, 15894)
+			.saturating_add(Weight::from_parts(2_100_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(20_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(4_200_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2021).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 5242).saturating_mul(p.into()))
+	}
+	fn execute(b: u32, m: u32, ) -> Weight {
+		Weight::from_parts(16_500_000, 3997)
+			.saturating_add(Weight::from_parts(1_600, 0).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(18_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(m.into()))
+	}
+	fn propose_execute(b: u32, m: u32, ) -> Weight {
+		Weight::from_parts(19_000_000, 3977)
+			.saturating_add(Weight::from_parts(1_600, 0).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(30_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(m.into()))
+	}
+	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight {
+		Weight::from_parts(28_000_000, 3918)
+			.saturating_add(Weight::from_parts(3_400, 0).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(30_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(230_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 33).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
+	}
+	fn vote(m: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 4440)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 64).saturating_mul(m.into()))
+	}
+	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
+		Weight::from_parts(33_000_000, 4020)
+			.saturating_add(Weight::from_parts(45_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(180_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 64).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 39).saturating_mul(p.into()))
+	}
+	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 4350)
+			.saturating_add(Weight::from_parts(3_000, 0).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(45_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(210_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(0, 66).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
+	}
+	fn close_disapproved(m: u32, p: u32, ) -> Weight {
+		Weight::from_parts(36_000_000, 4100)
+			.saturating_add(Weight::from_parts(45_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(180_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 65).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 39).saturating_mul(p.into()))
+	}
+	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
+		Weight::from_parts(53_000_000, 4480)
+			.saturating_add(Weight::from_parts(3_000, 0).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(45_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(210_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(0, 66).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
+	}
+	fn disapprove_proposal(p: u32, ) -> Weight {
+		Weight::from_parts(18_000_000, 2000)
+			.saturating_add(Weight::from_parts(170_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(p.into()))
+	}
+	fn kill(d: u32, p: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 4000)
+			.saturating_add(Weight::from_parts(1_900_000, 0).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(180_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
+	}
+	fn release_proposal_cost() -> Weight {
+		Weight::from_parts(60_000_000, 4300)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,87 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_conviction_voting`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN ESTIMATES, NOT BENCHMARK RESULTS. They are derived from the
+//! storage accessed by each call and must be replaced with the output of the pallet benchmarks
+//! before the next runtime release:
+//!
+//! frame-omni-bencher v1 benchmark pallet \
+//!     --runtime target/release/wbuild/<runtime>/<runtime>.compact.compressed.wasm \
+//!     --genesis-builder-preset=staging --pallet=pallet_conviction_voting --extrinsic=* \
+//!     --steps=50 --repeat=20 --wasm-execution=compiled --heap-pages=4096 \
+//!     --header=HEADER --template=.maintain/runtimes-weight-template.hbs \
+//!     --output=runtime/mainnet/src/weights/conviction_voting.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_conviction_voting::WeightInfo;
+
+pub struct AllfeatWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
+	fn vote_new() -> Weight {
+		Weight::from_parts(150_000_000, 219984)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn vote_existing() -> Weight {
+		Weight::from_parts(190_000_000, 219984)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn remove_vote() -> Weight {
+		Weight::from_parts(150_000_000, 219984)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn remove_other_vote() -> Weight {
+		Weight::from_parts(40_000_000, 30706)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn delegate(r: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 83866)
+			.saturating_add(Weight::from_parts(33_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2676).saturating_mul(r.into()))
+	}
+	fn undelegate(r: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 83866)
+			.saturating_add(Weight::from_parts(32_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2676).saturating_mul(r.into()))
+	}
+	fn unlock() -> Weight {
+		Weight::from_parts(70_000_000, 30706)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
@@ -16,21 +16,32 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod asset_rate;
 pub mod ats;
 pub mod balances;
+pub mod bounties;
+pub mod child_bounties;
+pub mod collective;
+pub mod conviction_voting;
 pub mod grandpa;
+pub mod membership;
 pub mod meta_tx;
 pub mod multisig;
 pub mod preimage;
 pub mod proxy;
+pub mod referenda;
+pub mod safe_mode;
 pub mod scheduler;
 pub mod session;
-pub mod sudo;
+pub mod sponsorship;
 pub mod system;
 pub mod timestamp;
 pub mod token_allocation;
 pub mod transaction_payment;
 pub mod treasury;
+pub mod tx_pause;
 pub mod utility;
+pub mod validator_rewards;
 pub mod validators;
 pub mod verify_signature;
+pub mod whitelist;
@@ -1,109 +0,0 @@
-// This file is part of Allfeat.
-
-// Copyright (C) 2022-2025 Allfeat.
-// SPDX-License-Identifier: GPL-3.0-or-later
-
-// This program is free software: you can redistribute it and/or modify
-// it under the terms of the GNU General Public License as published by
-// the Free Software Foundation, either version 3 of the License, or
-// (at your option) any later version.
-
-// This program is distributed in the hope that it will be useful,
-// but WITHOUT ANY WARRANTY; without even the implied warranty of
-// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
-// GNU General Public License for more details.
-
-// You should have received a copy of the GNU General Public License
-// along with this program. If not, see <https://www.gnu.org/licenses/>.
-
-//! Autogenerated weights for `pallet_sudo`
-//!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 53.0.0
-//! DATE: 2026-03-20, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `3803e90a-1da7-4940-8054-96c4ef646f72-0`, CPU: `AMD EPYC-Milan Processor`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
-
-// Executed Command:
-// frame-omni-bencher
-// v1
-// benchmark
-// pallet
-// --runtime
-// /home/debian/Allfeat/target/release/wbuild/allfeat-runtime/allfeat_runtime.compact.compressed.wasm
-// --genesis-builder-preset=staging
-// --pallet=pallet_sudo
-// --extrinsic=*
-// --steps=50
-// --repeat=20
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --header=/home/debian/Allfeat/HEADER
-// --template=/home/debian/Allfeat/.maintain/runtimes-weight-template.hbs
-// --output=/home/debian/Allfeat/runtime/mainnet/src/weights/sudo.rs
-
-#![cfg_attr(rustfmt, rustfmt_skip)]
-#![allow(unused_parens)]
-#![allow(unused_imports)]
-#![allow(missing_docs)]
-#![allow(dead_code)]
-
-use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
-use core::marker::PhantomData;
-use pallet_sudo::WeightInfo;
-
-pub struct AllfeatWeight<T>(PhantomData<T>);
-impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
-	/// Storage: `Sudo::Key` (r:1 w:1)
-	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	fn set_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `132`
-		//  Estimated: `1517`
-		// Minimum execution time: 10_150_000 picoseconds.
-		Weight::from_parts(10_450_000, 1517)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-	/// Storage: `Sudo::Key` (r:1 w:0)
-	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	fn sudo() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `132`
-		//  Estimated: `1517`
-		// Minimum execution time: 10_830_000 picoseconds.
-		Weight::from_parts(11_320_000, 1517)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-	}
-	/// Storage: `Sudo::Key` (r:1 w:0)
-	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	fn sudo_as() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `132`
-		//  Estimated: `1517`
-		// Minimum execution time: 11_060_000 picoseconds.
-		Weight::from_parts(11_730_000, 1517)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-	}
-	/// Storage: `Sudo::Key` (r:1 w:1)
-	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	fn remove_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `132`
-		//  Estimated: `1517`
-		// Minimum execution time: 9_169_000 picoseconds.
-		Weight::from_parts(9_569_000, 1517)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-	/// Storage: `Sudo::Key` (r:1 w:0)
-	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	fn check_only_sudo_account() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `132`
-		//  Estimated: `1517`
-		// Minimum execution time: 5_520_000 picoseconds.
-		Weight::from_parts(5_760_000, 1517)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-	}
-}
@@ -16,31 +16,18 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for `pallet_token_allocation`
+//! Placeholder weights for `pallet_token_allocation`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 53.0.0
-//! DATE: 2026-03-20, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `3803e90a-1da7-4940-8054-96c4ef646f72-0`, CPU: `AMD EPYC-Milan Processor`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
-
-// Executed Command:
-// frame-omni-bencher
-// v1
-// benchmark
-// pallet
-// --runtime
-// /home/debian/Allfeat/target/release/wbuild/allfeat-runtime/allfeat_runtime.compact.compressed.wasm
-// --genesis-builder-preset=staging
-// --pallet=pallet_token_allocation
-// --extrinsic=*
-// --steps=50
-// --repeat=20
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --header=/home/debian/Allfeat/HEADER
-// --template=/home/debian/Allfeat/.maintain/runtimes-weight-template.hbs
-// --output=/home/debian/Allfeat/runtime/mainnet/src/weights/token_allocation.rs
+//! THESE WEIGHTS ARE HAND-WRITTEN ESTIMATES, NOT BENCHMARK RESULTS. They are derived from the
+//! storage accessed by each call and must be replaced with the output of the pallet benchmarks
+//! before the next runtime release:
+//!
+//! frame-omni-bencher v1 benchmark pallet \
+//!     --runtime target/release/wbuild/<runtime>/<runtime>.compact.compressed.wasm \
+//!     --genesis-builder-preset=staging --pallet=pallet_token_allocation --extrinsic=* \
+//!     --steps=50 --repeat=20 --wasm-execution=compiled --heap-pages=4096 \
+//!     --header=HEADER --template=.maintain/runtimes-weight-template.hbs \
+//!     --output=runtime/mainnet/src/weights/token_allocation.rs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -55,9 +42,9 @@ use pallet_token_allocation::WeightInfo;
 pub struct AllfeatWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
 	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
-	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
 	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
-	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
 	/// Storage: `TokenAllocation::NextAllocationId` (r:1 w:1)
 	/// Proof: `TokenAllocation::NextAllocationId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
@@ -65,78 +52,247 @@ impl<T: frame_system::Config> WeightInfo for AllfeatWeight<T> {
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `TokenAllocation::Allocations` (r:0 w:1)
-	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	fn add_allocation() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1137`
-		//  Estimated: `6196`
-		// Minimum execution time: 122_558_000 picoseconds.
 		Weight::from_parts(125_708_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::FailedReleases` (r:1 w:1)
+	/// Proof: `TokenAllocation::FailedReleases` (`max_values`: Some(1), `max_size`: Some(1538), added: 2033, mode: `MaxEncodedLen`)
+	fn claim() -> Weight {
+		Weight::from_parts(49_417_000, 3593)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `TokenAllocation::NextPayoutAt` (r:1 w:0)
 	/// Proof: `TokenAllocation::NextPayoutAt` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `TokenAllocation::PayoutCursor` (r:1 w:0)
-	/// Proof: `TokenAllocation::PayoutCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn on_initialize_noop() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `184`
-		//  Estimated: `1489`
-		// Minimum execution time: 5_411_000 picoseconds.
-		Weight::from_parts(5_660_000, 1489)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		Weight::from_parts(4_310_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
-	/// Storage: `TokenAllocation::NextPayoutAt` (r:1 w:0)
+	/// Storage: `TokenAllocation::NextPayoutAt` (r:1 w:1)
 	/// Proof: `TokenAllocation::NextPayoutAt` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `TokenAllocation::PayoutCursor` (r:1 w:1)
-	/// Proof: `TokenAllocation::PayoutCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `TokenAllocation::Allocations` (r:257 w:256)
-	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EpochIndex` (r:1 w:1)
+	/// Proof: `TokenAllocation::EpochIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn on_initialize_epoch() -> Weight {
+		Weight::from_parts(9_271_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
 	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
-	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:256 w:256)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:256 w:256)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
-	/// Storage: `TokenAllocation::EpochIndex` (r:1 w:0)
-	/// Proof: `TokenAllocation::EpochIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	fn on_initialize_partial() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `87007`
-		//  Estimated: `670174`
-		// Minimum execution time: 15_637_523_000 picoseconds.
-		Weight::from_parts(16_687_958_000, 670174)
-			.saturating_add(T::DbWeight::get().reads(773_u64))
-			.saturating_add(T::DbWeight::get().writes(769_u64))
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::FailedReleases` (r:1 w:1)
+	/// Proof: `TokenAllocation::FailedReleases` (`max_values`: Some(1), `max_size`: Some(1538), added: 2033, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn revoke_allocation() -> Weight {
+		Weight::from_parts(94_083_000, 6218)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
-	/// Storage: `TokenAllocation::NextPayoutAt` (r:1 w:1)
-	/// Proof: `TokenAllocation::NextPayoutAt` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `TokenAllocation::PayoutCursor` (r:1 w:1)
-	/// Proof: `TokenAllocation::PayoutCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `TokenAllocation::Allocations` (r:256 w:255)
-	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:0)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
 	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
-	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:255 w:255)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:0)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::PendingTransfers` (r:0 w:1)
+	/// Proof: `TokenAllocation::PendingTransfers` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn transfer_allocation() -> Weight {
+		Weight::from_parts(24_310_000, 3678)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenAllocation::PendingTransfers` (r:1 w:1)
+	/// Proof: `TokenAllocation::PendingTransfers` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:0)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	fn approve_transfer() -> Weight {
+		Weight::from_parts(16_420_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::NextAllocationId` (r:1 w:1)
+	/// Proof: `TokenAllocation::NextAllocationId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:255 w:255)
+	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
-	/// Storage: `TokenAllocation::EpochIndex` (r:1 w:1)
-	/// Proof: `TokenAllocation::EpochIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 255]`.
-	fn on_initialize_epoch_finished(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `354 + x * (337 ±0)`
-		//  Estimated: `3989 + x * (2613 ±0)`
-		// Minimum execution time: 143_698_000 picoseconds.
-		Weight::from_parts(145_598_000, 3989)
-			// Standard Error: 207_939
-			.saturating_add(Weight::from_parts(67_239_129, 0).saturating_mul(x.into()))
+	/// Storage: `TokenAllocation::Allocations` (r:0 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn add_allocation_with_overrides() -> Weight {
+		Weight::from_parts(79_041_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:1)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	fn set_envelope_curve() -> Weight {
+		Weight::from_parts(12_250_000, 3625)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::FailedReleases` (r:1 w:1)
+	/// Proof: `TokenAllocation::FailedReleases` (`max_values`: Some(1), `max_size`: Some(1538), added: 2033, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:1 w:1)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::PendingTransfers` (r:0 w:1)
+	/// Proof: `TokenAllocation::PendingTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn force_settle() -> Weight {
+		Weight::from_parts(59_291_000, 3674)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Airdrops` (r:1 w:1)
+	/// Proof: `TokenAllocation::Airdrops` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::SweptAirdropRoots` (r:1 w:0)
+	/// Proof: `TokenAllocation::SweptAirdropRoots` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_airdrop_root() -> Weight {
+		Weight::from_parts(22_125_000, 3625)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TokenAllocation::Airdrops` (r:1 w:1)
+	/// Proof: `TokenAllocation::Airdrops` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AirdropClaimBitmap` (r:1 w:1)
+	/// Proof: `TokenAllocation::AirdropClaimBitmap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 32]`.
+	fn claim_airdrop(p: u32, ) -> Weight {
+		Weight::from_parts(62_671_342, 6196)
+			.saturating_add(Weight::from_parts(1_386_217, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TokenAllocation::Airdrops` (r:1 w:1)
+	/// Proof: `TokenAllocation::Airdrops` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::SweptAirdropRoots` (r:0 w:1)
+	/// Proof: `TokenAllocation::SweptAirdropRoots` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn sweep_airdrop() -> Weight {
+		Weight::from_parts(56_250_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:2 w:2)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:1)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn create_envelope() -> Weight {
+		Weight::from_parts(59_625_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:1 w:1)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:0)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Airdrops` (r:1 w:0)
+	/// Proof: `TokenAllocation::Airdrops` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:0)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_envelope_cap() -> Weight {
+		Weight::from_parts(25_333_000, 3629)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
-			.saturating_add(Weight::from_parts(0, 2613).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenAllocation::Envelopes` (r:2 w:2)
+	/// Proof: `TokenAllocation::Envelopes` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::EnvelopeDistributed` (r:1 w:0)
+	/// Proof: `TokenAllocation::EnvelopeDistributed` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::ClosedEnvelopes` (r:1 w:1)
+	/// Proof: `TokenAllocation::ClosedEnvelopes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn close_envelope() -> Weight {
+		Weight::from_parts(58_459_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TokenAllocation::PendingTransfers` (r:1 w:1)
+	/// Proof: `TokenAllocation::PendingTransfers` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::Allocations` (r:1 w:1)
+	/// Proof: `TokenAllocation::Allocations` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AllocationsOf` (r:2 w:2)
+	/// Proof: `TokenAllocation::AllocationsOf` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	fn accept_transfer() -> Weight {
+		Weight::from_parts(81_792_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `TokenAllocation::SweptAirdropRoots` (r:1 w:1)
+	/// Proof: `TokenAllocation::SweptAirdropRoots` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `TokenAllocation::AirdropClaimBitmap` (r:1001 w:1000)
+	/// Proof: `TokenAllocation::AirdropClaimBitmap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn clear_airdrop_bitmap(n: u32, ) -> Weight {
+		Weight::from_parts(14_208_000, 3497)
+			.saturating_add(Weight::from_parts(2_875_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2535).saturating_mul(n.into()))
 	}
 }
@@ -16,7 +16,9 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::pallet_prelude::{TransactionSource, TransactionValidity};
+use frame_support::pallet_prelude::{
+    TransactionSource, TransactionValidity, TransactionValidityError,
+};
 use sp_api::impl_runtime_apis;
 use sp_inherents::InherentData;
 use sp_runtime::traits::Block as BlockT;
@@ -130,9 +132,17 @@ impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
         Balance,
     > for Runtime {
         fn query_info(uxt: <Block as frame_support::sp_runtime::traits::Block>::Extrinsic, len: u32) -> pallet_transaction_payment::RuntimeDispatchInfo<Balance> {
-            TransactionPayment::query_info(uxt, len)
+            let waived = midds::fee_waiver::is_fee_waived(&uxt);
+            let mut info = TransactionPayment::query_info(uxt, len);
+            if waived {
+                info.partial_fee = 0;
+            }
+            info
         }
         fn query_fee_details(uxt: <Block as frame_support::sp_runtime::traits::Block>::Extrinsic, len: u32) -> pallet_transaction_payment::FeeDetails<Balance> {
+            if midds::fee_waiver::is_fee_waived(&uxt) {
+                return pallet_transaction_payment::FeeDetails { inclusion_fee: None, tip: 0 };
+            }
             TransactionPayment::query_fee_details(uxt, len)
         }
         fn query_weight_to_fee(weight: frame_support::weights::Weight) -> Balance {
@@ -188,6 +198,41 @@ impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
         }
     }
 
+    impl pallet_validators::runtime_api::ValidatorsApi<Block, AccountId> for Runtime {
+        fn active_bootnodes() -> Vec<(AccountId, Vec<Vec<u8>>)> {
+            Validators::active_bootnodes()
+        }
+    }
+
+    impl pallet_sponsorship::runtime_api::SponsorshipApi<Block, AccountId, Balance> for Runtime {
+        fn remaining_budget(sponsor: AccountId) -> Option<Balance> {
+            Sponsorship::remaining_budget(&sponsor)
+        }
+
+        fn is_beneficiary(sponsor: AccountId, beneficiary: AccountId) -> bool {
+            Sponsorship::is_beneficiary(&sponsor, &beneficiary)
+        }
+    }
+
+    impl allfeat_primitives::meta_tx::MetaTxRelayApi<Block, AccountId, Nonce, Signature> for Runtime {
+        fn prepare_relay(
+            meta_tx: Vec<u8>,
+            nonce: Nonce,
+            era: sp_runtime::generic::Era,
+            block_hash: <Block as BlockT>::Hash,
+        ) -> Result<allfeat_primitives::meta_tx::MetaTxRelay<AccountId>, TransactionValidityError> {
+            pallets::prepare_meta_tx_relay(meta_tx, nonce, era, block_hash)
+        }
+
+        fn relay_extrinsic(
+            relay: allfeat_primitives::meta_tx::MetaTxRelay<AccountId>,
+            relayer: AccountId,
+            signature: Signature,
+        ) -> Result<<Block as BlockT>::Extrinsic, TransactionValidityError> {
+            pallets::meta_tx_relay_extrinsic(relay, relayer, signature)
+        }
+    }
+
     impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
         fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
             use frame_support::genesis_builder_helper::build_state;
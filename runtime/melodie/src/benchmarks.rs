@@ -25,7 +25,9 @@ frame_benchmarking::define_benchmarks!(
     [pallet_midds_musical_works, MusicalWorks]
     [pallet_midds_recordings, Recordings]
     [pallet_midds_releases, Releases]
+    [pallet_midds_fee_waiver, MiddsFeeWaiver]
     [pallet_meta_tx, MetaTx]
+    [pallet_sponsorship, Sponsorship]
     [pallet_multisig, Multisig]
     [pallet_preimage, Preimage]
     [pallet_proxy, Proxy]
@@ -75,10 +75,12 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: alloc::borrow::Cow::Borrowed("allfeat-melodie-3"),
     impl_name: alloc::borrow::Cow::Borrowed("allfeatlabs-melodie-3"),
     authoring_version: 1,
-    spec_version: 201,
+    // Bumped by every change to runtime storage, calls or logic.
+    spec_version: 211,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 2,
+    // Bumped whenever the encoding of an existing call or extension changes.
+    transaction_version: 4,
     system_version: 1,
 };
 
@@ -105,7 +107,11 @@ pub type TxBareExtension = (
     frame_system::CheckMortality<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    pallet_sponsorship::CheckSponsorship<Runtime>,
+    pallet_midds_fee_waiver::WaiveFeeWithinQuota<
+        Runtime,
+        pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    >,
     frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 );
 
@@ -219,12 +225,18 @@ mod runtime {
     #[runtime::pallet_index(18)]
     pub type SafeMode = pallet_safe_mode;
 
+    #[runtime::pallet_index(19)]
+    pub type Parameters = pallet_parameters;
+
     #[runtime::pallet_index(20)]
     pub type MetaTx = pallet_meta_tx;
 
     #[runtime::pallet_index(21)]
     pub type VerifySignature = pallet_verify_signature;
 
+    #[runtime::pallet_index(22)]
+    pub type Sponsorship = pallet_sponsorship;
+
     // Allfeat related
 
     #[runtime::pallet_index(102)]
@@ -238,4 +250,7 @@ mod runtime {
 
     #[runtime::pallet_index(105)]
     pub type Ats = pallet_ats;
+
+    #[runtime::pallet_index(106)]
+    pub type MiddsFeeWaiver = pallet_midds_fee_waiver;
 }
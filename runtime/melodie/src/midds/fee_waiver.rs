@@ -0,0 +1,61 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{parameter_types, traits::Contains};
+use frame_system::EnsureRoot;
+use sp_runtime::{generic::Preamble, traits::StaticLookup};
+
+parameter_types! {
+    pub const FeeWaiverQuotaPeriod: BlockNumber = 30 * DAYS;
+}
+
+/// MIDDS registrations and unregistrations, the only calls accredited providers get for free.
+pub struct MiddsRegistrations;
+impl Contains<RuntimeCall> for MiddsRegistrations {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::MusicalWorks(
+                pallet_midds::Call::register { .. } | pallet_midds::Call::unregister { .. }
+            ) | RuntimeCall::Recordings(
+                pallet_midds::Call::register { .. } | pallet_midds::Call::unregister { .. }
+            ) | RuntimeCall::Releases(
+                pallet_midds::Call::register { .. } | pallet_midds::Call::unregister { .. }
+            )
+        )
+    }
+}
+
+impl pallet_midds_fee_waiver::Config for Runtime {
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type FeelessCalls = MiddsRegistrations;
+    type QuotaPeriod = FeeWaiverQuotaPeriod;
+    type WeightInfo = weights::midds_fee_waiver::AllfeatWeight<Runtime>;
+}
+
+/// Whether the fee of `uxt` would be waived, used to report it in the fee query runtime APIs.
+pub fn is_fee_waived(uxt: &UncheckedExtrinsic) -> bool {
+    let Preamble::Signed(address, ..) = &uxt.preamble else {
+        return false;
+    };
+    let Ok(who) = <Runtime as frame_system::Config>::Lookup::lookup(address.clone()) else {
+        return false;
+    };
+    MiddsFeeWaiver::is_waived(&who, &uxt.function)
+}
@@ -16,6 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod fee_waiver;
 pub mod musical_works;
 pub mod recordings;
 pub mod releases;
@@ -20,27 +20,14 @@ use crate::*;
 
 use super::MusicalWorks;
 use allfeat_midds::musical_work::MusicalWork;
-use allfeat_primitives::Balance;
 use frame_support::{PalletId, parameter_types};
 use frame_system::EnsureSigned;
-use shared_runtime::currency::MILLIAFT;
 
 #[cfg(feature = "runtime-benchmarks")]
 use allfeat_midds::benchmarking::MusicalWorkBenchmarkHelper;
 
 parameter_types! {
     pub const StakeholderPalletId: PalletId = PalletId(*b"m/muwork");
-    pub const ByteDepositCost: Balance = 10 * MILLIAFT; // 0.01 AFT / byte
-}
-
-#[cfg(not(feature = "runtime-benchmarks"))]
-parameter_types! {
-    pub const UnregisterPeriod: Option<Moment> = Some(30 * DAYS as u64);
-}
-
-#[cfg(feature = "runtime-benchmarks")]
-parameter_types! {
-    pub const UnregisterPeriod: Option<Moment> = None;
 }
 
 impl pallet_midds::Config<MusicalWorks> for Runtime {
@@ -50,8 +37,8 @@ impl pallet_midds::Config<MusicalWorks> for Runtime {
     type RuntimeHoldReason = RuntimeHoldReason;
     type MIDDS = MusicalWork;
     type ProviderOrigin = EnsureSigned<Self::AccountId>;
-    type ByteDepositCost = ByteDepositCost;
-    type UnregisterPeriod = UnregisterPeriod;
+    type ByteDepositCost = dynamic_params::midds::ByteDepositCost;
+    type UnregisterPeriod = dynamic_params::midds::UnregisterPeriod;
     type WeightInfo = weights::midds_musical_works::AllfeatWeight<Runtime>;
 
     #[cfg(feature = "runtime-benchmarks")]
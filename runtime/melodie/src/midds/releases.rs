@@ -20,27 +20,14 @@ use crate::*;
 
 use super::Releases;
 use allfeat_midds::release::Release;
-use allfeat_primitives::Balance;
 use frame_support::{PalletId, parameter_types};
 use frame_system::EnsureSigned;
-use shared_runtime::currency::MILLIAFT;
 
 #[cfg(feature = "runtime-benchmarks")]
 use allfeat_midds::benchmarking::ReleaseBenchmarkHelper;
 
 parameter_types! {
     pub const ReleasePalletId: PalletId = PalletId(*b"m/rlease");
-    pub const ByteDepositCost: Balance = 10 * MILLIAFT; // 0.01 AFT / byte
-}
-
-#[cfg(not(feature = "runtime-benchmarks"))]
-parameter_types! {
-    pub const UnregisterPeriod: Option<Moment> = Some(30 * DAYS as u64);
-}
-
-#[cfg(feature = "runtime-benchmarks")]
-parameter_types! {
-    pub const UnregisterPeriod: Option<Moment> = None;
 }
 
 impl pallet_midds::Config<Releases> for Runtime {
@@ -50,8 +37,8 @@ impl pallet_midds::Config<Releases> for Runtime {
     type RuntimeHoldReason = RuntimeHoldReason;
     type MIDDS = Release;
     type ProviderOrigin = EnsureSigned<Self::AccountId>;
-    type ByteDepositCost = ByteDepositCost;
-    type UnregisterPeriod = UnregisterPeriod;
+    type ByteDepositCost = dynamic_params::midds::ByteDepositCost;
+    type UnregisterPeriod = dynamic_params::midds::UnregisterPeriod;
     type WeightInfo = weights::midds_releases::AllfeatWeight<Runtime>;
 
     #[cfg(feature = "runtime-benchmarks")]
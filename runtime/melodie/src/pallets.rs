@@ -25,9 +25,11 @@ mod authorship;
 mod balances;
 mod grandpa;
 mod meta_tx;
+mod parameters;
 mod preimage;
 mod safe_mode;
 mod session;
+mod sponsorship;
 mod sudo;
 mod system;
 mod timestamp;
@@ -37,6 +39,10 @@ mod validators;
 
 // External required imports
 pub use balances::*;
+pub use meta_tx::{meta_tx_relay_extrinsic, prepare_meta_tx_relay};
+pub use parameters::*;
+pub use proxy::ProxyType;
 pub use session::*;
+pub use sponsorship::SponsoredCalls;
 pub use system::*;
 pub use transaction_payment::*;
@@ -0,0 +1,77 @@
+// This file is part of Allfeat.
+
+// Copyright (C) 2022-2025 Allfeat.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    dynamic_params::{dynamic_pallet_params, dynamic_params},
+    traits::AsEnsureOriginWithArg,
+};
+use frame_system::EnsureRoot;
+use shared_runtime::currency::{MICROAFT, MILLIAFT};
+
+/// Runtime values that can be changed without a runtime upgrade.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+    use super::*;
+
+    #[dynamic_pallet_params]
+    #[codec(index = 0)]
+    pub mod fees {
+        /// Fee charged per byte of an encoded extrinsic.
+        #[codec(index = 0)]
+        pub static TransactionByteFee: Balance = 10 * MICROAFT;
+
+        /// Fee charged for the weight of a base extrinsic.
+        #[codec(index = 1)]
+        pub static WeightFeeFactor: Balance = 10 * MILLIAFT;
+    }
+
+    #[dynamic_pallet_params]
+    #[codec(index = 1)]
+    pub mod midds {
+        /// Deposit held per byte of a registered MIDDS, shared by all MIDDS pallets.
+        #[codec(index = 0)]
+        pub static ByteDepositCost: Balance = 10 * MILLIAFT; // 0.01 AFT / byte
+
+        /// Delay after its registration during which a MIDDS cannot be unregistered by its
+        /// provider (`UnregisterLocked`). `None` lets it be unregistered at any time.
+        #[codec(index = 1)]
+        pub static UnregisterPeriod: Option<Moment> = if cfg!(feature = "runtime-benchmarks") {
+            None
+        } else {
+            Some(30 * DAYS as u64)
+        };
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+    fn default() -> Self {
+        RuntimeParameters::Fees(dynamic_params::fees::Parameters::TransactionByteFee(
+            dynamic_params::fees::TransactionByteFee,
+            Some(10 * MICROAFT),
+        ))
+    }
+}
+
+impl pallet_parameters::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeParameters = RuntimeParameters;
+    type AdminOrigin = AsEnsureOriginWithArg<EnsureRoot<AccountId>>;
+    type WeightInfo = pallet_parameters::weights::SubstrateWeight<Runtime>;
+}
@@ -35,6 +35,7 @@ use shared_runtime::{NORMAL_DISPATCH_RATIO, RuntimeBlockLength};
 type SingleBlockMigrations = (
     pallet_ats::migrations::v1::MigrateV0ToV1<Runtime>,
     pallet_ats::migrations::v2::MigrateV1ToV2<Runtime>,
+    pallet_validators::migrations::v1::MigrateToV1<Runtime>,
 );
 
 frame_support::parameter_types! {
@@ -22,17 +22,14 @@ use frame_support::{
     parameter_types,
     sp_runtime::Perbill,
     traits::{
-        Imbalance, OnUnbalanced,
+        Get, Imbalance, OnUnbalanced,
         fungible::{Balanced, Credit},
     },
     weights::{
         ConstantMultiplier, WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial,
     },
 };
-use shared_runtime::{
-    SlowAdjustingFeeUpdate,
-    currency::{MICROAFT, MILLIAFT},
-};
+use shared_runtime::SlowAdjustingFeeUpdate;
 
 pub struct DealWithFees;
 impl OnUnbalanced<Credit<AccountId, Balances>> for DealWithFees {
@@ -57,9 +54,7 @@ impl OnUnbalanced<Credit<AccountId, Balances>> for DealWithFees {
 }
 
 parameter_types! {
-    pub const TransactionByteFee: Balance = 10 * MICROAFT;
     pub const OperationalFeeMultiplier: u8 = 5;
-    pub const WeightFeeFactor: Balance = 10 * MILLIAFT;
 }
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
@@ -76,7 +71,7 @@ pub struct WeightToFee;
 impl WeightToFeePolynomial for WeightToFee {
     type Balance = Balance;
     fn polynomial() -> WeightToFeeCoefficients<Self::Balance> {
-        let p = WeightFeeFactor::get();
+        let p = dynamic_params::fees::WeightFeeFactor::get();
         let q = Balance::from(
             RuntimeBlockWeights::get()
                 .get(DispatchClass::Normal)
@@ -98,7 +93,7 @@ impl pallet_transaction_payment::Config for Runtime {
     type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, DealWithFees>;
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
     type WeightToFee = WeightToFee;
-    type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+    type LengthToFee = ConstantMultiplier<Balance, dynamic_params::fees::TransactionByteFee>;
     type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
     type WeightInfo = weights::transaction_payment::AllfeatWeight<Runtime>;
 }
@@ -18,12 +18,33 @@
 
 use crate::*;
 use frame_support::parameter_types;
+use frame_system::EnsureRoot;
+use shared_runtime::currency::AFT;
+use sp_runtime::traits::ConvertInto;
 
 parameter_types! {
     pub const MaxValidators: u32 = 5;
+    pub const MaxProfileFieldLength: u32 = 128;
+    pub const MaxBootnodes: u32 = 4;
+    pub const ValidatorBond: Balance = 100 * AFT;
+    pub const UnbondingDelay: BlockNumber = DAYS;
+    pub const MaxSlashReasonLength: u32 = 256;
 }
 
 impl pallet_validators::Config for Runtime {
     type MaxValidators = MaxValidators;
+    type MaxProfileFieldLength = MaxProfileFieldLength;
+    type MaxBootnodes = MaxBootnodes;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type ValidatorAccountOf = ConvertInto;
+    type ValidatorBond = ValidatorBond;
+    type UnbondingDelay = UnbondingDelay;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type SlashOrigin = EnsureRoot<AccountId>;
+    /// Melodie has no treasury: slashed bonds are burned, dropping the credit reduces the total
+    /// issuance by the slashed amount.
+    type Slash = ();
+    type MaxSlashReasonLength = MaxSlashReasonLength;
     type WeightInfo = weights::validators::AllfeatWeight<Runtime>;
 }
@@ -1,4 +1,5 @@
-use crate::{Runtime, RuntimeBlockWeights, TransactionByteFee, WeightToFee, weights};
+use crate::{Runtime, RuntimeBlockWeights, WeightToFee, dynamic_params, weights};
+use frame_support::traits::Get;
 use frame_support::weights::{Weight, WeightToFee as WeightToFeeTrait};
 use frame_system::limits::BlockWeights;
 use shared_runtime::fee_estimator::{
@@ -32,13 +33,31 @@ fn print_fee_report() {
 
         let config = FeeReportConfig {
             base_weight: base_extrinsic_weight(),
-            byte_fee: TransactionByteFee::get(),
+            byte_fee: dynamic_params::fees::TransactionByteFee::get(),
             weight_to_fee_fn: weight_to_fee,
             min_multiplier_num: min_num,
             min_multiplier_den: min_den,
             max_multiplier_num: 10,
             max_multiplier_den: 1,
             aft_price_usd: DEFAULT_AFT_PRICE_USD,
+            parameters: vec![
+                (
+                    "TransactionByteFee",
+                    format_balance(dynamic_params::fees::TransactionByteFee::get()),
+                ),
+                (
+                    "WeightFeeFactor",
+                    format_balance(dynamic_params::fees::WeightFeeFactor::get()),
+                ),
+                (
+                    "ByteDepositCost",
+                    format_balance(dynamic_params::midds::ByteDepositCost::get()),
+                ),
+                (
+                    "UnregisterPeriod",
+                    format!("{:?}", dynamic_params::midds::UnregisterPeriod::get()),
+                ),
+            ],
         };
 
         use frame_system::WeightInfo as _;
@@ -71,7 +90,7 @@ fn print_fee_report() {
 
         let ats_base_deposit = crate::BaseDeposit::get();
         let ats_version_deposit = crate::VersionDeposit::get();
-        let midds_byte_deposit = crate::musical_works::ByteDepositCost::get();
+        let midds_byte_deposit = dynamic_params::midds::ByteDepositCost::get();
 
         // Estimated encoded sizes for MIDDS data
         let musical_work_size: u32 = 500;
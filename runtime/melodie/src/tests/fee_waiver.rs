@@ -0,0 +1,151 @@
+use crate::{
+    midds::fee_waiver::{MiddsRegistrations, is_fee_waived},
+    tests::new_test_ext,
+    *,
+};
+use frame_support::{
+    assert_ok,
+    pallet_prelude::{Encode, TransactionSource},
+    traits::Contains,
+};
+use shared_runtime::currency::AFT;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::generic::{Era, SignedPayload};
+
+fn unregister_recording() -> RuntimeCall {
+    RuntimeCall::Recordings(pallet_midds::Call::unregister { midds_id: 0 })
+}
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn tx_extension(nonce: Nonce) -> TxExtension {
+    (
+        frame_system::CheckNonZeroSender::new(),
+        frame_system::CheckSpecVersion::new(),
+        frame_system::CheckTxVersion::new(),
+        frame_system::CheckGenesis::new(),
+        frame_system::CheckMortality::from(Era::Immortal),
+        frame_system::CheckNonce::from(nonce),
+        frame_system::CheckWeight::new(),
+        pallet_sponsorship::CheckSponsorship::new(),
+        pallet_midds_fee_waiver::WaiveFeeWithinQuota::new(
+            pallet_transaction_payment::ChargeTransactionPayment::from(0),
+        ),
+        frame_metadata_hash_extension::CheckMetadataHash::new(false),
+    )
+}
+
+fn signed(who: AccountId, call: RuntimeCall) -> UncheckedExtrinsic {
+    UncheckedExtrinsic::new_signed(
+        call,
+        who.into(),
+        Signature::Sr25519(sp_core::sr25519::Signature::from_raw([0; 64])),
+        tx_extension(0),
+    )
+}
+
+fn signed_by(signer: Sr25519Keyring, nonce: Nonce, call: RuntimeCall) -> UncheckedExtrinsic {
+    let payload = SignedPayload::new(call.clone(), tx_extension(nonce)).unwrap();
+    let signature = payload.using_encoded(|payload| signer.sign(payload));
+    UncheckedExtrinsic::new_signed(
+        call,
+        signer.to_account_id().into(),
+        signature.into(),
+        tx_extension(nonce),
+    )
+}
+
+#[test]
+fn only_midds_registrations_are_feeless() {
+    assert!(MiddsRegistrations::contains(&unregister_recording()));
+    assert!(MiddsRegistrations::contains(&RuntimeCall::MusicalWorks(
+        pallet_midds::Call::unregister { midds_id: 0 }
+    )));
+    assert!(!MiddsRegistrations::contains(&remark()));
+    assert!(!MiddsRegistrations::contains(&RuntimeCall::Balances(
+        pallet_balances::Call::transfer_keep_alive {
+            dest: Sr25519Keyring::Bob.to_account_id().into(),
+            value: 1,
+        }
+    )));
+}
+
+#[test]
+fn accredited_provider_extrinsics_are_reported_feeless() {
+    new_test_ext().execute_with(|| {
+        let provider = Sr25519Keyring::Alice.to_account_id();
+        assert!(!is_fee_waived(&signed(
+            provider.clone(),
+            unregister_recording()
+        )));
+
+        assert_ok!(MiddsFeeWaiver::set_quota(
+            RuntimeOrigin::root(),
+            provider.clone(),
+            1
+        ));
+        assert!(is_fee_waived(&signed(
+            provider.clone(),
+            unregister_recording()
+        )));
+        assert!(!is_fee_waived(&signed(provider, remark())));
+        assert!(!is_fee_waived(&UncheckedExtrinsic::new_bare(
+            unregister_recording()
+        )));
+    });
+}
+
+#[test]
+fn pending_waived_extrinsics_do_not_replace_each_other() {
+    new_test_ext().execute_with(|| {
+        System::initialize(&1, &Default::default(), &Default::default());
+        let alice = Sr25519Keyring::Alice;
+        let provider = alice.to_account_id();
+        assert_ok!(Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            provider.clone().into(),
+            100 * AFT
+        ));
+        assert_ok!(MiddsFeeWaiver::set_quota(
+            RuntimeOrigin::root(),
+            provider.clone(),
+            2
+        ));
+        let first = signed_by(alice, 0, unregister_recording());
+        let second = signed_by(alice, 1, unregister_recording());
+
+        // Both are in the pool before any of them is included.
+        let first_valid = RuntimeExecutive::validate_transaction(
+            TransactionSource::External,
+            first.clone(),
+            Default::default(),
+        )
+        .unwrap();
+        let second_valid = RuntimeExecutive::validate_transaction(
+            TransactionSource::External,
+            second.clone(),
+            Default::default(),
+        )
+        .unwrap();
+        assert!(
+            first_valid
+                .provides
+                .iter()
+                .all(|tag| !second_valid.provides.contains(tag))
+        );
+        assert!(!second_valid.requires.is_empty());
+        assert!(
+            second_valid
+                .requires
+                .iter()
+                .all(|tag| first_valid.provides.contains(tag))
+        );
+
+        assert!(RuntimeExecutive::apply_extrinsic(first).is_ok());
+        assert!(RuntimeExecutive::apply_extrinsic(second).is_ok());
+        assert_eq!(MiddsFeeWaiver::remaining_quota(&provider), Some(0));
+        assert_eq!(Balances::free_balance(&provider), 100 * AFT);
+    });
+}
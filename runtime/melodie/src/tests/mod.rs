@@ -2,6 +2,9 @@ use crate::Runtime;
 use sp_runtime::BuildStorage;
 
 pub mod fee_report;
+pub mod fee_waiver;
+pub mod parameters;
+pub mod proxy;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
     let t = frame_system::GenesisConfig::<Runtime>::default()
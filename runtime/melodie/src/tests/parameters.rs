@@ -0,0 +1,52 @@
+use crate::{dynamic_params::midds, tests::new_test_ext, *};
+use frame_support::{assert_ok, traits::Get};
+
+fn byte_deposit_cost<I: 'static>() -> Balance
+where
+    Runtime: pallet_midds::Config<I>,
+{
+    <<Runtime as pallet_midds::Config<I>>::ByteDepositCost as Get<_>>::get()
+}
+
+fn unregister_period<I: 'static>() -> Option<Moment>
+where
+    Runtime: pallet_midds::Config<I>,
+{
+    <<Runtime as pallet_midds::Config<I>>::UnregisterPeriod as Get<_>>::get()
+}
+
+fn assert_midds_params(deposit: Balance, period: Option<Moment>) {
+    assert_eq!(byte_deposit_cost::<crate::midds::MusicalWorks>(), deposit);
+    assert_eq!(byte_deposit_cost::<crate::midds::Recordings>(), deposit);
+    assert_eq!(byte_deposit_cost::<crate::midds::Releases>(), deposit);
+    assert_eq!(unregister_period::<crate::midds::MusicalWorks>(), period);
+    assert_eq!(unregister_period::<crate::midds::Recordings>(), period);
+    assert_eq!(unregister_period::<crate::midds::Releases>(), period);
+}
+
+#[test]
+fn midds_pallets_follow_the_dynamic_parameters() {
+    new_test_ext().execute_with(|| {
+        assert_midds_params(
+            midds::ByteDepositCost::get(),
+            midds::UnregisterPeriod::get(),
+        );
+
+        assert_ok!(Parameters::set_parameter(
+            RuntimeOrigin::root(),
+            RuntimeParameters::Midds(midds::Parameters::ByteDepositCost(
+                midds::ByteDepositCost,
+                Some(42),
+            )),
+        ));
+        assert_ok!(Parameters::set_parameter(
+            RuntimeOrigin::root(),
+            RuntimeParameters::Midds(midds::Parameters::UnregisterPeriod(
+                midds::UnregisterPeriod,
+                Some(Some(7)),
+            )),
+        ));
+
+        assert_midds_params(42, Some(7));
+    });
+}
@@ -0,0 +1,126 @@
+use crate::*;
+use frame_support::{pallet_prelude::Encode, traits::InstanceFilter};
+use sp_keyring::Sr25519Keyring;
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn transfer() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: Sr25519Keyring::Bob.to_account_id().into(),
+        value: 1,
+    })
+}
+
+fn sudo() -> RuntimeCall {
+    RuntimeCall::Sudo(pallet_sudo::Call::sudo {
+        call: Box::new(remark()),
+    })
+}
+
+fn multisig() -> RuntimeCall {
+    RuntimeCall::Multisig(pallet_multisig::Call::as_multi_threshold_1 {
+        other_signatories: vec![Sr25519Keyring::Bob.to_account_id()],
+        call: Box::new(remark()),
+    })
+}
+
+fn add_beneficiary() -> RuntimeCall {
+    RuntimeCall::Sponsorship(pallet_sponsorship::Call::add_beneficiary {
+        beneficiary: Sr25519Keyring::Bob.to_account_id(),
+    })
+}
+
+fn unregister_musical_work() -> RuntimeCall {
+    RuntimeCall::MusicalWorks(pallet_midds::Call::unregister { midds_id: 0 })
+}
+
+fn unregister_release() -> RuntimeCall {
+    RuntimeCall::Releases(pallet_midds::Call::unregister { midds_id: 0 })
+}
+
+fn purge_keys() -> RuntimeCall {
+    RuntimeCall::Session(pallet_session::Call::purge_keys {})
+}
+
+#[test]
+fn non_transfer_proxy_cannot_sudo_or_multisig() {
+    let p = ProxyType::NonTransfer;
+
+    assert!(!p.filter(&transfer()));
+    assert!(!p.filter(&sudo()));
+    assert!(!p.filter(&multisig()));
+    assert!(!p.filter(&add_beneficiary()));
+    assert!(p.filter(&remark()));
+    assert!(p.filter(&unregister_musical_work()));
+}
+
+#[test]
+fn registrar_proxy_only_manages_midds() {
+    let p = ProxyType::Registrar;
+
+    assert!(p.filter(&unregister_musical_work()));
+    assert!(p.filter(&unregister_release()));
+    assert!(!p.filter(&transfer()));
+    assert!(!p.filter(&remark()));
+    assert!(!p.filter(&purge_keys()));
+}
+
+#[test]
+fn validator_proxy_only_manages_keys() {
+    let p = ProxyType::Validator;
+
+    assert!(p.filter(&purge_keys()));
+    assert!(!p.filter(&unregister_musical_work()));
+    assert!(!p.filter(&sudo()));
+}
+
+#[test]
+fn narrow_proxies_only_batch_through_utility() {
+    for p in [
+        ProxyType::Registrar,
+        ProxyType::Validator,
+        ProxyType::CancelProxy,
+    ] {
+        assert!(
+            p.filter(&RuntimeCall::Utility(pallet_utility::Call::batch_all {
+                calls: vec![]
+            }))
+        );
+        assert!(
+            !p.filter(&RuntimeCall::Utility(pallet_utility::Call::as_derivative {
+                index: 0,
+                call: Box::new(unregister_musical_work()),
+            }))
+        );
+    }
+}
+
+#[test]
+fn narrow_proxies_are_only_supersets_of_themselves() {
+    let narrow = [
+        ProxyType::Registrar,
+        ProxyType::Validator,
+        ProxyType::CancelProxy,
+    ];
+
+    for p in narrow {
+        assert!(ProxyType::Any.is_superset(&p));
+        assert!(ProxyType::NonTransfer.is_superset(&p));
+        assert!(!p.is_superset(&ProxyType::NonTransfer));
+        for o in narrow {
+            assert_eq!(p.is_superset(&o), p == o);
+        }
+    }
+    assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::Any));
+}
+
+#[test]
+fn proxy_types_are_encoded_as_on_mainnet() {
+    assert_eq!(ProxyType::Any.encode(), vec![0]);
+    assert_eq!(ProxyType::NonTransfer.encode(), vec![1]);
+    assert_eq!(ProxyType::Registrar.encode(), vec![2]);
+    assert_eq!(ProxyType::Validator.encode(), vec![4]);
+    assert_eq!(ProxyType::CancelProxy.encode(), vec![6]);
+}
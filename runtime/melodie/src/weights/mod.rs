@@ -20,6 +20,7 @@ pub mod ats;
 pub mod balances;
 pub mod grandpa;
 pub mod meta_tx;
+pub mod midds_fee_waiver;
 pub mod midds_musical_works;
 pub mod midds_recordings;
 pub mod midds_releases;
@@ -28,6 +29,7 @@ pub mod preimage;
 pub mod proxy;
 pub mod safe_mode;
 pub mod scheduler;
+pub mod sponsorship;
 pub mod sudo;
 pub mod system;
 pub mod timestamp;
@@ -25,7 +25,7 @@ extern crate alloc;
 
 use crate::currency::{AFT, MICROAFT, MILLIAFT};
 use allfeat_primitives::Balance;
-use alloc::{format, string::String};
+use alloc::{format, string::String, vec::Vec};
 use frame_support::weights::Weight;
 
 /// Default AFT price in USD for fee reports.
@@ -48,6 +48,8 @@ pub struct FeeReportConfig {
     pub max_multiplier_den: u128,
     /// AFT price in USD
     pub aft_price_usd: f64,
+    /// Current values of the on-chain runtime parameters, as `(name, formatted value)`
+    pub parameters: Vec<(&'static str, String)>,
 }
 
 /// Information about an extrinsic's fee components.
@@ -325,5 +327,13 @@ pub fn print_fee_report(runtime_name: &str, estimates: &[FeeEstimate], config: &
         "  Fee for 1s ref_time:    {}",
         format_balance(fee_for_one_second)
     );
+
+    if !config.parameters.is_empty() {
+        println!();
+        println!("Runtime parameters (on-chain):");
+        for (name, value) in &config.parameters {
+            println!("  {:<23} {value}", format!("{name}:"));
+        }
+    }
     println!();
 }